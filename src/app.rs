@@ -19,6 +19,7 @@ use crate::ui::theme::ThemeMode;
 use crate::ui::tokens::tokens_screen::{TokensScreen, TokensSubscreen};
 use crate::ui::tools::contract_visualizer_screen::ContractVisualizerScreen;
 use crate::ui::tools::document_visualizer_screen::DocumentVisualizerScreen;
+use crate::ui::tools::message_verifier_screen::MessageVerifierScreen;
 use crate::ui::tools::platform_info_screen::PlatformInfoScreen;
 use crate::ui::tools::proof_log_screen::ProofLogScreen;
 use crate::ui::tools::proof_visualizer_screen::ProofVisualizerScreen;
@@ -218,6 +219,7 @@ impl AppState {
         let mut contract_visualizer_screen = ContractVisualizerScreen::new(&mainnet_app_context);
         let mut proof_log_screen = ProofLogScreen::new(&mainnet_app_context);
         let mut platform_info_screen = PlatformInfoScreen::new(&mainnet_app_context);
+        let mut message_verifier_screen = MessageVerifierScreen::new(&mainnet_app_context);
         let mut document_query_screen = DocumentQueryScreen::new(&mainnet_app_context);
         let mut tokens_balances_screen =
             TokensScreen::new(&mainnet_app_context, TokensSubscreen::MyTokens);
@@ -278,6 +280,7 @@ impl AppState {
                 wallets_balances_screen = WalletsBalancesScreen::new(testnet_app_context);
                 proof_log_screen = ProofLogScreen::new(testnet_app_context);
                 platform_info_screen = PlatformInfoScreen::new(testnet_app_context);
+                message_verifier_screen = MessageVerifierScreen::new(testnet_app_context);
                 tokens_balances_screen =
                     TokensScreen::new(testnet_app_context, TokensSubscreen::MyTokens);
                 token_search_screen =
@@ -303,6 +306,7 @@ impl AppState {
                 wallets_balances_screen = WalletsBalancesScreen::new(devnet_app_context);
                 proof_log_screen = ProofLogScreen::new(devnet_app_context);
                 platform_info_screen = PlatformInfoScreen::new(devnet_app_context);
+                message_verifier_screen = MessageVerifierScreen::new(devnet_app_context);
                 tokens_balances_screen =
                     TokensScreen::new(devnet_app_context, TokensSubscreen::MyTokens);
                 token_search_screen =
@@ -326,6 +330,7 @@ impl AppState {
                 wallets_balances_screen = WalletsBalancesScreen::new(local_app_context);
                 proof_log_screen = ProofLogScreen::new(local_app_context);
                 platform_info_screen = PlatformInfoScreen::new(local_app_context);
+                message_verifier_screen = MessageVerifierScreen::new(local_app_context);
                 tokens_balances_screen =
                     TokensScreen::new(local_app_context, TokensSubscreen::MyTokens);
                 token_search_screen =
@@ -437,6 +442,10 @@ impl AppState {
                     RootScreenType::RootScreenToolsPlatformInfoScreen,
                     Screen::PlatformInfoScreen(platform_info_screen),
                 ),
+                (
+                    RootScreenType::RootScreenToolsMessageVerifierScreen,
+                    Screen::MessageVerifierScreen(message_verifier_screen),
+                ),
                 (
                     RootScreenType::RootScreenDocumentQuery,
                     Screen::DocumentQueryScreen(document_query_screen),
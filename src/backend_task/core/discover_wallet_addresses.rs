@@ -0,0 +1,123 @@
+use crate::app::TaskResult;
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::backend_task::core::CoreItem;
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::address_discovery::AddressDiscoveryProgress;
+use dash_sdk::dpp::dashcore::Address;
+use std::sync::{Arc, RwLock};
+
+/// Scan state of one BIP44 chain.
+struct ChainScan {
+    account: u32,
+    change: bool,
+    next_index: u32,
+    last_used_index: Option<u32>,
+}
+
+impl ChainScan {
+    /// Index up to which addresses have to be checked before `gap_limit` unused addresses
+    /// follow the last used one.
+    fn scan_end(&self, gap_limit: u32) -> u32 {
+        self.last_used_index.map_or(0, |index| index + 1) + gap_limit
+    }
+}
+
+impl AppContext {
+    /// Walks the external and change chains of every BIP44 account of the wallet until
+    /// `gap_limit` consecutive addresses have never received funds, registering every
+    /// address along the way.
+    ///
+    /// Used addresses are found without rescanning, see
+    /// [`crate::chain_data::ChainDataBackend::used_addresses`]. Once the gap is complete on
+    /// every chain, a single rescan loads the history of the newly imported addresses.
+    pub async fn discover_wallet_addresses(
+        &self,
+        wallet: Arc<RwLock<Wallet>>,
+        gap_limit: u32,
+        sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        if gap_limit == 0 {
+            return Err("The gap limit must be at least 1".to_string());
+        }
+
+        let mut chains: Vec<ChainScan> = {
+            let wallet = wallet.read().map_err(|e| e.to_string())?;
+            wallet
+                .accounts
+                .keys()
+                .flat_map(|&account| {
+                    [false, true].map(|change| ChainScan {
+                        account,
+                        change,
+                        next_index: 0,
+                        last_used_index: None,
+                    })
+                })
+                .collect()
+        };
+        let mut progress = AddressDiscoveryProgress::default();
+        let mut imported_new_addresses = false;
+
+        loop {
+            // Derive and register the addresses needed to complete the gap on every chain
+            let mut batch: Vec<(usize, u32, Address)> = Vec::new();
+            {
+                let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+                for (chain, scan) in chains.iter_mut().enumerate() {
+                    while scan.next_index < scan.scan_end(gap_limit) {
+                        let (address, derivation_path) = wallet.bip44_address_at(
+                            self.network,
+                            scan.account,
+                            scan.change,
+                            scan.next_index,
+                        )?;
+                        imported_new_addresses |=
+                            wallet.register_bip44_address(&address, &derivation_path, self)?;
+                        batch.push((chain, scan.next_index, address));
+                        scan.next_index += 1;
+                    }
+                }
+            }
+
+            if batch.is_empty() {
+                break;
+            }
+
+            let addresses: Vec<Address> = batch
+                .iter()
+                .map(|(_, _, address)| address.clone())
+                .collect();
+            let used = self.with_chain_data(|chain_data| chain_data.used_addresses(&addresses))?;
+            for (chain, index, address) in &batch {
+                if used.contains(address) {
+                    let scan = &mut chains[*chain];
+                    scan.last_used_index = scan.last_used_index.max(Some(*index));
+                    progress.used_addresses += 1;
+                }
+            }
+
+            progress.checked_addresses += batch.len() as u32;
+            sender
+                .send(TaskResult::Success(Box::new(
+                    BackendTaskSuccessResult::CoreItem(CoreItem::AddressDiscoveryProgress(
+                        progress,
+                    )),
+                )))
+                .await
+                .map_err(|e| format!("Failed to send message: {}", e))?;
+        }
+
+        if imported_new_addresses {
+            self.with_chain_data(|chain_data| chain_data.rescan(None))?;
+        }
+
+        // Load balances and UTXOs of everything we found
+        self.refresh_wallet_info(wallet)?;
+
+        Ok(BackendTaskSuccessResult::Message(format!(
+            "Address discovery finished: {} of {} checked addresses were used",
+            progress.used_addresses, progress.checked_addresses
+        )))
+    }
+}
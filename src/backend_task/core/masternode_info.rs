@@ -0,0 +1,174 @@
+use crate::context::AppContext;
+use crate::model::masternode_core_info::{MasternodeCoreInfo, MasternodeWalletKeys, key_address};
+use crate::model::qualified_identity::IdentityType;
+use dash_sdk::dashcore_rpc::{Client, RpcApi};
+use dash_sdk::dpp::dashcore::{Address, Network};
+use serde::Deserialize;
+use serde_json::json;
+use std::str::FromStr;
+
+/// A ProTx as shown by `protx info` and `protx list ... true`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProTxInfo {
+    pro_tx_hash: String,
+    /// `Regular` or `Evo`, older Core versions call evonodes `HighPerformance`
+    #[serde(rename = "type", default)]
+    masternode_type: Option<String>,
+    state: ProTxState,
+    /// Only there when Core has a wallet
+    #[serde(default)]
+    wallet: Option<ProTxWallet>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProTxState {
+    owner_address: String,
+    voting_address: String,
+    payout_address: String,
+    pub_key_operator: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProTxWallet {
+    has_owner_key: bool,
+    has_voting_key: bool,
+    owns_payee_script: bool,
+}
+
+impl AppContext {
+    /// Looks up the masternode registered by `pro_tx_hash` on Core, with the keys of it the
+    /// Core wallet holds.
+    pub fn masternode_core_info(&self, pro_tx_hash: &str) -> Result<MasternodeCoreInfo, String> {
+        let core_client = self
+            .core_client
+            .read()
+            .expect("Core client lock was poisoned");
+        let pro_tx: ProTxInfo = core_client
+            .call("protx", &[json!("info"), json!(pro_tx_hash.trim())])
+            .map_err(|e| format!("Could not get ProTx {} from Core: {}", pro_tx_hash, e))?;
+        masternode_from_pro_tx(&core_client, pro_tx, self.network)
+    }
+
+    /// The masternodes the Core wallet holds keys or the collateral of.
+    pub(crate) fn wallet_masternodes(&self) -> Result<Vec<MasternodeCoreInfo>, String> {
+        let core_client = self
+            .core_client
+            .read()
+            .expect("Core client lock was poisoned");
+        let pro_txs: Vec<ProTxInfo> = core_client
+            .call("protx", &[json!("list"), json!("wallet"), json!(true)])
+            .map_err(|e| format!("Could not list the masternodes of the Core wallet: {}", e))?;
+        pro_txs
+            .into_iter()
+            .map(|pro_tx| masternode_from_pro_tx(&core_client, pro_tx, self.network))
+            .collect()
+    }
+
+    /// The registered masternodes whose voting, owner and payout addresses belong to those of
+    /// the given private keys that are set. Masternodes can share keys, so there may be
+    /// several.
+    pub(crate) fn masternodes_with_keys(
+        &self,
+        voting_private_key: Option<[u8; 32]>,
+        owner_private_key: Option<[u8; 32]>,
+        payout_private_key: Option<[u8; 32]>,
+    ) -> Result<Vec<MasternodeCoreInfo>, String> {
+        // Derived once, there are thousands of masternodes to compare them with
+        let address_of = |private_key: Option<[u8; 32]>, name: &str| {
+            private_key
+                .map(|private_key| {
+                    key_address(&private_key, self.network)
+                        .map(|address| address.to_string())
+                        .ok_or(format!("{} key is not a valid private key", name))
+                })
+                .transpose()
+        };
+        let voting_address = address_of(voting_private_key, "Voting")?;
+        let owner_address = address_of(owner_private_key, "Owner")?;
+        let payout_address = address_of(payout_private_key, "Payout address")?;
+        let matches = |registered: &str, address: &Option<String>| {
+            address
+                .as_deref()
+                .is_none_or(|address| address == registered)
+        };
+
+        let core_client = self
+            .core_client
+            .read()
+            .expect("Core client lock was poisoned");
+        let pro_txs: Vec<ProTxInfo> = core_client
+            .call("protx", &[json!("list"), json!("registered"), json!(true)])
+            .map_err(|e| format!("Could not list the registered masternodes: {}", e))?;
+        let mut masternodes = Vec::new();
+        for mut pro_tx in pro_txs {
+            if !(matches(&pro_tx.state.voting_address, &voting_address)
+                && matches(&pro_tx.state.owner_address, &owner_address)
+                && matches(&pro_tx.state.payout_address, &payout_address))
+            {
+                continue;
+            }
+            // The keys were given, there's no need to dump them from the Core wallet
+            pro_tx.wallet = None;
+            masternodes.push(masternode_from_pro_tx(&core_client, pro_tx, self.network)?);
+        }
+        Ok(masternodes)
+    }
+}
+
+fn masternode_from_pro_tx(
+    core_client: &Client,
+    pro_tx: ProTxInfo,
+    network: Network,
+) -> Result<MasternodeCoreInfo, String> {
+    let parse_address = |address: &str| {
+        Address::from_str(address)
+            .map_err(|e| format!("Invalid address {} in ProTx: {}", address, e))?
+            .require_network(network)
+            .map_err(|_| format!("Address {} is not valid for network {}", address, network))
+    };
+    let owner_address = parse_address(&pro_tx.state.owner_address)?;
+    let voting_address = parse_address(&pro_tx.state.voting_address)?;
+    let payout_address = parse_address(&pro_tx.state.payout_address)?;
+
+    // A locked or descriptor wallet can't dump keys, the masternode can still be looked up
+    let dump_key = |address: &Address| -> Option<String> {
+        core_client
+            .call("dumpprivkey", &[json!(address.to_string())])
+            .inspect_err(|e| tracing::warn!("Could not get the private key of {}: {}", address, e))
+            .ok()
+    };
+    let wallet_keys = match &pro_tx.wallet {
+        Some(wallet) => MasternodeWalletKeys {
+            owner: wallet
+                .has_owner_key
+                .then(|| dump_key(&owner_address))
+                .flatten(),
+            voting: wallet
+                .has_voting_key
+                .then(|| dump_key(&voting_address))
+                .flatten(),
+            payout: wallet
+                .owns_payee_script
+                .then(|| dump_key(&payout_address))
+                .flatten(),
+        },
+        None => MasternodeWalletKeys::default(),
+    };
+
+    let identity_type = match pro_tx.masternode_type.as_deref() {
+        Some("Evo") | Some("HighPerformance") => IdentityType::Evonode,
+        _ => IdentityType::Masternode,
+    };
+    Ok(MasternodeCoreInfo {
+        pro_tx_hash: pro_tx.pro_tx_hash,
+        identity_type,
+        owner_address,
+        voting_address,
+        payout_address,
+        operator_public_key: pro_tx.state.pub_key_operator,
+        wallet_keys,
+    })
+}
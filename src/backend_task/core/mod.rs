@@ -1,23 +1,112 @@
+mod discover_wallet_addresses;
+mod masternode_info;
 mod refresh_wallet_info;
+mod rescan_wallet;
+mod send_funds;
 mod start_dash_qt;
+mod sweep_private_key;
+mod unsigned_transactions;
 
+use crate::app::TaskResult;
 use crate::app_dir::core_cookie_path;
 use crate::backend_task::BackendTaskSuccessResult;
 use crate::config::{Config, NetworkConfig};
 use crate::context::AppContext;
+use crate::model::masternode_core_info::MasternodeCoreInfo;
 use crate::model::wallet::Wallet;
+use crate::model::wallet::address_discovery::AddressDiscoveryProgress;
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
+use crate::model::wallet::sweep::SweepTransaction;
+use crate::model::wallet::unsigned_transaction::UnsignedTransaction;
 use dash_sdk::dashcore_rpc::RpcApi;
 use dash_sdk::dashcore_rpc::{Auth, Client};
-use dash_sdk::dpp::dashcore::{Address, ChainLock, Network, OutPoint, Transaction, TxOut};
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::{
+    Address, ChainLock, Network, OutPoint, PrivateKey, Transaction, TxOut,
+};
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
+#[derive(Debug, Clone)]
+pub struct WalletPaymentInfo {
+    pub wallet: Arc<RwLock<Wallet>>,
+    pub recipient: Address,
+    pub amount: Duffs,
+    pub subtract_fee_from_amount: bool,
+    /// BIP44 account the funds are taken from.
+    pub account: u32,
+    pub coin_selection: CoinSelectionStrategy,
+}
+
+impl PartialEq for WalletPaymentInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.recipient == other.recipient
+            && self.amount == other.amount
+            && self.subtract_fee_from_amount == other.subtract_fee_from_amount
+            && self.account == other.account
+            && self.coin_selection == other.coin_selection
+    }
+}
+
+/// What [`CoreTask::ExportUnsignedAssetLock`] builds.
+#[derive(Debug, Clone)]
+pub struct WalletAssetLockInfo {
+    pub wallet: Arc<RwLock<Wallet>>,
+    pub amount: Duffs,
+    pub allow_take_fee_from_amount: bool,
+    /// BIP44 account the funds are taken from.
+    pub account: u32,
+    pub coin_selection: CoinSelectionStrategy,
+}
+
+impl PartialEq for WalletAssetLockInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount
+            && self.allow_take_fee_from_amount == other.allow_take_fee_from_amount
+            && self.account == other.account
+            && self.coin_selection == other.coin_selection
+    }
+}
+
+/// Stage of a wallet rescan, reported to the UI while it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletRescanProgress {
+    ImportingAddresses {
+        imported: usize,
+        total: usize,
+    },
+    /// Core is rescanning the chain for the imported addresses
+    Rescanning,
+    LoadingBalances,
+}
+
 #[derive(Debug, Clone)]
 pub enum CoreTask {
     #[allow(dead_code)] // May be used for getting single chain lock
     GetBestChainLock,
     GetBestChainLocks,
     RefreshWalletInfo(Arc<RwLock<Wallet>>),
+    /// Find used BIP44 addresses of a wallet, stopping after the given gap limit.
+    DiscoverWalletAddresses(Arc<RwLock<Wallet>>, u32),
+    /// Import the wallet's addresses into Core where needed, rescan from the given height, or
+    /// from genesis, and reload the wallet.
+    RescanWallet(Arc<RwLock<Wallet>>, Option<u32>),
+    SendFunds(WalletPaymentInfo),
+    /// Build a payment without signing it, for signing by an offline wallet.
+    ExportUnsignedPayment(WalletPaymentInfo),
+    /// Build an asset lock without signing it, for signing by an offline wallet.
+    ExportUnsignedAssetLock(WalletAssetLockInfo),
+    /// Broadcast a transaction of the wallet that was signed by an offline wallet.
+    BroadcastSignedTransaction(Arc<RwLock<Wallet>>, Transaction),
+    /// Find the funds of a standalone private key and build a transaction moving them into
+    /// the wallet, without broadcasting it.
+    PrepareSweep(Arc<RwLock<Wallet>>, PrivateKey),
+    BroadcastSweep(Arc<RwLock<Wallet>>, SweepTransaction),
+    /// Ask Core for a fee rate estimate and cache it for the UI, see
+    /// [`AppContext::cached_fee_rate`].
+    RefreshFeeRate,
+    /// Look up a masternode by its ProTx hash with `protx info`.
+    GetMasternodeInfo(String),
     StartDashQT(Network, PathBuf, bool),
 }
 impl PartialEq for CoreTask {
@@ -30,6 +119,34 @@ impl PartialEq for CoreTask {
                     CoreTask::RefreshWalletInfo(_),
                     CoreTask::RefreshWalletInfo(_)
                 )
+                | (
+                    CoreTask::DiscoverWalletAddresses(_, _),
+                    CoreTask::DiscoverWalletAddresses(_, _)
+                )
+                | (CoreTask::RescanWallet(_, _), CoreTask::RescanWallet(_, _))
+                | (CoreTask::SendFunds(_), CoreTask::SendFunds(_))
+                | (
+                    CoreTask::ExportUnsignedPayment(_),
+                    CoreTask::ExportUnsignedPayment(_)
+                )
+                | (
+                    CoreTask::ExportUnsignedAssetLock(_),
+                    CoreTask::ExportUnsignedAssetLock(_)
+                )
+                | (
+                    CoreTask::BroadcastSignedTransaction(_, _),
+                    CoreTask::BroadcastSignedTransaction(_, _)
+                )
+                | (CoreTask::PrepareSweep(_, _), CoreTask::PrepareSweep(_, _))
+                | (
+                    CoreTask::BroadcastSweep(_, _),
+                    CoreTask::BroadcastSweep(_, _)
+                )
+                | (CoreTask::RefreshFeeRate, CoreTask::RefreshFeeRate)
+                | (
+                    CoreTask::GetMasternodeInfo(_),
+                    CoreTask::GetMasternodeInfo(_)
+                )
                 | (
                     CoreTask::StartDashQT(_, _, _),
                     CoreTask::StartDashQT(_, _, _)
@@ -48,10 +165,23 @@ pub enum CoreItem {
         Option<ChainLock>,
         Option<ChainLock>,
     ), // Mainnet, Testnet, Devnet, Local
+    AddressDiscoveryProgress(AddressDiscoveryProgress),
+    WalletRescanProgress(WalletRescanProgress),
+    /// Outcome of a payment, the confirmation to show or why it failed. Failures are
+    /// reported here rather than as task errors so the send dialog can tell them apart.
+    SentFunds(Result<String, String>),
+    /// Outcome of exporting an unsigned transaction
+    UnsignedTransaction(Result<UnsignedTransaction, String>),
+    SweepTransaction(SweepTransaction),
+    MasternodeInfo(MasternodeCoreInfo),
 }
 
 impl AppContext {
-    pub async fn run_core_task(&self, task: CoreTask) -> Result<BackendTaskSuccessResult, String> {
+    pub async fn run_core_task(
+        &self,
+        task: CoreTask,
+        sender: crate::utils::egui_mpsc::SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
         match task {
             CoreTask::GetBestChainLock => self
                 .core_client
@@ -109,6 +239,48 @@ impl AppContext {
             CoreTask::RefreshWalletInfo(wallet) => self
                 .refresh_wallet_info(wallet)
                 .map_err(|e| format!("Error refreshing wallet: {}", e)),
+            CoreTask::DiscoverWalletAddresses(wallet, gap_limit) => self
+                .discover_wallet_addresses(wallet, gap_limit, sender)
+                .await
+                .map_err(|e| format!("Error discovering wallet addresses: {}", e)),
+            CoreTask::RescanWallet(wallet, start_height) => self
+                .rescan_wallet(wallet, start_height, sender)
+                .await
+                .map_err(|e| format!("Error rescanning wallet: {}", e)),
+            CoreTask::SendFunds(payment_info) => {
+                Ok(BackendTaskSuccessResult::CoreItem(CoreItem::SentFunds(
+                    self.send_funds(payment_info)
+                        .map_err(|e| format!("Error sending funds: {}", e)),
+                )))
+            }
+            CoreTask::ExportUnsignedPayment(payment_info) => Ok(
+                BackendTaskSuccessResult::CoreItem(CoreItem::UnsignedTransaction(
+                    self.export_unsigned_payment(payment_info)
+                        .map_err(|e| format!("Error building unsigned payment: {}", e)),
+                )),
+            ),
+            CoreTask::ExportUnsignedAssetLock(asset_lock_info) => Ok(
+                BackendTaskSuccessResult::CoreItem(CoreItem::UnsignedTransaction(
+                    self.export_unsigned_asset_lock(asset_lock_info)
+                        .map_err(|e| format!("Error building unsigned asset lock: {}", e)),
+                )),
+            ),
+            CoreTask::BroadcastSignedTransaction(wallet, transaction) => self
+                .broadcast_signed_transaction(wallet, transaction)
+                .map_err(|e| format!("Error broadcasting signed transaction: {}", e)),
+            CoreTask::PrepareSweep(wallet, private_key) => self
+                .prepare_sweep(wallet, private_key)
+                .map_err(|e| format!("Error preparing sweep: {}", e)),
+            CoreTask::BroadcastSweep(wallet, sweep) => self
+                .broadcast_sweep(wallet, sweep)
+                .map_err(|e| format!("Error sweeping private key: {}", e)),
+            CoreTask::RefreshFeeRate => {
+                self.refresh_fee_rate();
+                Ok(BackendTaskSuccessResult::None)
+            }
+            CoreTask::GetMasternodeInfo(pro_tx_hash) => self
+                .masternode_core_info(&pro_tx_hash)
+                .map(|info| BackendTaskSuccessResult::CoreItem(CoreItem::MasternodeInfo(info))),
             CoreTask::StartDashQT(network, custom_dash_qt, overwrite_dash_conf) => self
                 .start_dash_qt(network, custom_dash_qt, overwrite_dash_conf)
                 .map_err(|e| e.to_string())
@@ -1,11 +1,14 @@
 use crate::backend_task::BackendTaskSuccessResult;
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
+use crate::model::wallet::wallet_transaction::{TransactionDirection, WalletTransaction};
 use crate::ui::wallets::wallets_screen::DerivationPathHelpers;
-use dash_sdk::dashcore_rpc::RpcApi;
 use dash_sdk::dpp::dashcore::Address;
 use std::sync::{Arc, RwLock};
 
+/// How many of the most recent Core wallet transactions are scanned for history on refresh.
+const TRANSACTION_HISTORY_SCAN_COUNT: usize = 1000;
+
 impl AppContext {
     pub fn refresh_wallet_info(
         &self,
@@ -27,39 +30,48 @@ impl AppContext {
                 .collect::<Vec<_>>()
         };
 
-        // Step 2: Iterate over each address and update balances
-        for address in &addresses {
-            // Fetch balance for the address from Dash Core
-            match self
-                .core_client
-                .read()
-                .expect("Core client lock was poisoned")
-                .get_received_by_address(address, None)
-            {
-                Ok(new_balance) => {
-                    // Update the wallet's address_balances and database
-                    {
-                        let mut wallet_guard = wallet.write().map_err(|e| e.to_string())?;
-                        wallet_guard.update_address_balance(address, new_balance.to_sat(), self)?;
-                    }
-                }
+        // Step 2: Look for addresses the chain data backend doesn't track, like those of a
+        // freshly imported wallet. Importing them needs a rescan, which is far too slow for a
+        // refresh, so that is left to Rescan.
+        let untracked =
+            match self.with_chain_data(|chain_data| chain_data.untracked_addresses(&addresses)) {
+                Ok(untracked) => untracked.len(),
                 Err(e) => {
-                    eprintln!("Error fetching balance for address {}: {}", address, e);
+                    eprintln!("Error checking which wallet addresses are tracked: {}", e);
+                    0
+                }
+            };
+
+        // Step 3: Update the total each address received, which marks it as used
+        match self.with_chain_data(|chain_data| {
+            Ok((
+                chain_data.received_by_addresses(&addresses, true)?,
+                chain_data.reports_total_received(),
+            ))
+        }) {
+            Ok((totals_received, reports_total_received)) => {
+                let mut wallet_guard = wallet.write().map_err(|e| e.to_string())?;
+                for (address, mut total_received) in totals_received {
+                    // What an address still holds is only a lower bound of what it received,
+                    // lowering the stored total would hand out used addresses again
+                    if !reports_total_received {
+                        let stored = wallet_guard.address_total_received.get(&address);
+                        total_received = total_received.max(stored.copied().unwrap_or(0));
+                    }
+                    wallet_guard.update_address_total_received(&address, total_received, self)?;
                 }
             }
+            Err(e) => {
+                eprintln!("Error fetching totals received by wallet addresses: {}", e);
+            }
         }
 
-        // Step 3: Reload UTXOs using the wallet's existing method
+        // Step 4: Reload UTXOs, balances are derived from them
         let utxo_map = {
             let mut wallet_guard = wallet.write().map_err(|e| e.to_string())?;
-            match wallet_guard.reload_utxos(
-                &self
-                    .core_client
-                    .read()
-                    .expect("Core client lock was poisoned"),
-                self.network,
-                Some(self),
-            ) {
+            match self.with_chain_data(|chain_data| {
+                wallet_guard.reload_utxos(chain_data, self.network, Some(self))
+            }) {
                 Ok(utxo_map) => utxo_map,
                 Err(e) => {
                     eprintln!("Error reloading UTXOs: {}", e);
@@ -85,9 +97,57 @@ impl AppContext {
                 .map_err(|e| e.to_string())?;
         }
 
-        // Step 5: Return a success result
-        Ok(BackendTaskSuccessResult::Message(
-            "Successfully refreshed wallet".to_string(),
-        ))
+        // Step 5: Record incoming transactions and refresh confirmations of known ones
+        if let Err(e) = self.refresh_wallet_transactions(&wallet, &addresses) {
+            eprintln!("Error refreshing transaction history: {}", e);
+        }
+
+        // Step 6: Return a success result
+        Ok(BackendTaskSuccessResult::Message(if untracked > 0 {
+            format!(
+                "Successfully refreshed wallet, but {} of its addresses are not tracked by Dash Core yet. Run Rescan to load their funds.",
+                untracked
+            )
+        } else {
+            "Successfully refreshed wallet".to_string()
+        }))
+    }
+
+    fn refresh_wallet_transactions(
+        &self,
+        wallet: &Arc<RwLock<Wallet>>,
+        addresses: &[Address],
+    ) -> Result<(), String> {
+        let transactions = self.with_chain_data(|chain_data| {
+            chain_data.recent_transactions(addresses, TRANSACTION_HISTORY_SCAN_COUNT)
+        })?;
+
+        let seed_hash = wallet.read().map_err(|e| e.to_string())?.seed_hash();
+        for transaction in &transactions {
+            self.db
+                .update_wallet_transaction_confirmations(
+                    &transaction.txid,
+                    transaction.confirmations,
+                    self.network,
+                )
+                .map_err(|e| e.to_string())?;
+
+            if transaction.received == 0 {
+                continue;
+            }
+            let wallet_transaction = WalletTransaction {
+                txid: transaction.txid,
+                direction: TransactionDirection::Incoming,
+                amount: transaction.received,
+                fee: None,
+                confirmations: transaction.confirmations,
+                identity_id: None,
+                timestamp: transaction.timestamp,
+            };
+            self.db
+                .insert_wallet_transaction(&seed_hash, &wallet_transaction, self.network)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(())
     }
 }
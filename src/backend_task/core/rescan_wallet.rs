@@ -0,0 +1,92 @@
+use crate::app::TaskResult;
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::backend_task::core::{CoreItem, WalletRescanProgress};
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::utils::egui_mpsc::SenderAsync;
+use dash_sdk::dpp::dashcore::Address;
+use std::sync::{Arc, RwLock};
+
+/// Addresses imported per progress report
+const IMPORT_BATCH_SIZE: usize = 100;
+
+impl AppContext {
+    /// Imports every wallet address the chain data backend doesn't track yet, followed by a
+    /// single rescan from `start_height` so their history is known. Returns how many
+    /// addresses were imported.
+    fn import_untracked_addresses(
+        &self,
+        wallet: &Arc<RwLock<Wallet>>,
+        start_height: Option<u32>,
+        sender: Option<&SenderAsync<TaskResult>>,
+    ) -> Result<usize, String> {
+        let (alias, known_addresses) = {
+            let wallet = wallet.read().map_err(|e| e.to_string())?;
+            (
+                wallet.alias.clone().unwrap_or_default(),
+                wallet.known_addresses.clone(),
+            )
+        };
+        let addresses: Vec<Address> = known_addresses.keys().cloned().collect();
+
+        self.with_chain_data(|chain_data| {
+            let untracked = chain_data.untracked_addresses(&addresses)?;
+            if untracked.is_empty() {
+                return Ok(0);
+            }
+
+            let total = untracked.len();
+            for (batch_index, batch) in untracked.chunks(IMPORT_BATCH_SIZE).enumerate() {
+                report_progress(
+                    sender,
+                    WalletRescanProgress::ImportingAddresses {
+                        imported: batch_index * IMPORT_BATCH_SIZE,
+                        total,
+                    },
+                );
+                for address in batch {
+                    let label = format!(
+                        "Managed by Dash Evo Tool {} {}",
+                        alias, known_addresses[address]
+                    );
+                    chain_data.watch_address(address, &label)?;
+                }
+            }
+
+            report_progress(sender, WalletRescanProgress::Rescanning);
+            chain_data.rescan(start_height)?;
+            Ok(total)
+        })
+    }
+
+    /// Makes the chain data backend track every wallet address and reloads the wallet,
+    /// reporting each stage to the UI. Only blocks from `start_height` on are rescanned,
+    /// funds received before it stay missing.
+    pub async fn rescan_wallet(
+        &self,
+        wallet: Arc<RwLock<Wallet>>,
+        start_height: Option<u32>,
+        sender: SenderAsync<TaskResult>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let imported = self.import_untracked_addresses(&wallet, start_height, Some(&sender))?;
+
+        report_progress(Some(&sender), WalletRescanProgress::LoadingBalances);
+        self.refresh_wallet_info(wallet.clone())?;
+
+        let balance = wallet.read().map_err(|e| e.to_string())?.balance().total();
+        Ok(BackendTaskSuccessResult::Message(format!(
+            "Rescan finished: {} addresses imported, balance {:.8} DASH",
+            imported,
+            balance as f64 * 1e-8
+        )))
+    }
+}
+
+/// Progress is only informative, so it is dropped if the UI can't take it right away.
+fn report_progress(sender: Option<&SenderAsync<TaskResult>>, progress: WalletRescanProgress) {
+    if let Some(sender) = sender {
+        let _ = sender.try_send(TaskResult::Success(Box::new(
+            BackendTaskSuccessResult::CoreItem(CoreItem::WalletRescanProgress(progress)),
+        )));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::backend_task::core::WalletPaymentInfo;
+use crate::context::{AppContext, asset_lock_credit_value};
+use crate::model::wallet::Wallet;
+use crate::model::wallet::wallet_transaction::TransactionDirection;
+use dash_sdk::dpp::dashcore::{Address, OutPoint, Transaction, TxOut, Txid};
+use dash_sdk::dpp::prelude::AssetLockProof;
+use dash_sdk::platform::Identifier;
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+impl AppContext {
+    /// Pay from the wallet and broadcast the payment, returning the confirmation to show.
+    pub fn send_funds(&self, input: WalletPaymentInfo) -> Result<String, String> {
+        let WalletPaymentInfo {
+            wallet,
+            recipient,
+            amount,
+            subtract_fee_from_amount,
+            account,
+            coin_selection,
+        } = input;
+
+        let fee_rate = self.refresh_fee_rate();
+
+        let (transaction, _, used_utxos) = {
+            let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+            let payment = match wallet.payment_transaction(
+                self.network,
+                &recipient,
+                amount,
+                subtract_fee_from_amount,
+                account,
+                coin_selection,
+                fee_rate,
+                Some(self),
+            ) {
+                Ok(transaction) => transaction,
+                Err(_) => {
+                    // Our UTXO set might be stale, reload it and try once more
+                    self.with_chain_data(|chain_data| {
+                        wallet.reload_utxos(chain_data, self.network, Some(self))
+                    })?;
+                    wallet.payment_transaction(
+                        self.network,
+                        &recipient,
+                        amount,
+                        subtract_fee_from_amount,
+                        account,
+                        coin_selection,
+                        fee_rate,
+                        Some(self),
+                    )?
+                }
+            };
+            let (transaction, _, used_utxos) = &payment;
+            wallet.reserve_utxos(used_utxos.keys().copied(), transaction.txid(), Some(self))?;
+            payment
+        };
+
+        let txid = self
+            .broadcast_reserved_transaction(&wallet, &transaction, &used_utxos)
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+
+        for utxo in used_utxos.keys() {
+            self.db
+                .drop_utxo(utxo, &self.network.to_string())
+                .map_err(|e| e.to_string())?;
+        }
+
+        let sent_amount = transaction
+            .output
+            .first()
+            .map(|tx_out| tx_out.value)
+            .unwrap_or(amount);
+
+        let input_value = used_utxos.values().map(|(tx_out, _)| tx_out.value).sum();
+        let seed_hash = wallet.read().map_err(|e| e.to_string())?.seed_hash();
+        self.store_broadcast_wallet_transaction(
+            &seed_hash,
+            &transaction,
+            TransactionDirection::Outgoing,
+            sent_amount,
+            input_value,
+            None,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(format!(
+            "Sent {:.8} DASH to {}, transaction id {}",
+            sent_amount as f64 * 1e-8,
+            recipient,
+            txid
+        ))
+    }
+
+    /// Broadcast a transaction of `wallet` spending `used_utxos`, which were reserved for it.
+    /// If the broadcast fails nothing was spent, so the reservation is released and the
+    /// UTXOs are given back to the wallet.
+    pub(crate) fn broadcast_reserved_transaction(
+        &self,
+        wallet: &Arc<RwLock<Wallet>>,
+        transaction: &Transaction,
+        used_utxos: &BTreeMap<OutPoint, (TxOut, Address)>,
+    ) -> Result<Txid, String> {
+        self.with_chain_data(|chain_data| chain_data.broadcast_transaction(transaction))
+            .or_else(|e| {
+                let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+                wallet.release_utxo_reservations(&transaction.txid(), Some(self))?;
+                wallet.restore_utxos(used_utxos);
+                Err(e)
+            })
+    }
+
+    /// Broadcast an asset lock transaction of `wallet` spending the reserved `used_utxos`,
+    /// and record it in the wallet history. The txid is registered as waiting for
+    /// finality before broadcasting, so the proof is not missed if the InstantSend lock
+    /// arrives right away.
+    pub fn broadcast_wallet_asset_lock(
+        &self,
+        wallet: &Arc<RwLock<Wallet>>,
+        asset_lock_transaction: &Transaction,
+        used_utxos: &BTreeMap<OutPoint, (TxOut, Address)>,
+        identity_id: Option<Identifier>,
+    ) -> Result<Txid, String> {
+        let tx_id = asset_lock_transaction.txid();
+        // todo: maybe one day we will want to use platform again, but for right now we use
+        //  the local core as it is more stable
+        // let asset_lock_proof = self
+        //     .broadcast_and_retrieve_asset_lock(&asset_lock_transaction, &change_address)
+        //     .await
+        //     .map_err(|e| e.to_string())?;
+
+        {
+            let mut proofs = self.transactions_waiting_for_finality.lock().unwrap();
+            proofs.insert(tx_id, None);
+        }
+
+        if let Err(e) =
+            self.broadcast_reserved_transaction(wallet, asset_lock_transaction, used_utxos)
+        {
+            self.transactions_waiting_for_finality
+                .lock()
+                .unwrap()
+                .remove(&tx_id);
+            return Err(e);
+        }
+
+        let seed_hash = {
+            let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+            wallet.utxos.retain(|_, utxo_map| {
+                utxo_map.retain(|outpoint, _| !used_utxos.contains_key(outpoint));
+                !utxo_map.is_empty() // Keep addresses that still have UTXOs
+            });
+            for utxo in used_utxos.keys() {
+                self.db
+                    .drop_utxo(utxo, &self.network.to_string())
+                    .map_err(|e| e.to_string())?;
+            }
+            wallet.seed_hash()
+        };
+
+        self.store_broadcast_wallet_transaction(
+            &seed_hash,
+            asset_lock_transaction,
+            TransactionDirection::AssetLock,
+            asset_lock_credit_value(asset_lock_transaction).unwrap_or_default(),
+            used_utxos.values().map(|(tx_out, _)| tx_out.value).sum(),
+            identity_id,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(tx_id)
+    }
+
+    /// The proof of a broadcast asset lock, once Core reported its InstantSend or ChainLock.
+    pub fn asset_lock_proof(&self, tx_id: &Txid) -> Option<AssetLockProof> {
+        let proofs = self.transactions_waiting_for_finality.lock().unwrap();
+        proofs.get(tx_id).cloned().flatten()
+    }
+
+    /// Wait until the asset lock broadcast with [`Self::broadcast_wallet_asset_lock`] is
+    /// final.
+    pub async fn wait_for_asset_lock_proof(&self, tx_id: &Txid) -> AssetLockProof {
+        loop {
+            if let Some(proof) = self.asset_lock_proof(tx_id) {
+                return proof;
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    }
+}
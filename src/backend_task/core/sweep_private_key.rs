@@ -0,0 +1,80 @@
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::backend_task::core::CoreItem;
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::sweep::SweepTransaction;
+use crate::model::wallet::wallet_transaction::TransactionDirection;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::{Address, PrivateKey};
+use std::sync::{Arc, RwLock};
+
+impl AppContext {
+    /// Look up the unspent outputs of `private_key` and build a signed transaction moving
+    /// them to a receive address of `wallet`, for review before it is broadcast.
+    ///
+    /// The key is not part of any wallet, so its outputs are looked up with
+    /// [`crate::chain_data::ChainDataBackend::scan_unspent`].
+    pub fn prepare_sweep(
+        &self,
+        wallet: Arc<RwLock<Wallet>>,
+        private_key: PrivateKey,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let secp = Secp256k1::new();
+        let source = Address::p2pkh(&private_key.public_key(&secp), self.network);
+
+        let utxos = self.with_chain_data(|chain_data| chain_data.scan_unspent(&[source]))?;
+        let utxos = utxos
+            .into_iter()
+            .filter(|(_, _, status)| !status.is_immature())
+            .map(|(outpoint, tx_out, _)| (outpoint, tx_out))
+            .collect();
+
+        let destination = wallet.write().map_err(|e| e.to_string())?.receive_address(
+            self.network,
+            0,
+            false,
+            Some(self),
+        )?;
+        let sweep = SweepTransaction::new(
+            &private_key,
+            utxos,
+            destination,
+            self.refresh_fee_rate(),
+            self.network,
+        )?;
+
+        Ok(BackendTaskSuccessResult::CoreItem(
+            CoreItem::SweepTransaction(sweep),
+        ))
+    }
+
+    /// Broadcast a sweep prepared by [`AppContext::prepare_sweep`].
+    pub fn broadcast_sweep(
+        &self,
+        wallet: Arc<RwLock<Wallet>>,
+        sweep: SweepTransaction,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let txid = self
+            .with_chain_data(|chain_data| chain_data.broadcast_transaction(&sweep.transaction))
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+
+        let seed_hash = wallet.read().map_err(|e| e.to_string())?.seed_hash();
+        self.store_broadcast_wallet_transaction(
+            &seed_hash,
+            &sweep.transaction,
+            TransactionDirection::Incoming,
+            sweep.amount(),
+            sweep.input_value,
+            None,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(BackendTaskSuccessResult::Message(format!(
+            "Swept {:.8} DASH from {} to {}, transaction id {}",
+            sweep.amount() as f64 * 1e-8,
+            sweep.source,
+            sweep.destination,
+            txid
+        )))
+    }
+}
@@ -0,0 +1,220 @@
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::backend_task::core::{WalletAssetLockInfo, WalletPaymentInfo};
+use crate::context::{AppContext, asset_lock_credit_value};
+use crate::model::wallet::Wallet;
+use crate::model::wallet::unsigned_transaction::UnsignedTransaction;
+use crate::model::wallet::wallet_transaction::TransactionDirection;
+use dash_sdk::dpp::dashcore::{Address, OutPoint, ScriptBuf, Transaction, TxOut, Txid};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, RwLock};
+
+impl AppContext {
+    /// Build a payment like [`AppContext::send_funds`] but hand it back unsigned, so it can be
+    /// signed by an offline wallet.
+    ///
+    /// The spent UTXOs stay in the wallet until the signed transaction is broadcast, so the
+    /// broadcast can account for them, but are reserved for it so nothing else spends them
+    /// in the meantime.
+    pub fn export_unsigned_payment(
+        &self,
+        input: WalletPaymentInfo,
+    ) -> Result<UnsignedTransaction, String> {
+        let WalletPaymentInfo {
+            wallet,
+            recipient,
+            amount,
+            subtract_fee_from_amount,
+            account,
+            coin_selection,
+        } = input;
+
+        let fee_rate = self.refresh_fee_rate();
+
+        let (transaction, used_utxos) = {
+            let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+            let (transaction, _, used_utxos) = wallet.unsigned_payment_transaction(
+                self.network,
+                &recipient,
+                amount,
+                subtract_fee_from_amount,
+                account,
+                coin_selection,
+                fee_rate,
+                Some(self),
+            )?;
+            wallet.restore_utxos(&used_utxos);
+            wallet.reserve_utxos(used_utxos.keys().copied(), transaction.txid(), Some(self))?;
+            (transaction, used_utxos)
+        };
+        self.attach_previous_transactions(&wallet, transaction, &used_utxos)
+    }
+
+    /// Build an asset lock without signing it, so it can be signed by an offline wallet.
+    ///
+    /// The credits go to an unused receive address of the account, which a watch-only wallet
+    /// can derive as well. Once the signed transaction is broadcast and locked, the asset
+    /// lock shows up among the wallet's unused asset locks and can fund an identity from any
+    /// instance holding the seed.
+    pub fn export_unsigned_asset_lock(
+        &self,
+        input: WalletAssetLockInfo,
+    ) -> Result<UnsignedTransaction, String> {
+        let WalletAssetLockInfo {
+            wallet,
+            amount,
+            allow_take_fee_from_amount,
+            account,
+            coin_selection,
+        } = input;
+
+        let fee_rate = self.refresh_fee_rate();
+
+        let (transaction, used_utxos) = {
+            let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+            let (credit_public_key, _) =
+                wallet.unused_bip_44_public_key(self.network, account, false, false, Some(self))?;
+            let (transaction, _, used_utxos) = wallet.unsigned_asset_lock_transaction(
+                self.network,
+                amount,
+                allow_take_fee_from_amount,
+                credit_public_key.pubkey_hash(),
+                account,
+                coin_selection,
+                fee_rate,
+                Some(self),
+            )?;
+            wallet.restore_utxos(&used_utxos);
+            wallet.reserve_utxos(used_utxos.keys().copied(), transaction.txid(), Some(self))?;
+            (transaction, used_utxos)
+        };
+        self.attach_previous_transactions(&wallet, transaction, &used_utxos)
+    }
+
+    /// Look up the transactions that created the outputs spent by `transaction` and export it
+    /// with them, releasing its reserved outputs if that fails.
+    fn attach_previous_transactions(
+        &self,
+        wallet: &Arc<RwLock<Wallet>>,
+        transaction: Transaction,
+        used_utxos: &BTreeMap<OutPoint, (TxOut, Address)>,
+    ) -> Result<UnsignedTransaction, String> {
+        let pending_txid = transaction.txid();
+        let previous_transactions = self.with_chain_data(|chain_data| {
+            used_utxos
+                .keys()
+                .map(|outpoint| outpoint.txid)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|txid| Ok((txid, chain_data.fetch_transaction(&txid)?)))
+                .collect::<Result<BTreeMap<_, _>, String>>()
+        });
+
+        let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+        let exported = previous_transactions.and_then(|previous_transactions| {
+            wallet.export_unsigned_transaction(
+                self.network,
+                transaction,
+                used_utxos,
+                &previous_transactions,
+            )
+        });
+        if exported.is_err() {
+            wallet.release_utxo_reservations(&pending_txid, Some(self))?;
+        }
+        exported
+    }
+
+    /// Broadcast a transaction that spends outputs of `wallet` and was signed elsewhere.
+    pub fn broadcast_signed_transaction(
+        &self,
+        wallet: Arc<RwLock<Wallet>>,
+        transaction: Transaction,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let (seed_hash, input_value, outgoing_value) = {
+            let wallet = wallet.read().map_err(|e| e.to_string())?;
+            // Exported transactions reserve their inputs under the id they had unsigned
+            let unsigned_txid = unsigned_txid(&transaction);
+            for input in &transaction.input {
+                match wallet.utxo_reserved_for(&input.previous_output) {
+                    Some(pending_txid) if pending_txid != unsigned_txid => {
+                        return Err(format!(
+                            "Input {} is already being spent by pending transaction {}",
+                            input.previous_output, pending_txid
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+            let input_value = transaction
+                .input
+                .iter()
+                .map(|input| {
+                    wallet
+                        .utxos
+                        .values()
+                        .find_map(|outpoints| outpoints.get(&input.previous_output))
+                        .map(|tx_out| tx_out.value)
+                        .ok_or(format!(
+                            "Input {} is not an unspent output of this wallet",
+                            input.previous_output
+                        ))
+                })
+                .sum::<Result<u64, String>>()?;
+            // Everything not paid back to one of our addresses left the wallet
+            let outgoing_value = transaction
+                .output
+                .iter()
+                .filter(|tx_out| {
+                    !Address::from_script(&tx_out.script_pubkey, self.network)
+                        .is_ok_and(|address| wallet.known_addresses.contains_key(&address))
+                })
+                .map(|tx_out| tx_out.value)
+                .sum::<u64>();
+            (wallet.seed_hash(), input_value, outgoing_value)
+        };
+
+        let txid = self
+            .with_chain_data(|chain_data| chain_data.broadcast_transaction(&transaction))
+            .map_err(|e| format!("Failed to broadcast transaction: {}", e))?;
+
+        {
+            let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+            for input in &transaction.input {
+                for outpoints in wallet.utxos.values_mut() {
+                    outpoints.remove(&input.previous_output);
+                }
+                self.db
+                    .drop_utxo(&input.previous_output, &self.network.to_string())
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        let (direction, amount) = match asset_lock_credit_value(&transaction) {
+            Some(credit_value) => (TransactionDirection::AssetLock, credit_value),
+            None => (TransactionDirection::Outgoing, outgoing_value),
+        };
+        self.store_broadcast_wallet_transaction(
+            &seed_hash,
+            &transaction,
+            direction,
+            amount,
+            input_value,
+            None,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(BackendTaskSuccessResult::Message(format!(
+            "Broadcast signed transaction {}",
+            txid
+        )))
+    }
+}
+
+/// Id of `transaction` before its inputs were signed.
+fn unsigned_txid(transaction: &Transaction) -> Txid {
+    let mut unsigned = transaction.clone();
+    for input in &mut unsigned.input {
+        input.script_sig = ScriptBuf::new();
+    }
+    unsigned.txid()
+}
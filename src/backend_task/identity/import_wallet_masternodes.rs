@@ -0,0 +1,55 @@
+use super::{BackendTaskSuccessResult, IdentityInputToLoad};
+use crate::context::AppContext;
+use dash_sdk::Sdk;
+
+impl AppContext {
+    /// Loads the identity of every masternode of the Core wallet, with the owner, voting and
+    /// payout keys the wallet holds.
+    pub(super) async fn import_wallet_masternodes(
+        &self,
+        sdk: &Sdk,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let masternodes = self.wallet_masternodes()?;
+        if masternodes.is_empty() {
+            return Ok(BackendTaskSuccessResult::Message(
+                "The Core wallet has no masternodes".to_string(),
+            ));
+        }
+
+        let mut imported = 0;
+        let mut failures = Vec::new();
+        for mut masternode in masternodes {
+            // The keys are zeroized once load_identity has parsed them
+            let keys = &mut masternode.wallet_keys;
+            let input = IdentityInputToLoad {
+                identity_id_input: masternode.pro_tx_hash.clone(),
+                identity_type: masternode.identity_type,
+                alias_input: String::new(),
+                voting_private_key_input: keys.voting.take().unwrap_or_default(),
+                owner_private_key_input: keys.owner.take().unwrap_or_default(),
+                payout_address_private_key_input: keys.payout.take().unwrap_or_default(),
+                keys_input: vec![],
+            };
+            match self.load_identity(sdk, input).await {
+                Ok(_) => imported += 1,
+                Err(e) => failures.push(format!("{}: {}", masternode.pro_tx_hash, e)),
+            }
+        }
+
+        if imported == 0 {
+            return Err(format!(
+                "Could not import any masternode identity. {}",
+                failures.join("; ")
+            ));
+        }
+        let mut message = format!(
+            "Imported {} masternode identit{}",
+            imported,
+            if imported == 1 { "y" } else { "ies" }
+        );
+        if !failures.is_empty() {
+            message.push_str(&format!(". Failed to import {}", failures.join("; ")));
+        }
+        Ok(BackendTaskSuccessResult::Message(message))
+    }
+}
@@ -1,6 +1,7 @@
 use super::BackendTaskSuccessResult;
 use crate::backend_task::identity::{IdentityInputToLoad, verify_key_input};
 use crate::context::AppContext;
+use crate::model::dpns_name::dpns_label;
 use crate::model::qualified_identity::PrivateKeyTarget::{
     self, PrivateKeyOnMainIdentity, PrivateKeyOnVoterIdentity,
 };
@@ -21,6 +22,7 @@ use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicK
 use dash_sdk::dpp::platform_value::Value;
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::drive::query::{WhereClause, WhereOperator};
+use dash_sdk::platform::types::identity::PublicKeyHash;
 use dash_sdk::platform::{Document, DocumentQuery, Fetch, FetchMany, Identifier, Identity};
 use egui::ahash::HashMap;
 use std::collections::BTreeMap;
@@ -50,20 +52,97 @@ impl AppContext {
         let payout_address_private_key_bytes =
             verify_key_input(payout_address_private_key_input, "Payout Address")?;
 
-        // Parse the identity ID
-        let identity_id = match Identifier::from_string(&identity_id_input, Encoding::Base58)
-            .or_else(|_| Identifier::from_string(&identity_id_input, Encoding::Hex))
-        {
-            Ok(id) => id,
-            Err(e) => return Err(format!("Identifier error: {}", e)),
+        let input_private_keys = if identity_type == IdentityType::User {
+            keys_input
+                .into_iter()
+                .filter_map(|key_string| {
+                    Some(
+                        verify_key_input(key_string, "User Key")
+                            .transpose()?
+                            .and_then(|sk| {
+                                PrivateKey::from_slice(sk.as_slice(), self.network)
+                                    .map_err(|e| e.to_string())
+                            }),
+                    )
+                })
+                .collect::<Result<Vec<PrivateKey>, String>>()?
+        } else {
+            vec![]
         };
 
-        // Fetch the identity using the SDK
-        let identity = match Identity::fetch_by_identifier(sdk, identity_id).await {
-            Ok(Some(identity)) => identity,
-            Ok(None) => return Err("Identity not found".to_string()),
-            Err(e) => return Err(format!("Error fetching identity: {}", e)),
+        // Find the identity by its ID or DPNS name, or by one of its keys if neither is given
+        let identity_id_input = identity_id_input.trim();
+        let identity = if identity_id_input.is_empty() && identity_type == IdentityType::User {
+            if input_private_keys.is_empty() {
+                return Err(
+                    "Enter the identity ID, its DPNS name or one of its private keys".to_string(),
+                );
+            }
+            self.fetch_identity_by_private_keys(sdk, &input_private_keys)
+                .await?
+                .ok_or("No identity found for the given private keys".to_string())?
+        } else if identity_id_input.is_empty() {
+            if owner_private_key_bytes.is_none()
+                && voting_private_key_bytes.is_none()
+                && payout_address_private_key_bytes.is_none()
+            {
+                return Err(
+                    "Enter the ProTx hash or one of the masternode's private keys".to_string(),
+                );
+            }
+            // Masternode keys aren't unique on Platform, the ProTx they are registered for is
+            // looked up on Core instead
+            let masternodes = self.masternodes_with_keys(
+                voting_private_key_bytes,
+                owner_private_key_bytes,
+                payout_address_private_key_bytes,
+            )?;
+            let pro_tx_hash = match masternodes.as_slice() {
+                [] => return Err("No registered masternode has the given private keys".to_string()),
+                [masternode] => &masternode.pro_tx_hash,
+                _ => {
+                    return Err(format!(
+                        "The given private keys are registered for several masternodes, enter the ProTx hash of one of them: {}",
+                        masternodes
+                            .iter()
+                            .map(|masternode| masternode.pro_tx_hash.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+            };
+            let identity_id = Identifier::from_string(pro_tx_hash, Encoding::Hex)
+                .map_err(|e| format!("Identifier error: {}", e))?;
+            match Identity::fetch_by_identifier(sdk, identity_id).await {
+                Ok(Some(identity)) => identity,
+                Ok(None) => {
+                    return Err(format!(
+                        "Masternode {} has no identity on Platform",
+                        pro_tx_hash
+                    ));
+                }
+                Err(e) => return Err(format!("Error fetching identity: {}", e)),
+            }
+        } else {
+            // Parse the identity ID, or look up the DPNS name
+            let identity_id = match Identifier::from_string(identity_id_input, Encoding::Base58)
+                .or_else(|_| Identifier::from_string(identity_id_input, Encoding::Hex))
+            {
+                Ok(id) => id,
+                Err(e) => match dpns_label(identity_id_input) {
+                    Some(label) => self.fetch_dpns_name_owner(sdk, label).await?.1,
+                    None => return Err(format!("Identifier error: {}", e)),
+                },
+            };
+
+            // Fetch the identity using the SDK
+            match Identity::fetch_by_identifier(sdk, identity_id).await {
+                Ok(Some(identity)) => identity,
+                Ok(None) => return Err("Identity not found".to_string()),
+                Err(e) => return Err(format!("Error fetching identity: {}", e)),
+            }
         };
+        let identity_id = identity.id();
 
         let mut encrypted_private_keys = BTreeMap::new();
 
@@ -160,20 +239,6 @@ impl AppContext {
         // let mut wallet_seed_hash: Option<(WalletSeedHash, u32)> = None;
 
         if identity_type == IdentityType::User {
-            let input_private_keys = keys_input
-                .into_iter()
-                .filter_map(|key_string| {
-                    Some(
-                        verify_key_input(key_string, "User Key")
-                            .transpose()?
-                            .and_then(|sk| {
-                                PrivateKey::from_slice(sk.as_slice(), self.network)
-                                    .map_err(|e| e.to_string())
-                            }),
-                    )
-                })
-                .collect::<Result<Vec<PrivateKey>, String>>()?;
-
             let secp = Secp256k1::new();
             #[allow(clippy::type_complexity)]
             let (public_key_lookup, public_key_hash_lookup): (
@@ -315,4 +380,24 @@ impl AppContext {
             "Successfully loaded identity".to_string(),
         ))
     }
+
+    /// The identity one of `private_keys` belongs to, found by the hash of its public key.
+    /// Only keys that are unique on Platform can be found this way.
+    async fn fetch_identity_by_private_keys(
+        &self,
+        sdk: &Sdk,
+        private_keys: &[PrivateKey],
+    ) -> Result<Option<Identity>, String> {
+        let secp = Secp256k1::new();
+        for private_key in private_keys {
+            let public_key_hash = private_key.public_key(&secp).pubkey_hash().to_byte_array();
+            if let Some(identity) = Identity::fetch(sdk, PublicKeyHash(public_key_hash))
+                .await
+                .map_err(|e| format!("Error fetching identity by public key hash: {}", e))?
+            {
+                return Ok(Some(identity));
+            }
+        }
+        Ok(None)
+    }
 }
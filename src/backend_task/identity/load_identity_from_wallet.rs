@@ -41,6 +41,22 @@ impl AppContext {
             return Ok(BackendTaskSuccessResult::None);
         };
 
+        self.import_wallet_identity(&wallet_arc_ref, identity_index, identity)
+            .await?;
+
+        Ok(BackendTaskSuccessResult::Message(
+            "Successfully loaded identity".to_string(),
+        ))
+    }
+
+    /// Saves `identity`, found at `identity_index` of the wallet, as a local identity with its
+    /// DPNS names and the private keys the wallet can derive for it.
+    pub(super) async fn import_wallet_identity(
+        &self,
+        wallet_arc_ref: &WalletArcRef,
+        identity_index: IdentityIndex,
+        identity: Identity,
+    ) -> Result<QualifiedIdentity, String> {
         let identity_id = identity.id();
 
         // Fetch DPNS names using SDK
@@ -150,9 +166,13 @@ impl AppContext {
             &Some((wallet_seed_hash, identity_index)),
         )
         .map_err(|e| format!("Database error: {}", e))?;
+        wallet_arc_ref
+            .wallet
+            .write()
+            .unwrap()
+            .identities
+            .insert(identity_index, qualified_identity.identity.clone());
 
-        Ok(BackendTaskSuccessResult::Message(
-            "Successfully loaded identity".to_string(),
-        ))
+        Ok(qualified_identity)
     }
 }
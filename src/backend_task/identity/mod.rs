@@ -1,12 +1,16 @@
 mod add_key_to_identity;
+mod import_wallet_masternodes;
 mod load_identity;
 mod load_identity_from_wallet;
+mod recover_identities_from_wallet;
 mod refresh_identity;
 mod refresh_loaded_identities_dpns_names;
 mod register_dpns_name;
 mod register_identity;
+mod resolve_dpns_name;
 mod top_up_identity;
 mod transfer;
+mod update_identity_keys;
 mod withdraw_from_identity;
 
 use super::BackendTaskSuccessResult;
@@ -15,6 +19,7 @@ use crate::context::AppContext;
 use crate::model::qualified_identity::encrypted_key_storage::{KeyStorage, WalletDerivationPath};
 use crate::model::qualified_identity::qualified_identity_public_key::QualifiedIdentityPublicKey;
 use crate::model::qualified_identity::{IdentityType, PrivateKeyTarget, QualifiedIdentity};
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
 use crate::model::wallet::{Wallet, WalletArcRef, WalletSeedHash};
 use dash_sdk::Sdk;
 use dash_sdk::dashcore_rpc::dashcore::bip32::DerivationPath;
@@ -33,6 +38,7 @@ use dash_sdk::dpp::prelude::AssetLockProof;
 use dash_sdk::platform::{Identifier, Identity, IdentityPublicKey};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::{Arc, RwLock};
+use zeroize::Zeroize;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct IdentityInputToLoad {
@@ -193,18 +199,30 @@ impl IdentityKeys {
 
 pub type IdentityIndex = u32;
 pub type TopUpIndex = u32;
+
+/// Unused identity indices in a row after which a wallet recovery scan stops.
+pub const DEFAULT_IDENTITY_RECOVERY_GAP: u32 = 5;
+
+/// BIP44 account of a wallet.
+pub type AccountIndex = u32;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RegisterIdentityFundingMethod {
     UseAssetLock(Address, Box<AssetLockProof>, Box<Transaction>),
     FundWithUtxo(OutPoint, TxOut, Address, IdentityIndex),
-    FundWithWallet(Duffs, IdentityIndex),
+    FundWithWallet(Duffs, IdentityIndex, AccountIndex, CoinSelectionStrategy),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TopUpIdentityFundingMethod {
     UseAssetLock(Address, Box<AssetLockProof>, Box<Transaction>),
     FundWithUtxo(OutPoint, TxOut, Address, IdentityIndex, TopUpIndex),
-    FundWithWallet(Duffs, IdentityIndex, TopUpIndex),
+    FundWithWallet(
+        Duffs,
+        IdentityIndex,
+        TopUpIndex,
+        AccountIndex,
+        CoinSelectionStrategy,
+    ),
 }
 
 #[derive(Debug, Clone)]
@@ -247,24 +265,40 @@ pub struct RegisterDpnsNameInput {
 #[derive(Debug, Clone, PartialEq)]
 pub enum IdentityTask {
     LoadIdentity(IdentityInputToLoad),
+    /// Loads the identities of the masternodes of the Core wallet, with the keys it holds
+    ImportWalletMasternodes,
     #[allow(dead_code)] // May be used for finding identities in wallets
     SearchIdentityFromWallet(WalletArcRef, IdentityIndex),
+    /// Imports every identity of the wallet, stopping after the given gap of unused indices
+    RecoverIdentitiesFromWallet(WalletArcRef, u32),
     RegisterIdentity(IdentityRegistrationInfo),
     TopUpIdentity(IdentityTopUpInfo),
     AddKeyToIdentity(QualifiedIdentity, QualifiedIdentityPublicKey, [u8; 32]),
+    DisableKeys(QualifiedIdentity, Vec<KeyID>),
+    /// Adds the replacement key and disables the key with the given id in one update
+    RotateKey(
+        QualifiedIdentity,
+        KeyID,
+        QualifiedIdentityPublicKey,
+        [u8; 32],
+    ),
     WithdrawFromIdentity(QualifiedIdentity, Option<Address>, Credits, Option<KeyID>),
     Transfer(QualifiedIdentity, Identifier, Credits, Option<KeyID>),
     RegisterDpnsName(RegisterDpnsNameInput),
     RefreshIdentity(QualifiedIdentity),
     RefreshLoadedIdentitiesOwnedDPNSNames,
+    /// Looks up the identity a DPNS username such as `alice.dash` points to
+    ResolveDpnsName(String),
 }
 
-fn verify_key_input(
-    untrimmed_private_key: String,
+/// Parses a private key given in hex or WIF, zeroizing the input.
+pub(crate) fn verify_key_input(
+    mut untrimmed_private_key: String,
     type_key: &str,
 ) -> Result<Option<[u8; 32]>, String> {
-    let private_key = untrimmed_private_key.trim().to_string();
-    match private_key.len() {
+    let mut private_key = untrimmed_private_key.trim().to_string();
+    untrimmed_private_key.zeroize();
+    let result = match private_key.len() {
         64 => {
             // hex
             match hex::decode(private_key.as_str()) {
@@ -287,7 +321,9 @@ fn verify_key_input(
         }
         0 => Ok(None),
         _ => Err(format!("{} key is of incorrect size", type_key)),
-    }
+    };
+    private_key.zeroize();
+    result
 }
 
 impl AppContext {
@@ -451,6 +487,20 @@ impl AppContext {
                 self.add_key_to_identity(sdk, qualified_identity, public_key_to_add, private_key)
                     .await
             }
+            IdentityTask::DisableKeys(qualified_identity, key_ids) => {
+                self.disable_identity_keys(sdk, qualified_identity, key_ids)
+                    .await
+            }
+            IdentityTask::RotateKey(qualified_identity, key_id, replacement_key, private_key) => {
+                self.rotate_identity_key(
+                    sdk,
+                    qualified_identity,
+                    key_id,
+                    replacement_key,
+                    private_key,
+                )
+                .await
+            }
             IdentityTask::RegisterIdentity(registration_info) => {
                 self.register_identity(registration_info, sender).await
             }
@@ -467,12 +517,20 @@ impl AppContext {
                 self.load_user_identity_from_wallet(sdk, wallet, identity_index)
                     .await
             }
+            IdentityTask::RecoverIdentitiesFromWallet(wallet, gap) => {
+                self.recover_identities_from_wallet(sdk, wallet, gap).await
+            }
             IdentityTask::TopUpIdentity(top_up_info) => {
                 self.top_up_identity(top_up_info, sender).await
             }
             IdentityTask::RefreshLoadedIdentitiesOwnedDPNSNames => {
                 self.refresh_loaded_identities_dpns_names(sender).await
             }
+            IdentityTask::ImportWalletMasternodes => self.import_wallet_masternodes(sdk).await,
+            IdentityTask::ResolveDpnsName(name) => {
+                let resolution = self.resolve_dpns_name(sdk, &name).await;
+                Ok(BackendTaskSuccessResult::ResolvedDpnsName(name, resolution))
+            }
         }
     }
 }
@@ -0,0 +1,189 @@
+use super::{BackendTaskSuccessResult, IdentityIndex, TopUpIndex};
+use crate::context::AppContext;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::wallet::WalletArcRef;
+use dash_sdk::Sdk;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::hashes::Hash;
+use dash_sdk::dpp::dashcore::transaction::special_transaction::TransactionPayload;
+use dash_sdk::dpp::dashcore::{Address, ScriptBuf};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::platform::types::identity::PublicKeyHash;
+use dash_sdk::platform::{Fetch, Identity};
+use std::collections::HashMap;
+
+impl AppContext {
+    /// Walks the identity indices of the wallet, importing every identity registered with one
+    /// of them, until `gap` indices in a row have no identity. The top ups of the identities
+    /// found are imported as well, so new top ups don't reuse their keys.
+    pub(super) async fn recover_identities_from_wallet(
+        &self,
+        sdk: &Sdk,
+        wallet_arc_ref: WalletArcRef,
+        gap: u32,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let mut recovered = Vec::new();
+        let mut misses = 0;
+        let mut identity_index: IdentityIndex = 0;
+        while misses < gap.max(1) {
+            let public_key = wallet_arc_ref
+                .wallet
+                .read()
+                .map_err(|e| e.to_string())?
+                .identity_authentication_ecdsa_public_key(self.network, identity_index, 0)?;
+            let identity =
+                Identity::fetch(sdk, PublicKeyHash(public_key.pubkey_hash().to_byte_array()))
+                    .await
+                    .map_err(|e| {
+                        format!("Error fetching identity at index {}: {}", identity_index, e)
+                    })?;
+            match identity {
+                Some(identity) => {
+                    misses = 0;
+                    let qualified_identity = self
+                        .import_wallet_identity(&wallet_arc_ref, identity_index, identity)
+                        .await?;
+                    recovered.push((identity_index, qualified_identity));
+                }
+                None => misses += 1,
+            }
+            identity_index += 1;
+        }
+
+        if recovered.is_empty() {
+            return Ok(BackendTaskSuccessResult::Message(format!(
+                "No identities found in the first {} identity indices of the wallet",
+                identity_index
+            )));
+        }
+
+        // Top ups are only found in the transaction history, not having one shouldn't fail the
+        // recovery of the identities themselves
+        let mut top_up_count = 0;
+        let mut top_up_error = None;
+        match self.wallet_asset_lock_credits(&wallet_arc_ref) {
+            Ok(credits) => {
+                for (identity_index, qualified_identity) in &recovered {
+                    top_up_count += self.import_top_ups(
+                        &wallet_arc_ref,
+                        *identity_index,
+                        qualified_identity,
+                        &credits,
+                        gap,
+                    )?;
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Could not look up identity top ups: {}", e);
+                top_up_error = Some(e);
+            }
+        }
+
+        let recovered_message = format!(
+            "Recovered {} identit{}",
+            recovered.len(),
+            if recovered.len() == 1 { "y" } else { "ies" },
+        );
+        Ok(BackendTaskSuccessResult::Message(match top_up_error {
+            None => format!(
+                "{} with {} top up{}",
+                recovered_message,
+                top_up_count,
+                if top_up_count == 1 { "" } else { "s" }
+            ),
+            Some(e) => format!(
+                "{}, but their top ups could not be looked up: {}",
+                recovered_message, e
+            ),
+        }))
+    }
+
+    /// Credited amount of each asset lock credit output in the full transaction history of
+    /// the wallet, by the script it pays to.
+    fn wallet_asset_lock_credits(
+        &self,
+        wallet_arc_ref: &WalletArcRef,
+    ) -> Result<HashMap<ScriptBuf, Duffs>, String> {
+        let addresses: Vec<Address> = wallet_arc_ref
+            .wallet
+            .read()
+            .map_err(|e| e.to_string())?
+            .known_addresses
+            .iter()
+            .filter(|(_, derivation_path)| derivation_path.is_bip44(self.network))
+            .map(|(address, _)| address.clone())
+            .collect();
+
+        self.with_chain_data(|chain_data| {
+            if !chain_data.has_transaction_history() {
+                return Err(
+                    "the chain data source has no transaction history, use Dash Core's wallet or Insight"
+                        .to_string(),
+                );
+            }
+            let transactions = chain_data.recent_transactions(&addresses, usize::MAX)?;
+
+            let mut credits = HashMap::new();
+            // Asset locks are funded by the wallet, so they are its own spends
+            for transaction in transactions.iter().filter(|tx| tx.received == 0) {
+                let transaction = chain_data.fetch_transaction(&transaction.txid)?;
+                if let Some(TransactionPayload::AssetLockPayloadType(payload)) =
+                    transaction.special_transaction_payload
+                {
+                    for credit_output in payload.credit_outputs {
+                        *credits.entry(credit_output.script_pubkey).or_insert(0) +=
+                            credit_output.value;
+                    }
+                }
+            }
+            Ok(credits)
+        })
+    }
+
+    /// Records the top ups of `qualified_identity` found among `credits`, walking its top-up
+    /// indices until `gap` of them in a row are unused. Returns how many were found.
+    fn import_top_ups(
+        &self,
+        wallet_arc_ref: &WalletArcRef,
+        identity_index: IdentityIndex,
+        qualified_identity: &QualifiedIdentity,
+        credits: &HashMap<ScriptBuf, Duffs>,
+        gap: u32,
+    ) -> Result<u32, String> {
+        let secp = Secp256k1::new();
+        let mut found = 0;
+        let mut misses = 0;
+        let mut top_up_index: TopUpIndex = 0;
+        while misses < gap.max(1) {
+            let private_key = wallet_arc_ref
+                .wallet
+                .write()
+                .map_err(|e| e.to_string())?
+                .identity_top_up_ecdsa_private_key(
+                    self.network,
+                    identity_index,
+                    top_up_index,
+                    None,
+                )?;
+            let script =
+                Address::p2pkh(&private_key.public_key(&secp), self.network).script_pubkey();
+            match credits.get(&script) {
+                Some(amount) => {
+                    misses = 0;
+                    found += 1;
+                    self.db
+                        .insert_top_up_if_missing(
+                            qualified_identity.identity.id().as_bytes(),
+                            top_up_index,
+                            *amount,
+                        )
+                        .map_err(|e| e.to_string())?;
+                }
+                None => misses += 1,
+            }
+            top_up_index += 1;
+        }
+        Ok(found)
+    }
+}
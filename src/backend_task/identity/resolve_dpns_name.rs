@@ -0,0 +1,120 @@
+use crate::context::AppContext;
+use crate::model::dpns_name::{DPNS_PARENT_DOMAIN, DpnsNameResolution, dpns_label};
+use dash_sdk::Sdk;
+use dash_sdk::dpp::document::DocumentV0Getters;
+use dash_sdk::dpp::platform_value::Value;
+use dash_sdk::dpp::util::strings::convert_to_homograph_safe_chars;
+use dash_sdk::drive::query::{WhereClause, WhereOperator};
+use dash_sdk::platform::{Document, DocumentQuery, FetchMany, Identifier};
+
+impl AppContext {
+    /// Looks up the identity the DPNS username `name` points to, along with its other names.
+    pub(super) async fn resolve_dpns_name(
+        &self,
+        sdk: &Sdk,
+        name: &str,
+    ) -> Result<DpnsNameResolution, String> {
+        let label = dpns_label(name).ok_or(format!("\"{}\" is not a valid DPNS name", name))?;
+
+        let (registered_label, identity_id) = self.fetch_dpns_name_owner(sdk, label).await?;
+
+        let other_names = self
+            .fetch_dpns_labels(sdk, identity_id)
+            .await?
+            .into_iter()
+            .filter(|other_label| *other_label != registered_label)
+            .map(|other_label| format!("{}.{}", other_label, DPNS_PARENT_DOMAIN))
+            .collect();
+
+        Ok(DpnsNameResolution {
+            query: name.to_string(),
+            label: registered_label,
+            identity_id,
+            other_names,
+        })
+    }
+
+    /// The registered label of the DPNS name with the given `label` and the identity it points
+    /// to. Labels are matched the way Platform does, ignoring case and look-alike characters.
+    pub(super) async fn fetch_dpns_name_owner(
+        &self,
+        sdk: &Sdk,
+        label: &str,
+    ) -> Result<(String, Identifier), String> {
+        let name_query = DocumentQuery {
+            data_contract: self.dpns_contract.clone(),
+            document_type_name: "domain".to_string(),
+            where_clauses: vec![
+                WhereClause {
+                    field: "normalizedParentDomainName".to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Text(DPNS_PARENT_DOMAIN.to_string()),
+                },
+                WhereClause {
+                    field: "normalizedLabel".to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Text(convert_to_homograph_safe_chars(label)),
+                },
+            ],
+            order_by_clauses: vec![],
+            limit: 1,
+            start: None,
+        };
+        let document = Document::fetch_many(sdk, name_query)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_values()
+            .flatten()
+            .next()
+            .ok_or(format!(
+                "No identity has the name {}.{}",
+                label, DPNS_PARENT_DOMAIN
+            ))?;
+
+        let registered_label = document
+            .get("label")
+            .and_then(|label| label.as_text())
+            .unwrap_or(label)
+            .to_string();
+        let identity_id = match document.get("records") {
+            Some(Value::Map(records)) => records
+                .iter()
+                .find(|(key, _)| key.as_text() == Some("identity"))
+                .and_then(|(_, value)| value.to_identifier().ok()),
+            _ => None,
+        }
+        .ok_or(format!(
+            "The name {}.{} doesn't point to an identity",
+            registered_label, DPNS_PARENT_DOMAIN
+        ))?;
+
+        Ok((registered_label, identity_id))
+    }
+
+    async fn fetch_dpns_labels(
+        &self,
+        sdk: &Sdk,
+        identity_id: Identifier,
+    ) -> Result<Vec<String>, String> {
+        let names_query = DocumentQuery {
+            data_contract: self.dpns_contract.clone(),
+            document_type_name: "domain".to_string(),
+            where_clauses: vec![WhereClause {
+                field: "records.identity".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.into()),
+            }],
+            order_by_clauses: vec![],
+            limit: 100,
+            start: None,
+        };
+        let documents = Document::fetch_many(sdk, names_query)
+            .await
+            .map_err(|e| format!("Error fetching DPNS names: {}", e))?;
+        Ok(documents
+            .values()
+            .flatten()
+            .filter_map(|document| document.get("label")?.as_text().map(str::to_string))
+            .collect())
+    }
+}
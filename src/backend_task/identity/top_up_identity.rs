@@ -15,7 +15,7 @@ use dash_sdk::dpp::state_transition::identity_topup_transition::IdentityTopUpTra
 use dash_sdk::dpp::state_transition::identity_topup_transition::methods::IdentityTopUpTransitionMethodsV0;
 use dash_sdk::platform::Fetch;
 use dash_sdk::platform::transition::top_up_identity::TopUpIdentity;
-use std::time::Duration;
+use std::collections::BTreeMap;
 
 impl AppContext {
     pub(super) async fn top_up_identity(
@@ -86,86 +86,58 @@ impl AppContext {
                     amount,
                     identity_index,
                     top_up_index,
+                    account,
+                    coin_selection,
                 ) => {
+                    let fee_rate = self.refresh_fee_rate();
                     // Scope the write lock to avoid holding it across an await.
                     let (asset_lock_transaction, asset_lock_proof_private_key, _, used_utxos) = {
                         let mut wallet = wallet.write().unwrap();
-                        match wallet.top_up_asset_lock_transaction(
+                        let transaction = match wallet.top_up_asset_lock_transaction(
                             sdk.network,
                             amount,
                             true,
                             identity_index,
                             top_up_index,
+                            account,
+                            coin_selection,
+                            fee_rate,
                             Some(self),
                         ) {
                             Ok(transaction) => transaction,
                             Err(_) => {
-                                wallet
-                                    .reload_utxos(
-                                        &self
-                                            .core_client
-                                            .read()
-                                            .expect("Core client lock was poisoned"),
-                                        self.network,
-                                        Some(self),
-                                    )
-                                    .map_err(|e| e.to_string())?;
+                                self.with_chain_data(|chain_data| {
+                                    wallet.reload_utxos(chain_data, self.network, Some(self))
+                                })?;
                                 wallet.top_up_asset_lock_transaction(
                                     sdk.network,
                                     amount,
                                     true,
                                     identity_index,
                                     top_up_index,
+                                    account,
+                                    coin_selection,
+                                    fee_rate,
                                     Some(self),
                                 )?
                             }
-                        }
+                        };
+                        let (asset_lock_transaction, _, _, used_utxos) = &transaction;
+                        wallet.reserve_utxos(
+                            used_utxos.keys().copied(),
+                            asset_lock_transaction.txid(),
+                            Some(self),
+                        )?;
+                        transaction
                     };
 
-                    let tx_id = asset_lock_transaction.txid();
-                    // todo: maybe one day we will want to use platform again, but for right now we use
-                    //  the local core as it is more stable
-                    // let asset_lock_proof = self
-                    //     .broadcast_and_retrieve_asset_lock(&asset_lock_transaction, &change_address)
-                    //     .await
-                    //     .map_err(|e| e.to_string())?;
-
-                    {
-                        let mut proofs = self.transactions_waiting_for_finality.lock().unwrap();
-                        proofs.insert(tx_id, None);
-                    }
-
-                    self.core_client
-                        .read()
-                        .expect("Core client lock was poisoned")
-                        .send_raw_transaction(&asset_lock_transaction)
-                        .map_err(|e| e.to_string())?;
-
-                    {
-                        let mut wallet = wallet.write().unwrap();
-                        wallet.utxos.retain(|_, utxo_map| {
-                            utxo_map.retain(|outpoint, _| !used_utxos.contains_key(outpoint));
-                            !utxo_map.is_empty() // Keep addresses that still have UTXOs
-                        });
-                        for utxo in used_utxos.keys() {
-                            self.db
-                                .drop_utxo(utxo, &self.network.to_string())
-                                .map_err(|e| e.to_string())?;
-                        }
-                    }
-
-                    let asset_lock_proof;
-
-                    loop {
-                        {
-                            let proofs = self.transactions_waiting_for_finality.lock().unwrap();
-                            if let Some(Some(proof)) = proofs.get(&tx_id) {
-                                asset_lock_proof = proof.clone();
-                                break;
-                            }
-                        }
-                        tokio::time::sleep(Duration::from_millis(200)).await;
-                    }
+                    let tx_id = self.broadcast_wallet_asset_lock(
+                        &wallet,
+                        &asset_lock_transaction,
+                        &used_utxos,
+                        Some(qualified_identity.identity.id()),
+                    )?;
+                    let asset_lock_proof = self.wait_for_asset_lock_proof(&tx_id).await;
 
                     (
                         asset_lock_proof,
@@ -181,62 +153,39 @@ impl AppContext {
                     identity_index,
                     top_up_index,
                 ) => {
+                    let fee_rate = self.refresh_fee_rate();
                     // Scope the write lock to avoid holding it across an await.
                     let (asset_lock_transaction, asset_lock_proof_private_key) = {
                         let mut wallet = wallet.write().unwrap();
-                        wallet.top_up_asset_lock_transaction_for_utxo(
+                        if let Some(pending_txid) = wallet.utxo_reserved_for(&utxo) {
+                            return Err(format!(
+                                "Output {} is already being spent by pending transaction {}",
+                                utxo, pending_txid
+                            ));
+                        }
+                        let transaction = wallet.top_up_asset_lock_transaction_for_utxo(
                             sdk.network,
                             utxo,
                             tx_out.clone(),
                             input_address.clone(),
                             identity_index,
                             top_up_index,
+                            fee_rate,
                             Some(self),
-                        )?
+                        )?;
+                        wallet.reserve_utxos([utxo], transaction.0.txid(), Some(self))?;
+                        transaction
                     };
 
-                    let tx_id = asset_lock_transaction.txid();
-                    // todo: maybe one day we will want to use platform again, but for right now we use
-                    //  the local core as it is more stable
-                    // let asset_lock_proof = self
-                    //     .broadcast_and_retrieve_asset_lock(&asset_lock_transaction, &change_address)
-                    //     .await
-                    //     .map_err(|e| e.to_string())?;
-
-                    {
-                        let mut proofs = self.transactions_waiting_for_finality.lock().unwrap();
-                        proofs.insert(tx_id, None);
-                    }
-
-                    self.core_client
-                        .read()
-                        .expect("Core client lock was poisoned")
-                        .send_raw_transaction(&asset_lock_transaction)
-                        .map_err(|e| e.to_string())?;
-
-                    {
-                        let mut wallet = wallet.write().unwrap();
-                        wallet.utxos.retain(|_, utxo_map| {
-                            utxo_map.retain(|outpoint, _| outpoint != &utxo);
-                            !utxo_map.is_empty()
-                        });
-                        self.db
-                            .drop_utxo(&utxo, &self.network.to_string())
-                            .map_err(|e| e.to_string())?;
-                    }
-
-                    let asset_lock_proof;
-
-                    loop {
-                        {
-                            let proofs = self.transactions_waiting_for_finality.lock().unwrap();
-                            if let Some(Some(proof)) = proofs.get(&tx_id) {
-                                asset_lock_proof = proof.clone();
-                                break;
-                            }
-                        }
-                        tokio::time::sleep(Duration::from_millis(200)).await;
-                    }
+                    let used_utxos =
+                        BTreeMap::from([(utxo, (tx_out.clone(), input_address.clone()))]);
+                    let tx_id = self.broadcast_wallet_asset_lock(
+                        &wallet,
+                        &asset_lock_transaction,
+                        &used_utxos,
+                        Some(qualified_identity.identity.id()),
+                    )?;
+                    let asset_lock_proof = self.wait_for_asset_lock_proof(&tx_id).await;
 
                     (
                         asset_lock_proof,
@@ -321,6 +270,14 @@ impl AppContext {
             )
             .map_err(|e| e.to_string())?;
 
+        self.db
+            .set_wallet_transaction_identity_id(
+                tx_id.as_byte_array(),
+                qualified_identity.identity.id().as_bytes(),
+                self.network,
+            )
+            .map_err(|e| e.to_string())?;
+
         if let Some((amount, top_up_index)) = top_up_index {
             self.db
                 .insert_top_up(
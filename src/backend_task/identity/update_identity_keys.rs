@@ -0,0 +1,173 @@
+use super::BackendTaskSuccessResult;
+use crate::context::AppContext;
+use crate::model::qualified_identity::PrivateKeyTarget::PrivateKeyOnMainIdentity;
+use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::qualified_identity::qualified_identity_public_key::QualifiedIdentityPublicKey;
+use chrono::Utc;
+use dash_sdk::Sdk;
+use dash_sdk::dpp::identity::KeyID;
+use dash_sdk::dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::{
+    IdentityPublicKeyGettersV0, IdentityPublicKeySettersV0,
+};
+use dash_sdk::dpp::prelude::UserFeeIncrease;
+use dash_sdk::dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
+use dash_sdk::dpp::state_transition::identity_update_transition::methods::IdentityUpdateTransitionMethodsV0;
+use dash_sdk::dpp::state_transition::proof_result::StateTransitionProofResult;
+use dash_sdk::platform::transition::broadcast::BroadcastStateTransition;
+use dash_sdk::platform::{Fetch, Identity};
+
+impl AppContext {
+    /// Adds `keys_to_add` to the identity and disables `key_ids_to_disable` in a single
+    /// identity update signed by the master key. The new keys get the next free key ids.
+    ///
+    /// Returns the updated identity, which is also saved locally.
+    pub(super) async fn update_identity_keys(
+        &self,
+        sdk: &Sdk,
+        mut qualified_identity: QualifiedIdentity,
+        keys_to_add: Vec<(QualifiedIdentityPublicKey, [u8; 32])>,
+        key_ids_to_disable: Vec<KeyID>,
+    ) -> Result<QualifiedIdentity, String> {
+        let new_identity_nonce = sdk
+            .get_identity_nonce(qualified_identity.identity.id(), true, None)
+            .await
+            .map_err(|e| format!("Fetch nonce error: {}", e))?;
+        let Some(master_key) = qualified_identity.can_sign_with_master_key() else {
+            return Err("Master key not found".to_string());
+        };
+        let master_key_id = master_key.identity_public_key.id();
+        let identity = Identity::fetch_by_identifier(sdk, qualified_identity.identity.id())
+            .await
+            .map_err(|e| format!("Fetch identity error: {}", e))?
+            .ok_or("Identity not found".to_string())?;
+        qualified_identity.identity = identity;
+
+        for key_id in &key_ids_to_disable {
+            if let Some(reason) = qualified_identity.key_update_blocker(*key_id) {
+                return Err(reason);
+            }
+        }
+
+        qualified_identity.identity.bump_revision();
+        let mut next_key_id = qualified_identity.identity.get_public_key_max_id() + 1;
+        let mut public_keys_to_add = Vec::with_capacity(keys_to_add.len());
+        for (mut public_key_to_add, private_key) in keys_to_add {
+            public_key_to_add.identity_public_key.set_id(next_key_id);
+            next_key_id += 1;
+            qualified_identity.private_keys.insert_non_encrypted(
+                (
+                    PrivateKeyOnMainIdentity,
+                    public_key_to_add.identity_public_key.id(),
+                ),
+                (public_key_to_add.clone(), private_key),
+            );
+            public_keys_to_add.push(public_key_to_add.identity_public_key);
+        }
+
+        let state_transition = IdentityUpdateTransition::try_from_identity_with_signer(
+            &qualified_identity.identity,
+            &master_key_id,
+            public_keys_to_add.clone(),
+            key_ids_to_disable.clone(),
+            new_identity_nonce,
+            UserFeeIncrease::default(),
+            &qualified_identity,
+            sdk.version(),
+            None,
+        )
+        .map_err(|e| format!("IdentityUpdateTransition error: {}", e))?;
+
+        let result = state_transition
+            .broadcast_and_wait(sdk, None)
+            .await
+            .map_err(|e| format!("Broadcasting error: {}", e))?;
+
+        for public_key in public_keys_to_add {
+            qualified_identity.identity.add_public_key(public_key);
+        }
+        // Platform sets the disabled time; until the identity is refreshed, ours is close enough
+        let disabled_at = Utc::now().timestamp_millis() as u64;
+        for key_id in &key_ids_to_disable {
+            if let Some(mut key) = qualified_identity
+                .identity
+                .get_public_key_by_id(*key_id)
+                .cloned()
+            {
+                key.set_disabled_at(disabled_at);
+                qualified_identity.identity.add_public_key(key);
+            }
+        }
+        if let StateTransitionProofResult::VerifiedPartialIdentity(identity) = result {
+            for public_key in identity.loaded_public_keys.into_values() {
+                qualified_identity.identity.add_public_key(public_key);
+            }
+        }
+        qualified_identity
+            .private_keys
+            .refresh_public_keys(&qualified_identity.identity);
+
+        self.update_local_qualified_identity(&qualified_identity)
+            .map_err(|e| format!("Database error: {}", e))?;
+        Ok(qualified_identity)
+    }
+
+    pub(super) async fn disable_identity_keys(
+        &self,
+        sdk: &Sdk,
+        qualified_identity: QualifiedIdentity,
+        key_ids: Vec<KeyID>,
+    ) -> Result<BackendTaskSuccessResult, String> {
+        if key_ids.is_empty() {
+            return Err("No keys selected to disable".to_string());
+        }
+        let key_list = key_ids
+            .iter()
+            .map(|key_id| key_id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.update_identity_keys(sdk, qualified_identity, vec![], key_ids)
+            .await?;
+        Ok(BackendTaskSuccessResult::Message(format!(
+            "Successfully disabled key(s) {}",
+            key_list
+        )))
+    }
+
+    /// Replaces the key `key_id` by `replacement_key`, which should have the same purpose and
+    /// security level, adding the one and disabling the other in the same update.
+    pub(super) async fn rotate_identity_key(
+        &self,
+        sdk: &Sdk,
+        qualified_identity: QualifiedIdentity,
+        key_id: KeyID,
+        replacement_key: QualifiedIdentityPublicKey,
+        replacement_private_key: [u8; 32],
+    ) -> Result<BackendTaskSuccessResult, String> {
+        let old_key = qualified_identity
+            .identity
+            .get_public_key_by_id(key_id)
+            .ok_or(format!("Key {} not found on identity", key_id))?;
+        if old_key.purpose() != replacement_key.identity_public_key.purpose()
+            || old_key.security_level() != replacement_key.identity_public_key.security_level()
+        {
+            return Err(
+                "The replacement key must have the same purpose and security level".to_string(),
+            );
+        }
+
+        let qualified_identity = self
+            .update_identity_keys(
+                sdk,
+                qualified_identity,
+                vec![(replacement_key, replacement_private_key)],
+                vec![key_id],
+            )
+            .await?;
+        Ok(BackendTaskSuccessResult::Message(format!(
+            "Successfully rotated key {} to key {}",
+            key_id,
+            qualified_identity.identity.get_public_key_max_id()
+        )))
+    }
+}
@@ -7,6 +7,7 @@ use crate::backend_task::identity::IdentityTask;
 use crate::backend_task::platform_info::{PlatformInfoTaskRequestType, PlatformInfoTaskResult};
 use crate::backend_task::system_task::SystemTask;
 use crate::context::AppContext;
+use crate::model::dpns_name::DpnsNameResolution;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::ui::tokens::tokens_screen::{
     ContractDescriptionInfo, IdentityTokenIdentifier, TokenInfo,
@@ -26,6 +27,7 @@ use dash_sdk::query_types::{Documents, IndexMap};
 use futures::future::join_all;
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::path::PathBuf;
 use tokens::TokenTask;
 
 pub mod broadcast_state_transition;
@@ -99,6 +101,11 @@ pub enum BackendTaskSuccessResult {
     },
     UpdatedThemePreference(crate::ui::theme::ThemeMode),
     PlatformInfo(PlatformInfoTaskResult),
+    /// Name that was looked up, and the identity it points to or why the lookup failed
+    ResolvedDpnsName(String, Result<DpnsNameResolution, String>),
+    ExportedBackup(PathBuf),
+    /// Number of restored rows per table
+    RestoredBackup(BTreeMap<String, usize>),
 }
 
 impl BackendTaskSuccessResult {}
@@ -162,7 +169,7 @@ impl AppContext {
             BackendTask::DocumentTask(document_task) => {
                 self.run_document_task(*document_task, &sdk).await
             }
-            BackendTask::CoreTask(core_task) => self.run_core_task(core_task).await,
+            BackendTask::CoreTask(core_task) => self.run_core_task(core_task, sender).await,
             BackendTask::BroadcastStateTransition(state_transition) => {
                 self.broadcast_state_transition(state_transition, &sdk)
                     .await
@@ -0,0 +1,53 @@
+use crate::backend_task::BackendTaskSuccessResult;
+use crate::context::AppContext;
+use crate::database::backup::Backup;
+use std::fmt;
+use std::path::PathBuf;
+use zeroize::Zeroize;
+
+/// A backup file to write or read, with the password it is encrypted with.
+#[derive(Clone, PartialEq)]
+pub struct BackupFile {
+    pub path: PathBuf,
+    pub password: String,
+}
+
+impl fmt::Debug for BackupFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BackupFile")
+            .field("path", &self.path)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Drop for BackupFile {
+    fn drop(&mut self) {
+        self.password.zeroize();
+    }
+}
+
+impl AppContext {
+    /// Writes an encrypted backup of the database. Deriving the key is slow on purpose, so
+    /// this must not run on the UI thread.
+    pub fn export_backup(&self, file: &BackupFile) -> Result<BackendTaskSuccessResult, String> {
+        let bytes = self
+            .db
+            .create_backup()
+            .map_err(|e| e.to_string())?
+            .to_encrypted_bytes(&file.password)?;
+        std::fs::write(&file.path, bytes).map_err(|e| e.to_string())?;
+
+        Ok(BackendTaskSuccessResult::ExportedBackup(file.path.clone()))
+    }
+
+    /// Decrypts a backup and merges it into the database, see
+    /// [`crate::database::Database::restore_backup`].
+    pub fn restore_backup(&self, file: &BackupFile) -> Result<BackendTaskSuccessResult, String> {
+        let bytes = std::fs::read(&file.path).map_err(|e| e.to_string())?;
+        let backup = Backup::from_encrypted_bytes(&bytes, &file.password)?;
+        let restored = self.db.restore_backup(&backup)?;
+
+        Ok(BackendTaskSuccessResult::RestoredBackup(restored))
+    }
+}
@@ -1,3 +1,7 @@
+mod backup;
+
+pub use backup::BackupFile;
+
 use crate::app::TaskResult;
 use crate::backend_task::BackendTaskSuccessResult;
 use crate::context::AppContext;
@@ -8,6 +12,8 @@ use std::sync::Arc;
 pub enum SystemTask {
     WipePlatformData,
     UpdateThemePreference(ThemeMode),
+    ExportBackup(BackupFile),
+    RestoreBackup(BackupFile),
 }
 
 impl AppContext {
@@ -21,6 +27,8 @@ impl AppContext {
             SystemTask::UpdateThemePreference(theme_mode) => {
                 self.update_theme_preference(theme_mode)
             }
+            SystemTask::ExportBackup(file) => self.export_backup(&file),
+            SystemTask::RestoreBackup(file) => self.restore_backup(&file),
         }
     }
 
@@ -0,0 +1,215 @@
+use super::{ChainDataBackend, ChainTransaction, CoreUtxoScan};
+use crate::model::wallet::balance::{COINBASE_MATURITY, UtxoStatus};
+use crate::model::wallet::fee_estimation::FeeRate;
+use dash_sdk::dashcore_rpc::json::GetTransactionResultDetailCategory;
+use dash_sdk::dashcore_rpc::{Client, RpcApi};
+use dash_sdk::dpp::dashcore::{Address, OutPoint, Transaction, TxOut, Txid};
+use std::collections::{BTreeMap, HashSet};
+
+/// How many entries of Core's wallet history are requested at a time.
+const TRANSACTION_PAGE_SIZE: usize = 1000;
+
+impl ChainDataBackend for Client {
+    fn list_unspent(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String> {
+        // Calling list_unspent with an empty addresses vector will return all UTXOs,
+        // which is not what we want here. Instead, we handle the empty case explicitly.
+        if addresses.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let addresses: Vec<_> = addresses.iter().collect();
+        let utxos = RpcApi::list_unspent(self, None, None, Some(&addresses), Some(false), None)
+            .map_err(|e| e.to_string())?;
+
+        // Only young outputs can be InstantSend locked without confirmations, or immature
+        let mut young_transactions = BTreeMap::new();
+        for utxo in &utxos {
+            if utxo.confirmations < COINBASE_MATURITY
+                && !young_transactions.contains_key(&utxo.txid)
+            {
+                let status = match self.get_raw_transaction_info(&utxo.txid, None) {
+                    Ok(info) => UtxoStatus {
+                        confirmations: utxo.confirmations,
+                        instant_locked: info.instantlock,
+                        coinbase: info.is_coinbase(),
+                    },
+                    // Without -txindex, only mempool transactions can be looked up
+                    Err(_) => UtxoStatus {
+                        confirmations: utxo.confirmations,
+                        ..Default::default()
+                    },
+                };
+                young_transactions.insert(utxo.txid, status);
+            }
+        }
+
+        Ok(utxos
+            .into_iter()
+            .map(|utxo| {
+                let status = young_transactions
+                    .get(&utxo.txid)
+                    .copied()
+                    .unwrap_or(UtxoStatus {
+                        confirmations: utxo.confirmations,
+                        ..Default::default()
+                    });
+                (
+                    OutPoint::new(utxo.txid, utxo.vout),
+                    TxOut {
+                        value: utxo.amount.to_sat(),
+                        script_pubkey: utxo.script_pub_key,
+                    },
+                    status,
+                )
+            })
+            .collect())
+    }
+
+    /// Core's wallet only lists outputs of its own addresses, so the UTXO set is scanned,
+    /// which only holds confirmed outputs.
+    fn scan_unspent(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String> {
+        CoreUtxoScan(self).list_unspent(addresses)
+    }
+
+    fn received_by_address(
+        &self,
+        address: &Address,
+        include_unconfirmed: bool,
+    ) -> Result<u64, String> {
+        let min_confirmations = include_unconfirmed.then_some(0);
+        self.get_received_by_address(address, min_confirmations)
+            .map(|amount| amount.to_sat())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Core's wallet only knows the history of addresses that were rescanned, so outputs
+    /// that are still unspent are looked up in the UTXO set as well.
+    fn used_addresses(&self, addresses: &[Address]) -> Result<HashSet<Address>, String> {
+        let mut used = CoreUtxoScan(self).used_addresses(addresses)?;
+        for (address, received) in self.received_by_addresses(addresses, true)? {
+            if received > 0 {
+                used.insert(address);
+            }
+        }
+        Ok(used)
+    }
+
+    fn recent_transactions(
+        &self,
+        addresses: &[Address],
+        count: usize,
+    ) -> Result<Vec<ChainTransaction>, String> {
+        let scripts: HashSet<_> = addresses
+            .iter()
+            .map(|address| address.script_pubkey())
+            .collect();
+
+        // Core lists one entry per output, so payments to several of our addresses are summed.
+        // Pages go from the most recent entries back, until `count` transactions were seen.
+        let mut transactions: BTreeMap<Txid, ChainTransaction> = BTreeMap::new();
+        let mut skip = 0;
+        loop {
+            let entries = self
+                .list_transactions(None, Some(TRANSACTION_PAGE_SIZE), Some(skip), Some(true))
+                .map_err(|e| e.to_string())?;
+
+            for entry in entries.iter().rev() {
+                if !transactions.contains_key(&entry.info.txid) && transactions.len() == count {
+                    return Ok(transactions.into_values().collect());
+                }
+                let transaction =
+                    transactions
+                        .entry(entry.info.txid)
+                        .or_insert_with(|| ChainTransaction {
+                            txid: entry.info.txid,
+                            confirmations: entry.info.confirmations.max(0) as u32,
+                            timestamp: entry.info.time,
+                            received: 0,
+                        });
+
+                if entry.detail.category != GetTransactionResultDetailCategory::Receive {
+                    continue;
+                }
+                let is_ours = entry.detail.address.as_ref().is_some_and(|address| {
+                    scripts.contains(&address.clone().assume_checked().script_pubkey())
+                });
+                if is_ours {
+                    transaction.received += entry.detail.amount.to_sat().unsigned_abs();
+                }
+            }
+
+            if entries.len() < TRANSACTION_PAGE_SIZE {
+                return Ok(transactions.into_values().collect());
+            }
+            skip += entries.len();
+        }
+    }
+
+    fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction, String> {
+        // Without -txindex, only mempool transactions can be looked up, but the wallet keeps
+        // its own transactions
+        match self.get_raw_transaction(txid, None) {
+            Ok(transaction) => Ok(transaction),
+            Err(_) => self
+                .get_transaction(txid, Some(true))
+                .map_err(|e| format!("Failed to get transaction {}: {}", txid, e))?
+                .transaction()
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    fn watch_address(&self, address: &Address, label: &str) -> Result<(), String> {
+        self.import_address(address, Some(label), Some(false))
+            .map_err(|e| e.to_string())
+    }
+
+    fn untracked_addresses(&self, addresses: &[Address]) -> Result<Vec<Address>, String> {
+        // Every address of Core's wallet, including empty and watch-only ones, in one call
+        let received: Vec<serde_json::Value> = self
+            .call(
+                "listreceivedbyaddress",
+                &[0.into(), true.into(), true.into()],
+            )
+            .map_err(|e| {
+                format!(
+                    "Dash Core has no wallet to import addresses into, load one or use the UTXO set scan: {}",
+                    e
+                )
+            })?;
+        let tracked: HashSet<&str> = received
+            .iter()
+            .filter_map(|entry| entry["address"].as_str())
+            .collect();
+        Ok(addresses
+            .iter()
+            .filter(|address| !tracked.contains(address.to_string().as_str()))
+            .cloned()
+            .collect())
+    }
+
+    fn rescan(&self, start_height: Option<u32>) -> Result<(), String> {
+        self.rescan_blockchain(start_height.map(|height| height as usize), None)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to rescan the blockchain: {}", e))
+    }
+
+    fn estimate_fee_rate(&self, confirmation_target: u16) -> Result<Option<FeeRate>, String> {
+        let estimate = self
+            .estimate_smart_fee(confirmation_target, None)
+            .map_err(|e| e.to_string())?;
+        Ok(estimate
+            .fee_rate
+            .map(|fee_rate| FeeRate::from_duffs_per_kb(fee_rate.to_sat())))
+    }
+
+    fn broadcast_transaction(&self, transaction: &Transaction) -> Result<Txid, String> {
+        self.send_raw_transaction(transaction)
+            .map_err(|e| e.to_string())
+    }
+}
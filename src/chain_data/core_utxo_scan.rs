@@ -0,0 +1,167 @@
+use super::{ChainDataBackend, ChainTransaction};
+use crate::model::wallet::balance::UtxoStatus;
+use crate::model::wallet::fee_estimation::FeeRate;
+use dash_sdk::dashcore_rpc::{Client, RpcApi};
+use dash_sdk::dpp::dashcore::{Address, OutPoint, ScriptBuf, Transaction, TxOut, Txid};
+use serde::Deserialize;
+use serde_json::json;
+use std::str::FromStr;
+
+/// Dash Core used without its wallet: unspent outputs are looked up in the UTXO set with
+/// `scantxoutset`, which works for any address but knows nothing about spent outputs or
+/// unconfirmed transactions.
+pub struct CoreUtxoScan<'a>(pub &'a Client);
+
+#[derive(Deserialize)]
+struct ScanResult {
+    success: bool,
+    /// Height of the chain tip the scan ran against
+    height: u32,
+    unspents: Vec<ScannedUtxo>,
+}
+
+#[derive(Deserialize)]
+struct ScannedUtxo {
+    txid: String,
+    vout: u32,
+    #[serde(rename = "scriptPubKey")]
+    script_pubkey: String,
+    /// In DASH
+    amount: f64,
+    height: u32,
+    #[serde(default)]
+    coinbase: bool,
+}
+
+impl CoreUtxoScan<'_> {
+    fn scan(&self, addresses: &[Address]) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String> {
+        if addresses.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let descriptors: Vec<String> = addresses
+            .iter()
+            .map(|address| format!("addr({})", address))
+            .collect();
+        let result: ScanResult = self
+            .0
+            .call("scantxoutset", &[json!("start"), json!(descriptors)])
+            .map_err(|e| format!("Dash Core UTXO set scan failed: {}", e))?;
+        if !result.success {
+            return Err("Dash Core UTXO set scan was aborted".to_string());
+        }
+
+        result
+            .unspents
+            .into_iter()
+            .map(|utxo| {
+                let txid = Txid::from_str(&utxo.txid).map_err(|e| e.to_string())?;
+                let script_pubkey = ScriptBuf::from_bytes(
+                    hex::decode(&utxo.script_pubkey).map_err(|e| e.to_string())?,
+                );
+                let status = UtxoStatus {
+                    confirmations: result.height.saturating_sub(utxo.height) + 1,
+                    instant_locked: false,
+                    coinbase: utxo.coinbase,
+                };
+                Ok((
+                    OutPoint::new(txid, utxo.vout),
+                    TxOut {
+                        value: (utxo.amount * 1e8).round() as u64,
+                        script_pubkey,
+                    },
+                    status,
+                ))
+            })
+            .collect()
+    }
+}
+
+impl ChainDataBackend for CoreUtxoScan<'_> {
+    fn list_unspent(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String> {
+        self.scan(addresses)
+    }
+
+    /// Only unspent outputs are visible, so this is what the address still holds.
+    fn received_by_address(
+        &self,
+        address: &Address,
+        _include_unconfirmed: bool,
+    ) -> Result<u64, String> {
+        Ok(self
+            .scan(std::slice::from_ref(address))?
+            .iter()
+            .map(|(_, tx_out, _)| tx_out.value)
+            .sum())
+    }
+
+    /// Scans once for all addresses instead of once per address.
+    fn received_by_addresses(
+        &self,
+        addresses: &[Address],
+        _include_unconfirmed: bool,
+    ) -> Result<Vec<(Address, u64)>, String> {
+        let unspent = self.scan(addresses)?;
+        Ok(addresses
+            .iter()
+            .map(|address| {
+                let script_pubkey = address.script_pubkey();
+                let received = unspent
+                    .iter()
+                    .filter(|(_, tx_out, _)| tx_out.script_pubkey == script_pubkey)
+                    .map(|(_, tx_out, _)| tx_out.value)
+                    .sum();
+                (address.clone(), received)
+            })
+            .collect())
+    }
+
+    fn reports_total_received(&self) -> bool {
+        false
+    }
+
+    /// Transactions are only known to Core's wallet.
+    fn recent_transactions(
+        &self,
+        _addresses: &[Address],
+        _count: usize,
+    ) -> Result<Vec<ChainTransaction>, String> {
+        Ok(Vec::new())
+    }
+
+    fn has_transaction_history(&self) -> bool {
+        false
+    }
+
+    fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction, String> {
+        self.0.get_raw_transaction(txid, None).map_err(|e| {
+            format!(
+                "Failed to get transaction {}, Dash Core needs -txindex to look up confirmed transactions without its wallet: {}",
+                txid, e
+            )
+        })
+    }
+
+    fn watch_address(&self, _address: &Address, _label: &str) -> Result<(), String> {
+        // The UTXO set covers every address
+        Ok(())
+    }
+
+    fn rescan(&self, _start_height: Option<u32>) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Fee estimation doesn't need Core's wallet.
+    fn estimate_fee_rate(&self, confirmation_target: u16) -> Result<Option<FeeRate>, String> {
+        self.0.estimate_fee_rate(confirmation_target)
+    }
+
+    fn broadcast_transaction(&self, transaction: &Transaction) -> Result<Txid, String> {
+        self.0
+            .send_raw_transaction(transaction)
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,372 @@
+use super::{ChainDataBackend, ChainTransaction};
+use crate::model::wallet::balance::{COINBASE_MATURITY, UtxoStatus};
+use crate::model::wallet::fee_estimation::FeeRate;
+use dash_sdk::dpp::dashcore::consensus::{deserialize, serialize};
+use dash_sdk::dpp::dashcore::{Address, OutPoint, ScriptBuf, Transaction, TxOut, Txid};
+use dash_sdk::sdk::Uri;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Insight limits how many addresses fit in one request and how many transactions fit in
+/// one page.
+const ADDRESS_BATCH_SIZE: usize = 100;
+const TRANSACTION_PAGE_SIZE: usize = 50;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Client for the Insight API, used instead of Dash Core RPC when no local node is running.
+pub struct InsightClient {
+    base_url: String,
+    agent: ureq::Agent,
+}
+
+#[derive(Deserialize)]
+struct InsightUtxo {
+    txid: String,
+    vout: u32,
+    #[serde(rename = "scriptPubKey")]
+    script_pubkey: String,
+    satoshis: u64,
+    #[serde(default)]
+    confirmations: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InsightAddress {
+    total_received_sat: u64,
+    /// Net change of the balance by unconfirmed transactions, negative for spends
+    #[serde(default)]
+    unconfirmed_balance_sat: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InsightTransactionPage {
+    total_items: usize,
+    items: Vec<InsightTransaction>,
+}
+
+#[derive(Deserialize)]
+struct InsightTransaction {
+    txid: String,
+    #[serde(default)]
+    confirmations: u32,
+    #[serde(default)]
+    time: u64,
+    /// Whether the transaction is InstantSend locked
+    #[serde(default)]
+    txlock: bool,
+    vin: Vec<InsightInput>,
+    vout: Vec<InsightOutput>,
+}
+
+#[derive(Deserialize)]
+struct InsightInput {
+    addr: Option<String>,
+    /// Only set on the input of coinbase transactions
+    #[serde(default)]
+    coinbase: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct InsightOutput {
+    value: String,
+    #[serde(rename = "scriptPubKey")]
+    script_pubkey: InsightScript,
+}
+
+#[derive(Deserialize)]
+struct InsightScript {
+    hex: String,
+}
+
+#[derive(Deserialize)]
+struct InsightRawTransaction {
+    rawtx: String,
+}
+
+#[derive(Deserialize)]
+struct InsightBroadcast {
+    txid: String,
+}
+
+impl InsightClient {
+    pub fn new(uri: Uri) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(REQUEST_TIMEOUT))
+            .build()
+            .into();
+        Self {
+            base_url: uri.to_string().trim_end_matches('/').to_string(),
+            agent,
+        }
+    }
+
+    fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        self.agent
+            .get(format!("{}/{}", self.base_url, path))
+            .call()
+            .map_err(|e| format!("Insight API request failed: {}", e))?
+            .body_mut()
+            .read_json()
+            .map_err(|e| format!("Unexpected Insight API response: {}", e))
+    }
+
+    /// Calls `f` with up to `count` of the most recent transactions of each batch of
+    /// `addresses`, a page of transactions at a time.
+    fn for_each_transaction(
+        &self,
+        addresses: &[Address],
+        count: usize,
+        mut f: impl FnMut(&InsightTransaction) -> Result<(), String>,
+    ) -> Result<(), String> {
+        for batch in addresses.chunks(ADDRESS_BATCH_SIZE) {
+            let addrs = batch
+                .iter()
+                .map(|address| address.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let mut from = 0;
+            while from < count {
+                let to = from.saturating_add(TRANSACTION_PAGE_SIZE).min(count);
+                let page: InsightTransactionPage = self.post(
+                    "addrs/txs",
+                    json!({ "addrs": addrs, "from": from, "to": to }),
+                )?;
+                for item in &page.items {
+                    f(item)?;
+                }
+
+                if page.items.is_empty() || to >= page.total_items {
+                    break;
+                }
+                from = to;
+            }
+        }
+        Ok(())
+    }
+
+    fn post<T: DeserializeOwned>(&self, path: &str, body: serde_json::Value) -> Result<T, String> {
+        self.agent
+            .post(format!("{}/{}", self.base_url, path))
+            .send_json(body)
+            .map_err(|e| format!("Insight API request failed: {}", e))?
+            .body_mut()
+            .read_json()
+            .map_err(|e| format!("Unexpected Insight API response: {}", e))
+    }
+}
+
+/// Converts a decimal DASH amount as returned by Insight, e.g. `"0.10000000"`, to duffs,
+/// without going through floating point.
+fn parse_dash_amount(value: &str) -> Result<u64, String> {
+    let invalid = || format!("Invalid amount {}", value);
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 8 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let whole: u64 = whole.parse().map_err(|_| invalid())?;
+    let fraction: u64 = format!("{:0<8}", fraction).parse().map_err(|_| invalid())?;
+    whole
+        .checked_mul(100_000_000)
+        .and_then(|duffs| duffs.checked_add(fraction))
+        .ok_or_else(invalid)
+}
+
+impl ChainDataBackend for InsightClient {
+    fn list_unspent(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String> {
+        let mut unspent = Vec::new();
+        let mut young_transactions: BTreeMap<Txid, InsightTransaction> = BTreeMap::new();
+        for batch in addresses.chunks(ADDRESS_BATCH_SIZE) {
+            let addrs = batch
+                .iter()
+                .map(|address| address.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let utxos: Vec<InsightUtxo> = self.post("addrs/utxo", json!({ "addrs": addrs }))?;
+            for utxo in utxos {
+                let txid = Txid::from_str(&utxo.txid).map_err(|e| e.to_string())?;
+                let script_pubkey = ScriptBuf::from_bytes(
+                    hex::decode(&utxo.script_pubkey).map_err(|e| e.to_string())?,
+                );
+                // Only young outputs can be InstantSend locked without confirmations, or immature
+                let mut status = UtxoStatus {
+                    confirmations: utxo.confirmations,
+                    ..Default::default()
+                };
+                if utxo.confirmations < COINBASE_MATURITY {
+                    if !young_transactions.contains_key(&txid) {
+                        let transaction = self.get(&format!("tx/{}", txid))?;
+                        young_transactions.insert(txid, transaction);
+                    }
+                    let transaction = &young_transactions[&txid];
+                    status.instant_locked = transaction.txlock;
+                    status.coinbase = transaction
+                        .vin
+                        .first()
+                        .is_some_and(|input| input.coinbase.is_some());
+                }
+                unspent.push((
+                    OutPoint::new(txid, utxo.vout),
+                    TxOut {
+                        value: utxo.satoshis,
+                        script_pubkey,
+                    },
+                    status,
+                ));
+            }
+        }
+        Ok(unspent)
+    }
+
+    /// Insight only reports the net change of the unconfirmed balance, which goes down with
+    /// unconfirmed spends, so only an unconfirmed gain is added.
+    fn received_by_address(
+        &self,
+        address: &Address,
+        include_unconfirmed: bool,
+    ) -> Result<u64, String> {
+        let info: InsightAddress = self.get(&format!("addr/{}?noTxList=1", address))?;
+        let unconfirmed = if include_unconfirmed {
+            info.unconfirmed_balance_sat.max(0) as u64
+        } else {
+            0
+        };
+        Ok(info.total_received_sat + unconfirmed)
+    }
+
+    /// Sums the outputs paying to each address over the history of a whole batch of
+    /// addresses at a time, instead of asking for every address on its own.
+    fn received_by_addresses(
+        &self,
+        addresses: &[Address],
+        include_unconfirmed: bool,
+    ) -> Result<Vec<(Address, u64)>, String> {
+        let indices: HashMap<String, usize> = addresses
+            .iter()
+            .enumerate()
+            .map(|(index, address)| (hex::encode(address.script_pubkey().as_bytes()), index))
+            .collect();
+        let mut received = vec![0; addresses.len()];
+        self.for_each_transaction(addresses, usize::MAX, |item| {
+            if !include_unconfirmed && item.confirmations == 0 {
+                return Ok(());
+            }
+            for output in &item.vout {
+                if let Some(&index) = indices.get(&output.script_pubkey.hex) {
+                    received[index] += parse_dash_amount(&output.value)?;
+                }
+            }
+            Ok(())
+        })?;
+        Ok(addresses.iter().cloned().zip(received).collect())
+    }
+
+    fn recent_transactions(
+        &self,
+        addresses: &[Address],
+        count: usize,
+    ) -> Result<Vec<ChainTransaction>, String> {
+        let address_strings: HashSet<String> = addresses
+            .iter()
+            .map(|address| address.to_string())
+            .collect();
+        let scripts: HashSet<String> = addresses
+            .iter()
+            .map(|address| hex::encode(address.script_pubkey().as_bytes()))
+            .collect();
+
+        let mut transactions: BTreeMap<Txid, ChainTransaction> = BTreeMap::new();
+        self.for_each_transaction(addresses, count, |item| {
+            let txid = Txid::from_str(&item.txid).map_err(|e| e.to_string())?;
+            // Outputs of our own spends are change, not something we received
+            let is_own_spend = item.vin.iter().any(|input| {
+                input
+                    .addr
+                    .as_ref()
+                    .is_some_and(|addr| address_strings.contains(addr))
+            });
+            let received = if is_own_spend {
+                0
+            } else {
+                item.vout
+                    .iter()
+                    .filter(|output| scripts.contains(&output.script_pubkey.hex))
+                    .map(|output| parse_dash_amount(&output.value))
+                    .sum::<Result<u64, String>>()?
+            };
+            transactions.insert(
+                txid,
+                ChainTransaction {
+                    txid,
+                    confirmations: item.confirmations,
+                    timestamp: item.time,
+                    received,
+                },
+            );
+            Ok(())
+        })?;
+        Ok(transactions.into_values().collect())
+    }
+
+    fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction, String> {
+        let result: InsightRawTransaction = self.get(&format!("rawtx/{}", txid))?;
+        deserialize(&hex::decode(&result.rawtx).map_err(|e| e.to_string())?)
+            .map_err(|e| format!("Invalid transaction {}: {}", txid, e))
+    }
+
+    fn watch_address(&self, _address: &Address, _label: &str) -> Result<(), String> {
+        // Insight indexes every address
+        Ok(())
+    }
+
+    fn rescan(&self, _start_height: Option<u32>) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Insight answers with DASH per kB by block target, -1 when it has no estimate.
+    fn estimate_fee_rate(&self, confirmation_target: u16) -> Result<Option<FeeRate>, String> {
+        let estimates: BTreeMap<String, f64> = self.get(&format!(
+            "utils/estimatefee?nbBlocks={}",
+            confirmation_target
+        ))?;
+        Ok(estimates
+            .get(&confirmation_target.to_string())
+            .filter(|dash_per_kb| **dash_per_kb > 0.0)
+            .map(|dash_per_kb| FeeRate::from_duffs_per_kb((dash_per_kb * 1e8).round() as u64)))
+    }
+
+    fn broadcast_transaction(&self, transaction: &Transaction) -> Result<Txid, String> {
+        let result: InsightBroadcast = self.post(
+            "tx/send",
+            json!({ "rawtx": hex::encode(serialize(transaction)) }),
+        )?;
+        Txid::from_str(&result.txid).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dash_amount() {
+        assert_eq!(parse_dash_amount("0.10000000"), Ok(10_000_000));
+        assert_eq!(parse_dash_amount("12"), Ok(1_200_000_000));
+        assert_eq!(
+            parse_dash_amount("21000000.00000001"),
+            Ok(2_100_000_000_000_001)
+        );
+        assert!(parse_dash_amount("0.000000001").is_err());
+        assert!(parse_dash_amount("-1").is_err());
+    }
+}
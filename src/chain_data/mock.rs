@@ -0,0 +1,476 @@
+//! In-memory regtest stand-in for Dash Core, so wallet flows can be tested without dashd.
+
+use super::{ChainDataBackend, ChainTransaction};
+use crate::components::core_zmq_listener::ZMQMessage;
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::balance::UtxoStatus;
+use dash_sdk::dpp::dashcore::consensus::encode::VarInt;
+use dash_sdk::dpp::dashcore::consensus::{deserialize, serialize};
+use dash_sdk::dpp::dashcore::hashes::Hash;
+use dash_sdk::dpp::dashcore::{
+    Address, Block, BlockHash, InstantLock, Network, OutPoint, Transaction, TxIn, TxOut, Txid,
+};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Regtest difficulty, as found in regtest block headers
+const REGTEST_BITS: u32 = 0x207f_ffff;
+const GENESIS_TIME: u32 = 1_700_000_000;
+/// Regtest block interval
+const BLOCK_INTERVAL: u32 = 150;
+
+/// A chain that only exists in memory.
+///
+/// Transactions are accepted as long as they spend known unspent outputs and don't create
+/// value, scripts and signatures are not verified. Instant locks and chain locks are only
+/// produced on request, and every lock is queued as the raw ZMQ message Dash Core would
+/// publish for it, see [`MockChain::replay_zmq_messages`].
+#[derive(Debug)]
+pub struct MockChain {
+    network: Network,
+    state: Mutex<MockChainState>,
+}
+
+#[derive(Debug)]
+struct MockChainState {
+    blocks: Vec<Block>,
+    mempool: Vec<Txid>,
+    /// Every transaction ever accepted, in the order they were accepted
+    transactions: Vec<Txid>,
+    by_txid: BTreeMap<Txid, MockTransaction>,
+    unspent: BTreeMap<OutPoint, TxOut>,
+    instant_locked: HashSet<Txid>,
+    chain_locked_height: Option<u32>,
+    watched_addresses: BTreeSet<Address>,
+    rescans: u32,
+    funding_counter: u32,
+    zmq_messages: Vec<(String, Vec<u8>)>,
+    /// Only report unspent outputs, like the UTXO set scan
+    unspent_only: bool,
+}
+
+#[derive(Debug)]
+struct MockTransaction {
+    transaction: Transaction,
+    height: Option<u32>,
+    time: u64,
+}
+
+impl MockChainState {
+    fn height(&self) -> u32 {
+        self.blocks.len() as u32 - 1
+    }
+
+    fn time(&self) -> u32 {
+        GENESIS_TIME + self.height() * BLOCK_INTERVAL
+    }
+
+    fn confirmations(&self, txid: &Txid) -> u32 {
+        self.by_txid
+            .get(txid)
+            .and_then(|tx| tx.height)
+            .map(|height| self.height() - height + 1)
+            .unwrap_or(0)
+    }
+
+    fn spent_output(&self, outpoint: &OutPoint) -> Option<&TxOut> {
+        self.by_txid
+            .get(&outpoint.txid)
+            .and_then(|tx| tx.transaction.output.get(outpoint.vout as usize))
+    }
+
+    fn accept(&mut self, transaction: Transaction) -> Txid {
+        let txid = transaction.txid();
+        for input in &transaction.input {
+            self.unspent.remove(&input.previous_output);
+        }
+        for (vout, output) in transaction.output.iter().enumerate() {
+            // Burn outputs of asset locks can never be spent
+            if !output.script_pubkey.is_op_return() {
+                self.unspent
+                    .insert(OutPoint::new(txid, vout as u32), output.clone());
+            }
+        }
+        let time = self.time() as u64;
+        self.by_txid.insert(
+            txid,
+            MockTransaction {
+                transaction,
+                height: None,
+                time,
+            },
+        );
+        self.transactions.push(txid);
+        self.mempool.push(txid);
+        txid
+    }
+
+    fn push_block(&mut self, transactions: Vec<Transaction>) -> Result<(), String> {
+        let prev_blockhash = self
+            .blocks
+            .last()
+            .map(|block| block.block_hash())
+            .unwrap_or_else(BlockHash::all_zeros);
+        let time = GENESIS_TIME + self.blocks.len() as u32 * BLOCK_INTERVAL;
+
+        // Encoded by hand so the block is exactly what Core would send over ZMQ
+        let mut raw = serialize(&1i32);
+        raw.extend(serialize(&prev_blockhash));
+        raw.extend([0u8; 32]); // merkle root, not checked by anything here
+        raw.extend(serialize(&time));
+        raw.extend(serialize(&REGTEST_BITS));
+        raw.extend(serialize(&0u32)); // nonce
+        raw.extend(serialize(&VarInt(transactions.len() as u64)));
+        for transaction in &transactions {
+            raw.extend(serialize(transaction));
+        }
+        let block = deserialize(&raw).map_err(|e| format!("Invalid mock block: {}", e))?;
+        self.blocks.push(block);
+        Ok(())
+    }
+}
+
+impl MockChain {
+    /// A chain holding only a genesis block.
+    pub fn new(network: Network) -> Self {
+        let mut state = MockChainState {
+            blocks: Vec::new(),
+            mempool: Vec::new(),
+            transactions: Vec::new(),
+            by_txid: BTreeMap::new(),
+            unspent: BTreeMap::new(),
+            instant_locked: HashSet::new(),
+            chain_locked_height: None,
+            watched_addresses: BTreeSet::new(),
+            rescans: 0,
+            funding_counter: 0,
+            zmq_messages: Vec::new(),
+            unspent_only: false,
+        };
+        state
+            .push_block(vec![])
+            .expect("expected the genesis block to encode");
+        Self {
+            network,
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Makes the chain report only what addresses still hold, like
+    /// [`crate::chain_data::CoreUtxoScan`], instead of what they received.
+    pub fn report_unspent_only(&self) {
+        self.state.lock().unwrap().unspent_only = true;
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    pub fn height(&self) -> u32 {
+        self.state.lock().unwrap().height()
+    }
+
+    /// Pays `value` duffs to `address` out of thin air, like a faucet. The payment sits in
+    /// the mempool until the next [`MockChain::mine_block`].
+    pub fn fund_address(&self, address: &Address, value: u64) -> OutPoint {
+        let mut state = self.state.lock().unwrap();
+        state.funding_counter += 1;
+        // Every funding transaction spends a distinct made up output, so txids never collide
+        let mut source = [0xfa; 32];
+        source[..4].copy_from_slice(&state.funding_counter.to_le_bytes());
+        let transaction = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output: OutPoint::new(Txid::from_byte_array(source), 0),
+                ..Default::default()
+            }],
+            output: vec![TxOut {
+                value,
+                script_pubkey: address.script_pubkey(),
+            }],
+            special_transaction_payload: None,
+        };
+        let txid = state.accept(transaction);
+        OutPoint::new(txid, 0)
+    }
+
+    /// Mines the mempool into a new block and returns its height.
+    pub fn mine_block(&self) -> Result<u32, String> {
+        let mut state = self.state.lock().unwrap();
+        let height = state.height() + 1;
+        let mempool = std::mem::take(&mut state.mempool);
+        let mut transactions = Vec::with_capacity(mempool.len());
+        for txid in mempool {
+            let tx = state
+                .by_txid
+                .get_mut(&txid)
+                .expect("mempool transactions are known");
+            tx.height = Some(height);
+            transactions.push(tx.transaction.clone());
+        }
+        state.push_block(transactions)?;
+        Ok(height)
+    }
+
+    /// Instant-locks a broadcast transaction and queues the `rawtxlocksig` message.
+    pub fn instant_lock(&self, txid: &Txid) -> Result<InstantLock, String> {
+        let mut state = self.state.lock().unwrap();
+        let transaction = state
+            .by_txid
+            .get(txid)
+            .map(|tx| tx.transaction.clone())
+            .ok_or(format!("Unknown transaction {}", txid))?;
+        let cycle_hash = state.blocks.last().map(|block| block.block_hash());
+
+        let mut raw_lock = vec![1u8]; // version
+        raw_lock.extend(serialize(&VarInt(transaction.input.len() as u64)));
+        for input in &transaction.input {
+            raw_lock.extend(serialize(&input.previous_output));
+        }
+        raw_lock.extend(serialize(txid));
+        raw_lock.extend(serialize(&cycle_hash.unwrap_or_else(BlockHash::all_zeros)));
+        raw_lock.extend([0u8; 96]); // signature, never verified by the stand-in
+        let instant_lock: InstantLock =
+            deserialize(&raw_lock).map_err(|e| format!("Invalid mock instant lock: {}", e))?;
+
+        let mut message = serialize(&transaction);
+        message.extend(raw_lock);
+        state
+            .zmq_messages
+            .push(("rawtxlocksig".to_string(), message));
+        state.instant_locked.insert(*txid);
+        Ok(instant_lock)
+    }
+
+    /// Chain-locks the block at `height` and queues the `rawchainlock` message.
+    pub fn chain_lock(&self, height: u32) -> Result<(), String> {
+        let mut state = self.state.lock().unwrap();
+        let block = state
+            .blocks
+            .get(height as usize)
+            .cloned()
+            .ok_or(format!("There is no block at height {}", height))?;
+        state.chain_locked_height = Some(height);
+        state
+            .zmq_messages
+            .push(("rawchainlock".to_string(), serialize(&block)));
+        Ok(())
+    }
+
+    pub fn is_instant_locked(&self, txid: &Txid) -> bool {
+        self.state.lock().unwrap().instant_locked.contains(txid)
+    }
+
+    /// Whether `txid` is mined at or below the last chain-locked height.
+    pub fn is_chain_locked(&self, txid: &Txid) -> bool {
+        let state = self.state.lock().unwrap();
+        let height = state.by_txid.get(txid).and_then(|tx| tx.height);
+        matches!((height, state.chain_locked_height), (Some(height), Some(locked)) if height <= locked)
+    }
+
+    pub fn confirmations(&self, txid: &Txid) -> u32 {
+        self.state.lock().unwrap().confirmations(txid)
+    }
+
+    pub fn transaction(&self, txid: &Txid) -> Option<Transaction> {
+        let state = self.state.lock().unwrap();
+        state.by_txid.get(txid).map(|tx| tx.transaction.clone())
+    }
+
+    pub fn is_unspent(&self, outpoint: &OutPoint) -> bool {
+        self.state.lock().unwrap().unspent.contains_key(outpoint)
+    }
+
+    pub fn watched_addresses(&self) -> BTreeSet<Address> {
+        self.state.lock().unwrap().watched_addresses.clone()
+    }
+
+    pub fn rescans(&self) -> u32 {
+        self.state.lock().unwrap().rescans
+    }
+
+    /// Takes the queued ZMQ messages as `(topic, data)` pairs, as Dash Core publishes them.
+    pub fn take_raw_zmq_messages(&self) -> Vec<(String, Vec<u8>)> {
+        std::mem::take(&mut self.state.lock().unwrap().zmq_messages)
+    }
+
+    /// Takes the queued ZMQ messages and decodes them like the ZMQ listener does.
+    pub fn replay_zmq_messages(&self) -> Result<Vec<ZMQMessage>, String> {
+        self.take_raw_zmq_messages()
+            .iter()
+            .map(|(topic, data)| ZMQMessage::from_raw(topic, data))
+            .collect()
+    }
+}
+
+impl ChainDataBackend for MockChain {
+    fn list_unspent(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String> {
+        let scripts: HashSet<_> = addresses.iter().map(Address::script_pubkey).collect();
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .unspent
+            .iter()
+            .filter(|(_, tx_out)| scripts.contains(&tx_out.script_pubkey))
+            .map(|(outpoint, tx_out)| {
+                let status = UtxoStatus {
+                    confirmations: state.confirmations(&outpoint.txid),
+                    instant_locked: state.instant_locked.contains(&outpoint.txid),
+                    coinbase: state.by_txid[&outpoint.txid].transaction.is_coin_base(),
+                };
+                (*outpoint, tx_out.clone(), status)
+            })
+            .collect())
+    }
+
+    fn received_by_address(
+        &self,
+        address: &Address,
+        include_unconfirmed: bool,
+    ) -> Result<u64, String> {
+        let script = address.script_pubkey();
+        let state = self.state.lock().unwrap();
+        if state.unspent_only {
+            return Ok(state
+                .unspent
+                .values()
+                .filter(|output| output.script_pubkey == script)
+                .map(|output| output.value)
+                .sum());
+        }
+        Ok(state
+            .by_txid
+            .values()
+            .filter(|tx| include_unconfirmed || tx.height.is_some())
+            .flat_map(|tx| tx.transaction.output.iter())
+            .filter(|output| output.script_pubkey == script)
+            .map(|output| output.value)
+            .sum())
+    }
+
+    fn reports_total_received(&self) -> bool {
+        !self.state.lock().unwrap().unspent_only
+    }
+
+    fn recent_transactions(
+        &self,
+        addresses: &[Address],
+        count: usize,
+    ) -> Result<Vec<ChainTransaction>, String> {
+        let scripts: HashSet<_> = addresses.iter().map(Address::script_pubkey).collect();
+        let state = self.state.lock().unwrap();
+        let mut transactions = Vec::new();
+        for txid in state.transactions.iter().rev() {
+            let tx = &state.by_txid[txid];
+            let is_own_spend = tx.transaction.input.iter().any(|input| {
+                state
+                    .spent_output(&input.previous_output)
+                    .is_some_and(|spent| scripts.contains(&spent.script_pubkey))
+            });
+            let paid: u64 = tx
+                .transaction
+                .output
+                .iter()
+                .filter(|output| scripts.contains(&output.script_pubkey))
+                .map(|output| output.value)
+                .sum();
+            if !is_own_spend && paid == 0 {
+                continue;
+            }
+            transactions.push(ChainTransaction {
+                txid: *txid,
+                confirmations: state.confirmations(txid),
+                timestamp: tx.time,
+                received: if is_own_spend { 0 } else { paid },
+            });
+            if transactions.len() == count {
+                break;
+            }
+        }
+        Ok(transactions)
+    }
+
+    fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction, String> {
+        self.transaction(txid).ok_or(format!(
+            "No such mempool or blockchain transaction {}",
+            txid
+        ))
+    }
+
+    fn watch_address(&self, address: &Address, _label: &str) -> Result<(), String> {
+        self.state
+            .lock()
+            .unwrap()
+            .watched_addresses
+            .insert(address.clone());
+        Ok(())
+    }
+
+    fn untracked_addresses(&self, addresses: &[Address]) -> Result<Vec<Address>, String> {
+        let state = self.state.lock().unwrap();
+        Ok(addresses
+            .iter()
+            .filter(|address| !state.watched_addresses.contains(address))
+            .cloned()
+            .collect())
+    }
+
+    fn rescan(&self, _start_height: Option<u32>) -> Result<(), String> {
+        self.state.lock().unwrap().rescans += 1;
+        Ok(())
+    }
+
+    fn broadcast_transaction(&self, transaction: &Transaction) -> Result<Txid, String> {
+        let mut state = self.state.lock().unwrap();
+        let txid = transaction.txid();
+        if state.by_txid.contains_key(&txid) {
+            return Err("txn-already-known".to_string());
+        }
+
+        let mut input_value = 0;
+        for input in &transaction.input {
+            let spent = state
+                .unspent
+                .get(&input.previous_output)
+                .ok_or("bad-txns-inputs-missingorspent".to_string())?;
+            input_value += spent.value;
+        }
+        let output_value: u64 = transaction.output.iter().map(|output| output.value).sum();
+        if output_value > input_value {
+            return Err("bad-txns-in-belowout".to_string());
+        }
+
+        Ok(state.accept(transaction.clone()))
+    }
+}
+
+impl AppContext {
+    /// Makes every chain data lookup and broadcast of this context go to `chain`, whatever
+    /// the configured source.
+    pub fn use_mock_chain(&self, chain: Arc<MockChain>) {
+        *self
+            .mock_chain
+            .write()
+            .expect("Mock chain lock was poisoned") = Some(chain);
+    }
+
+    /// Stores `wallet` and makes it available to the app, like importing it does.
+    pub fn add_wallet(&self, wallet: Wallet) -> Result<Arc<RwLock<Wallet>>, String> {
+        self.db
+            .store_wallet(&wallet, &self.network)
+            .map_err(|e| e.to_string())?;
+        let seed_hash = wallet.seed_hash();
+        let wallet = Arc::new(RwLock::new(wallet));
+        self.wallets
+            .write()
+            .map_err(|e| e.to_string())?
+            .insert(seed_hash, wallet.clone());
+        self.has_wallet.store(true, Ordering::Relaxed);
+        Ok(wallet)
+    }
+}
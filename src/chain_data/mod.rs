@@ -0,0 +1,163 @@
+//! Sources of wallet data, so wallets work both with a local Dash Core and without one.
+
+mod core_rpc;
+mod core_utxo_scan;
+mod insight;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
+
+pub use core_utxo_scan::CoreUtxoScan;
+pub use insight::InsightClient;
+
+use crate::config::ChainDataSource;
+use crate::context::AppContext;
+use crate::model::wallet::balance::UtxoStatus;
+use crate::model::wallet::fee_estimation::FeeRate;
+use dash_sdk::dpp::dashcore::{Address, OutPoint, Transaction, TxOut, Txid};
+use std::collections::HashSet;
+
+/// A transaction touching the wallet, as seen by a [`ChainDataBackend`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainTransaction {
+    pub txid: Txid,
+    pub confirmations: u32,
+    pub timestamp: u64,
+    /// Amount paid to the wallet by someone else, 0 for the wallet's own spends and change
+    pub received: u64,
+}
+
+/// Read access to the chain for wallet addresses, and transaction broadcast.
+pub trait ChainDataBackend {
+    /// Unspent outputs paying to any of `addresses`, including unconfirmed ones, with how
+    /// settled they are.
+    fn list_unspent(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String>;
+
+    /// Like [`ChainDataBackend::list_unspent`], for addresses the backend may not track, like
+    /// the address of a key being swept.
+    fn scan_unspent(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<(OutPoint, TxOut, UtxoStatus)>, String> {
+        self.list_unspent(addresses)
+    }
+
+    /// Total amount ever received by `address`, in duffs.
+    fn received_by_address(
+        &self,
+        address: &Address,
+        include_unconfirmed: bool,
+    ) -> Result<u64, String>;
+
+    /// Totals received by each of `addresses`, see [`ChainDataBackend::received_by_address`].
+    fn received_by_addresses(
+        &self,
+        addresses: &[Address],
+        include_unconfirmed: bool,
+    ) -> Result<Vec<(Address, u64)>, String> {
+        addresses
+            .iter()
+            .map(|address| {
+                let received = self.received_by_address(address, include_unconfirmed)?;
+                Ok((address.clone(), received))
+            })
+            .collect()
+    }
+
+    /// Whether [`ChainDataBackend::received_by_address`] is the total ever received. Backends
+    /// that only see unspent outputs report what the address still holds instead, which
+    /// drops to 0 once it is spent.
+    fn reports_total_received(&self) -> bool {
+        true
+    }
+
+    /// Those of `addresses` that received funds, for finding the used addresses of a wallet.
+    ///
+    /// Must not rely on the addresses having been rescanned, so discovery can check a whole
+    /// gap window before the single rescan at its end.
+    fn used_addresses(&self, addresses: &[Address]) -> Result<HashSet<Address>, String> {
+        Ok(self
+            .received_by_addresses(addresses, true)?
+            .into_iter()
+            .filter(|(_, received)| *received > 0)
+            .map(|(address, _)| address)
+            .collect())
+    }
+
+    /// Up to `count` of the most recent transactions touching `addresses`, `usize::MAX` for
+    /// the full history.
+    fn recent_transactions(
+        &self,
+        addresses: &[Address],
+        count: usize,
+    ) -> Result<Vec<ChainTransaction>, String>;
+
+    /// Whether [`ChainDataBackend::recent_transactions`] can find transactions at all.
+    fn has_transaction_history(&self) -> bool {
+        true
+    }
+
+    /// The transaction `txid`, for proving the values of the outputs it created.
+    fn fetch_transaction(&self, txid: &Txid) -> Result<Transaction, String>;
+
+    /// Makes sure the backend tracks `address`. Only needed where the backend keeps its own
+    /// index of watched addresses.
+    fn watch_address(&self, address: &Address, label: &str) -> Result<(), String>;
+
+    /// Those of `addresses` the backend doesn't track yet, so they need
+    /// [`ChainDataBackend::watch_address`] and a rescan before their funds show up.
+    fn untracked_addresses(&self, _addresses: &[Address]) -> Result<Vec<Address>, String> {
+        Ok(Vec::new())
+    }
+
+    /// Re-scans the chain from `start_height`, or from genesis, for addresses added with
+    /// [`ChainDataBackend::watch_address`].
+    fn rescan(&self, start_height: Option<u32>) -> Result<(), String>;
+
+    /// Fee rate for a transaction to confirm within `confirmation_target` blocks, or `None`
+    /// if the backend can't estimate one.
+    fn estimate_fee_rate(&self, _confirmation_target: u16) -> Result<Option<FeeRate>, String> {
+        Ok(None)
+    }
+
+    fn broadcast_transaction(&self, transaction: &Transaction) -> Result<Txid, String>;
+}
+
+impl AppContext {
+    /// Runs `f` with the chain data backend configured for this network.
+    pub fn with_chain_data<R>(
+        &self,
+        f: impl FnOnce(&dyn ChainDataBackend) -> Result<R, String>,
+    ) -> Result<R, String> {
+        #[cfg(any(test, feature = "test-utils"))]
+        if let Some(mock_chain) = self
+            .mock_chain
+            .read()
+            .expect("Mock chain lock was poisoned")
+            .clone()
+        {
+            return f(&*mock_chain);
+        }
+
+        let config = self
+            .config
+            .read()
+            .expect("Config lock was poisoned")
+            .clone();
+        match config.chain_data_source {
+            ChainDataSource::CoreRpc => f(&*self
+                .core_client
+                .read()
+                .expect("Core client lock was poisoned")),
+            ChainDataSource::CoreUtxoScan => f(&CoreUtxoScan(
+                &self
+                    .core_client
+                    .read()
+                    .expect("Core client lock was poisoned"),
+            )),
+            ChainDataSource::Insight => f(&InsightClient::new(config.insight_api_uri()?)),
+        }
+    }
+}
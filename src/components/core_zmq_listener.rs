@@ -39,6 +39,26 @@ pub enum ZMQMessage {
     ChainLockedLockedTransaction(Transaction, CoreBlockHeight),
 }
 
+impl ZMQMessage {
+    /// Decodes the data part of a message Dash Core published on `topic`.
+    pub fn from_raw(topic: &str, data: &[u8]) -> Result<Self, String> {
+        let mut cursor = Cursor::new(data);
+        match topic {
+            "rawchainlock" => Block::consensus_decode(&mut cursor)
+                .map(ZMQMessage::ChainLockedBlock)
+                .map_err(|e| format!("Error deserializing chain locked block: {}", e)),
+            "rawtxlocksig" => {
+                let tx = Transaction::consensus_decode(&mut cursor)
+                    .map_err(|e| format!("Error deserializing transaction: {}", e))?;
+                let islock = InstantLock::consensus_decode(&mut cursor)
+                    .map_err(|e| format!("Error deserializing InstantLock: {}", e))?;
+                Ok(ZMQMessage::ISLockedTransaction(tx, islock))
+            }
+            _ => Err(format!("Received unknown topic: {}", topic)),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ZMQConnectionEvent {
     Connected,
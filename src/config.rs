@@ -26,6 +26,41 @@ pub enum ConfigError {
     NoValidConfigs,
 }
 
+/// Where wallet balances and UTXOs are looked up and wallet transactions are broadcast
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainDataSource {
+    /// The RPC interface of a local, synced Dash Core. Wallet addresses are imported into
+    /// Core's wallet as watch-only, followed by a rescan.
+    #[default]
+    CoreRpc,
+    /// The RPC interface of a local, synced Dash Core without using its wallet. Unspent
+    /// outputs are found with `scantxoutset`, so spent funds and history are not seen.
+    CoreUtxoScan,
+    /// A public or self-hosted Insight API, for use without a local Dash Core
+    Insight,
+}
+
+impl ChainDataSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChainDataSource::CoreRpc => "Dash Core",
+            ChainDataSource::CoreUtxoScan => "Dash Core (UTXO set scan)",
+            ChainDataSource::Insight => "Insight API",
+        }
+    }
+}
+
+impl std::fmt::Display for ChainDataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainDataSource::CoreRpc => write!(f, "core_rpc"),
+            ChainDataSource::CoreUtxoScan => write!(f, "core_utxo_scan"),
+            ChainDataSource::Insight => write!(f, "insight"),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct NetworkConfig {
     /// Hostname of the Dash Platform node to connect to
@@ -40,6 +75,9 @@ pub struct NetworkConfig {
     pub core_rpc_password: String,
     /// URL of the Insight API
     pub insight_api_url: String,
+    /// Backend used for wallet data, Dash Core unless configured otherwise
+    #[serde(default)]
+    pub chain_data_source: ChainDataSource,
     /// Devnet network name if one exists
     pub devnet_name: Option<String>,
     /// Optional wallet private key to instantiate the wallet
@@ -102,6 +140,12 @@ impl Config {
                 prefix, config.insight_api_url
             )
             .map_err(|e| ConfigError::LoadError(e.to_string()))?;
+            writeln!(
+                env_file,
+                "{}chain_data_source={}",
+                prefix, config.chain_data_source
+            )
+            .map_err(|e| ConfigError::LoadError(e.to_string()))?;
 
             if let Some(devnet_name) = &config.devnet_name {
                 // Only write devnet name if it exists
@@ -290,9 +334,11 @@ impl NetworkConfig {
     }
 
     /// Insight API URI
-    #[allow(dead_code)] // May be used for insight API access
-    pub fn insight_api_uri(&self) -> Uri {
-        Uri::from_str(&self.insight_api_url).expect("invalid insight API URL")
+    pub fn insight_api_uri(&self) -> Result<Uri, String> {
+        if self.insight_api_url.is_empty() {
+            return Err("No Insight API URL is configured for this network".to_string());
+        }
+        Uri::from_str(&self.insight_api_url).map_err(|e| format!("Invalid Insight API URL: {}", e))
     }
 
     /// Update just the `core_rpc_password` in a builder-like manner.
@@ -8,12 +8,16 @@ use crate::model::contested_name::ContestedName;
 use crate::model::password_info::PasswordInfo;
 use crate::model::qualified_contract::QualifiedContract;
 use crate::model::qualified_identity::{DPNSNameInfo, QualifiedIdentity};
+use crate::model::wallet::balance::UtxoStatus;
+use crate::model::wallet::fee_estimation::FeeRate;
+use crate::model::wallet::wallet_transaction::{TransactionDirection, WalletTransaction};
 use crate::model::wallet::{Wallet, WalletSeedHash};
 use crate::sdk_wrapper::initialize_sdk;
 use crate::ui::RootScreenType;
 use crate::ui::tokens::tokens_screen::{IdentityTokenBalance, IdentityTokenIdentifier};
 use crate::utils::tasks::TaskManager;
 use bincode::config;
+use chrono::Utc;
 use crossbeam_channel::{Receiver, Sender};
 use dash_sdk::Sdk;
 use dash_sdk::dashcore_rpc::dashcore::{InstantLock, Transaction};
@@ -40,6 +44,9 @@ use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 
+/// Number of blocks within which wallet transactions should confirm, passed to `estimatesmartfee`.
+const FEE_ESTIMATE_CONFIRMATION_TARGET: u16 = 6;
+
 const ANIMATION_REFRESH_TIME: std::time::Duration = std::time::Duration::from_millis(100);
 
 #[derive(Debug)]
@@ -64,6 +71,8 @@ pub struct AppContext {
     #[allow(dead_code)] // May be used for password validation
     pub(crate) password_info: Option<PasswordInfo>,
     pub(crate) transactions_waiting_for_finality: Mutex<BTreeMap<Txid, Option<AssetLockProof>>>,
+    /// Last fee rate used for wallet transactions, see [`AppContext::cached_fee_rate`].
+    fee_rate: Mutex<Option<FeeRate>>,
     /// Whether to animate the UI elements.
     ///
     /// This is used to control animations in the UI, such as loading spinners or transitions.
@@ -71,6 +80,10 @@ pub struct AppContext {
     animate: AtomicBool,
     // subtasks started by the app context, used for graceful shutdown
     pub(crate) subtasks: Arc<TaskManager>,
+    /// Chain data backend used instead of the configured one, see
+    /// [`AppContext::use_mock_chain`].
+    #[cfg(any(test, feature = "test-utils"))]
+    pub(crate) mock_chain: RwLock<Option<Arc<crate::chain_data::mock::MockChain>>>,
 }
 
 impl AppContext {
@@ -173,9 +186,12 @@ impl AppContext {
             wallets: RwLock::new(wallets),
             password_info,
             transactions_waiting_for_finality: Mutex::new(BTreeMap::new()),
+            fee_rate: Mutex::new(None),
             zmq_connection_status: Mutex::new(ZMQConnectionEvent::Disconnected),
             animate,
             subtasks,
+            #[cfg(any(test, feature = "test-utils"))]
+            mock_chain: RwLock::new(None),
         };
 
         let app_context = Arc::new(app_context);
@@ -348,6 +364,68 @@ impl AppContext {
         self.db.get_local_voting_identities(self)
     }
 
+    /// Changes, adds or removes the password of `wallet`.
+    ///
+    /// Identity keys of the wallet that were encrypted with the current password are
+    /// re-encrypted with the new one. Nothing is changed, in memory or in the database, unless
+    /// everything could be re-encrypted.
+    pub fn change_wallet_password(
+        &self,
+        wallet: &Arc<RwLock<Wallet>>,
+        current_password: Option<&str>,
+        new_password: Option<&str>,
+        new_password_hint: Option<String>,
+    ) -> std::result::Result<(), String> {
+        let identities = self
+            .load_local_qualified_identities()
+            .map_err(|e| e.to_string())?;
+
+        let mut wallet = wallet.write().map_err(|e| e.to_string())?;
+        let mut updated_wallet = wallet.clone();
+        updated_wallet.change_password(current_password, new_password, new_password_hint)?;
+
+        let seed_hash = wallet.seed_hash();
+        let mut updated_identities = vec![];
+        for mut identity in identities {
+            let in_wallet = wallet
+                .identities
+                .values()
+                .any(|wallet_identity| wallet_identity.id() == identity.identity.id())
+                || identity
+                    .determine_wallet_info()?
+                    .is_some_and(|(identity_seed_hash, _)| identity_seed_hash == seed_hash);
+            if in_wallet
+                && identity
+                    .private_keys
+                    .change_password(current_password, new_password)?
+            {
+                updated_identities.push(identity);
+            }
+        }
+
+        self.db
+            .update_wallet_password(&updated_wallet, &updated_identities)
+            .map_err(|e| e.to_string())?;
+        *wallet = updated_wallet;
+
+        Ok(())
+    }
+
+    /// Loads wallets of this network that were added to the database behind the app's back,
+    /// like by restoring a backup. Wallets already loaded are left alone.
+    pub fn load_new_wallets(&self) -> Result<()> {
+        let stored_wallets = self.db.get_wallets(&self.network)?;
+        let mut wallets = self.wallets.write().unwrap();
+        for wallet in stored_wallets {
+            wallets
+                .entry(wallet.seed_hash())
+                .or_insert_with(|| Arc::new(RwLock::new(wallet)));
+        }
+        self.has_wallet
+            .store(!wallets.is_empty(), Ordering::Relaxed);
+        Ok(())
+    }
+
     /// Fetches all local user identities from the database
     pub fn load_local_user_identities(&self) -> Result<Vec<QualifiedIdentity>> {
         let identities = self.db.get_local_user_identities(self)?;
@@ -492,6 +570,44 @@ impl AppContext {
         self.db.get_settings()
     }
 
+    /// The last fee rate from [`AppContext::refresh_fee_rate`], if there is one.
+    ///
+    /// Meant for showing fee estimates in the UI without waiting for Core, transactions are
+    /// built with a fresh rate.
+    pub fn cached_fee_rate(&self) -> Option<FeeRate> {
+        *self.fee_rate.lock().unwrap()
+    }
+
+    /// Forgets the cached fee rate, so the next estimate asks Core again.
+    pub fn clear_cached_fee_rate(&self) {
+        *self.fee_rate.lock().unwrap() = None;
+    }
+
+    /// Asks the chain data backend for a fee rate estimate. Falls back to the fee rate from
+    /// the settings when it is unavailable or has too little data, and to the minimum relay
+    /// fee after that.
+    pub fn refresh_fee_rate(&self) -> FeeRate {
+        let estimate = self
+            .with_chain_data(|chain_data| {
+                chain_data.estimate_fee_rate(FEE_ESTIMATE_CONFIRMATION_TARGET)
+            })
+            .ok()
+            .flatten();
+
+        let fee_rate = estimate
+            .or_else(|| {
+                self.db
+                    .get_fallback_fee_rate()
+                    .ok()
+                    .flatten()
+                    .map(FeeRate::from_duffs_per_kb)
+            })
+            .unwrap_or_default();
+
+        *self.fee_rate.lock().unwrap() = Some(fee_rate);
+        fee_rate
+    }
+
     /// Retrieves all contracts from the database plus the system contracts from app context.
     pub fn get_contracts(
         &self,
@@ -569,7 +685,7 @@ impl AppContext {
         self.db.replace_contract(contract_id, new_contract, self)
     }
 
-    pub(crate) fn received_transaction_finality(
+    pub fn received_transaction_finality(
         &self,
         tx: &Transaction,
         islock: Option<InstantLock>,
@@ -578,10 +694,14 @@ impl AppContext {
         // Initialize a vector to collect wallet outpoints
         let mut wallet_outpoints = Vec::new();
 
+        let asset_lock_value = asset_lock_credit_value(tx);
+        let confirmations = u32::from(chain_locked_height.is_some());
+
         // Identify the wallets associated with the transaction
         let wallets = self.wallets.read().unwrap();
         for wallet_arc in wallets.values() {
             let mut wallet = wallet_arc.write().unwrap();
+            let mut received = 0;
             for (vout, tx_out) in tx.output.iter().enumerate() {
                 let address = if let Ok(output_addr) =
                     Address::from_script(&tx_out.script_pubkey, self.network)
@@ -602,36 +722,113 @@ impl AppContext {
                     &tx_out.script_pubkey.to_bytes(),
                     self.network,
                 )?;
-                self.db
-                    .add_to_address_balance(&wallet.seed_hash(), &address, tx_out.value)?;
 
                 // Create the OutPoint and insert it into the wallet.utxos entry
                 let out_point = OutPoint::new(tx.txid(), vout as u32);
-                wallet
+                let already_known = wallet
                     .utxos
                     .entry(address.clone())
                     .or_insert_with(HashMap::new) // Initialize inner HashMap if needed
-                    .insert(out_point, tx_out.clone()); // Insert the TxOut at the OutPoint
+                    .insert(out_point, tx_out.clone()) // Insert the TxOut at the OutPoint
+                    .is_some();
+
+                // The instant lock and the chain lock of a transaction both end up here
+                let status = UtxoStatus {
+                    confirmations,
+                    instant_locked: islock.is_some(),
+                    coinbase: tx.is_coin_base(),
+                };
+                let status = match wallet.utxo_statuses.get(&out_point) {
+                    Some(known) => known.merge(status),
+                    None => status,
+                };
+                wallet.utxo_statuses.insert(out_point, status);
 
                 // Collect the outpoint
                 wallet_outpoints.push((out_point, tx_out.clone(), address.clone()));
 
-                wallet
-                    .address_balances
-                    .entry(address)
-                    .and_modify(|balance| *balance += tx_out.value)
-                    .or_insert(tx_out.value);
+                if !already_known {
+                    self.db.add_to_address_total_received(
+                        &wallet.seed_hash(),
+                        &address,
+                        tx_out.value,
+                    )?;
+                    wallet
+                        .address_total_received
+                        .entry(address)
+                        .and_modify(|total| *total += tx_out.value)
+                        .or_insert(tx_out.value);
+                }
+
+                received += tx_out.value;
+            }
+
+            if received > 0 {
+                // Our own broadcasts are recorded with their fee and take precedence, so this
+                // only matters for transactions we did not build
+                self.db.insert_wallet_transaction(
+                    &wallet.seed_hash(),
+                    &WalletTransaction {
+                        txid: tx.txid(),
+                        direction: if asset_lock_value.is_some() {
+                            TransactionDirection::AssetLock
+                        } else {
+                            TransactionDirection::Incoming
+                        },
+                        amount: asset_lock_value.unwrap_or(received),
+                        fee: None,
+                        confirmations,
+                        identity_id: None,
+                        timestamp: Utc::now().timestamp() as u64,
+                    },
+                    self.network,
+                )?;
             }
         }
-        if matches!(
-            tx.special_transaction_payload,
-            Some(AssetLockPayloadType(_))
-        ) {
+
+        if confirmations > 0 {
+            self.db.update_wallet_transaction_confirmations(
+                &tx.txid(),
+                confirmations,
+                self.network,
+            )?;
+        }
+
+        if asset_lock_value.is_some() {
             self.received_asset_lock_finality(tx, islock, chain_locked_height)?;
         }
         Ok(wallet_outpoints)
     }
 
+    /// Record a transaction built by `wallet` after it was broadcast.
+    ///
+    /// `amount` is what left the wallet, not counting change, and `input_value` the total
+    /// value of the spent UTXOs, from which the fee is derived.
+    pub(crate) fn store_broadcast_wallet_transaction(
+        &self,
+        wallet_seed_hash: &WalletSeedHash,
+        tx: &Transaction,
+        direction: TransactionDirection,
+        amount: u64,
+        input_value: u64,
+        identity_id: Option<Identifier>,
+    ) -> Result<()> {
+        let output_value: u64 = tx.output.iter().map(|tx_out| tx_out.value).sum();
+        self.db.insert_wallet_transaction(
+            wallet_seed_hash,
+            &WalletTransaction {
+                txid: tx.txid(),
+                direction,
+                amount,
+                fee: input_value.checked_sub(output_value),
+                confirmations: 0,
+                identity_id,
+                timestamp: Utc::now().timestamp() as u64,
+            },
+            self.network,
+        )
+    }
+
     /// Store the asset lock transaction in the database and update the wallet.
     pub(crate) fn received_asset_lock_finality(
         &self,
@@ -802,3 +999,18 @@ pub(crate) const fn default_platform_version(network: &Network) -> &'static Plat
         _ => panic!("unsupported network"),
     }
 }
+
+/// Total value credited to Platform by an asset lock transaction, `None` for other
+/// transactions.
+pub(crate) fn asset_lock_credit_value(tx: &Transaction) -> Option<u64> {
+    match tx.special_transaction_payload.as_ref() {
+        Some(AssetLockPayloadType(payload)) => Some(
+            payload
+                .credit_outputs
+                .iter()
+                .map(|tx_out| tx_out.value)
+                .sum(),
+        ),
+        _ => None,
+    }
+}
@@ -0,0 +1,389 @@
+//! Password protected backups of everything a user can't get back from the network: wallets,
+//! local identities with their keys, saved contracts and tokens, and scheduled votes.
+
+use crate::database::Database;
+use crate::database::initialization::DEFAULT_DB_VERSION;
+use crate::model::wallet::encryption::{decrypt_message, encrypt_message};
+use bincode::{Decode, Encode};
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{Connection, params_from_iter};
+use std::collections::BTreeMap;
+
+const BACKUP_MAGIC: &[u8; 8] = b"DETBAK01";
+
+/// Tables in a backup, in an order that satisfies their foreign keys on restore, with the
+/// condition selecting the rows to back up.
+const BACKUP_TABLES: [(&str, Option<&str>); 9] = [
+    ("wallet", None),
+    ("wallet_accounts", None),
+    ("wallet_addresses", None),
+    ("identity", Some("is_local = 1")),
+    ("top_up", None),
+    ("asset_lock_transaction", None),
+    ("contract", None),
+    ("token", None),
+    ("scheduled_votes", None),
+];
+
+/// Columns renamed by migrations, as `(table, old name, new name)`, so backups made before
+/// the rename restore into the new column.
+const RENAMED_COLUMNS: [(&str, &str, &str); 2] = [
+    ("contract", "name", "alias"),
+    ("wallet_addresses", "balance", "total_received"),
+];
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub enum BackupValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct BackupTable {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<BackupValue>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct Backup {
+    /// Schema version of the database the backup was made from
+    pub database_version: u16,
+    pub created_at: i64,
+    pub tables: Vec<BackupTable>,
+}
+
+#[derive(Encode, Decode)]
+struct EncryptedBackup {
+    salt: Vec<u8>,
+    nonce: Vec<u8>,
+    ciphertext: Vec<u8>,
+}
+
+impl From<ValueRef<'_>> for BackupValue {
+    fn from(value: ValueRef<'_>) -> Self {
+        match value {
+            ValueRef::Null => BackupValue::Null,
+            ValueRef::Integer(integer) => BackupValue::Integer(integer),
+            ValueRef::Real(real) => BackupValue::Real(real),
+            ValueRef::Text(text) => BackupValue::Text(String::from_utf8_lossy(text).into_owned()),
+            ValueRef::Blob(blob) => BackupValue::Blob(blob.to_vec()),
+        }
+    }
+}
+
+impl From<&BackupValue> for Value {
+    fn from(value: &BackupValue) -> Self {
+        match value {
+            BackupValue::Null => Value::Null,
+            BackupValue::Integer(integer) => Value::Integer(*integer),
+            BackupValue::Real(real) => Value::Real(*real),
+            BackupValue::Text(text) => Value::Text(text.clone()),
+            BackupValue::Blob(blob) => Value::Blob(blob.clone()),
+        }
+    }
+}
+
+impl Backup {
+    /// Serializes and encrypts the backup with AES-256-GCM under a key derived from
+    /// `password`.
+    pub fn to_encrypted_bytes(&self, password: &str) -> Result<Vec<u8>, String> {
+        let plaintext =
+            bincode::encode_to_vec(self, bincode::config::standard()).map_err(|e| e.to_string())?;
+        let (ciphertext, salt, nonce) = encrypt_message(&plaintext, password)?;
+        let encrypted = bincode::encode_to_vec(
+            EncryptedBackup {
+                salt,
+                nonce,
+                ciphertext,
+            },
+            bincode::config::standard(),
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok([BACKUP_MAGIC.as_slice(), &encrypted].concat())
+    }
+
+    pub fn from_encrypted_bytes(bytes: &[u8], password: &str) -> Result<Self, String> {
+        let encrypted = bytes
+            .strip_prefix(BACKUP_MAGIC.as_slice())
+            .ok_or_else(|| "This is not a Dash Evo Tool backup file".to_string())?;
+        let (encrypted, _): (EncryptedBackup, _) =
+            bincode::decode_from_slice(encrypted, bincode::config::standard())
+                .map_err(|e| format!("The backup file is damaged: {}", e))?;
+        let plaintext = decrypt_message(
+            &encrypted.ciphertext,
+            &encrypted.salt,
+            &encrypted.nonce,
+            password,
+        )
+        .map_err(|_| "Incorrect password, or the backup file is damaged".to_string())?;
+        let (backup, _) = bincode::decode_from_slice(&plaintext, bincode::config::standard())
+            .map_err(|e| format!("The backup file is damaged: {}", e))?;
+
+        Ok(backup)
+    }
+}
+
+fn table_columns(conn: &Connection, table: &str) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare("SELECT name FROM pragma_table_info(?) ORDER BY cid")?;
+    let columns = stmt
+        .query_map([table], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+    Ok(columns)
+}
+
+impl Database {
+    /// Collects the rows of every backed up table, for all networks.
+    pub fn create_backup(&self) -> rusqlite::Result<Backup> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut tables = Vec::with_capacity(BACKUP_TABLES.len());
+        for (table, condition) in BACKUP_TABLES {
+            let columns = table_columns(&conn, table)?;
+            if columns.is_empty() {
+                continue;
+            }
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {} FROM {} WHERE {}",
+                columns.join(", "),
+                table,
+                condition.unwrap_or("1")
+            ))?;
+            let rows = stmt
+                .query_map([], |row| {
+                    (0..columns.len())
+                        .map(|index| row.get_ref(index).map(BackupValue::from))
+                        .collect::<rusqlite::Result<Vec<_>>>()
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+
+            tables.push(BackupTable {
+                name: table.to_string(),
+                columns,
+                rows,
+            });
+        }
+
+        Ok(Backup {
+            database_version: DEFAULT_DB_VERSION,
+            created_at: chrono::Utc::now().timestamp(),
+            tables,
+        })
+    }
+
+    /// Merges a backup into the database in one transaction. Rows that already exist are
+    /// kept as they are, except identities only known as remote ones, which become local.
+    ///
+    /// Returns the number of restored rows per table.
+    pub fn restore_backup(&self, backup: &Backup) -> Result<BTreeMap<String, usize>, String> {
+        if backup.database_version > DEFAULT_DB_VERSION {
+            return Err(format!(
+                "The backup was made by a newer version of Dash Evo Tool (database version {}, this version supports up to {}). Please update dash-evo-tool.",
+                backup.database_version, DEFAULT_DB_VERSION
+            ));
+        }
+
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+
+        let mut restored = BTreeMap::new();
+        for (table, _) in BACKUP_TABLES {
+            let Some(backup_table) = backup.tables.iter().find(|t| t.name == table) else {
+                continue;
+            };
+
+            // Backups from older versions may lack columns added since, those get defaults.
+            // Columns renamed since are restored under their new name, anything else this
+            // version doesn't know would silently lose data.
+            let existing_columns = table_columns(&tx, table).map_err(|e| e.to_string())?;
+            let columns = backup_table
+                .columns
+                .iter()
+                .map(|column| {
+                    if existing_columns.contains(column) {
+                        return Ok(column.as_str());
+                    }
+                    RENAMED_COLUMNS
+                        .iter()
+                        .find(|(renamed_table, old_name, new_name)| {
+                            *renamed_table == table
+                                && old_name == column
+                                && existing_columns.iter().any(|existing| existing == new_name)
+                        })
+                        .map(|(_, _, new_name)| *new_name)
+                        .ok_or_else(|| {
+                            format!(
+                                "The backup has a column {} in {} that this version of Dash Evo Tool doesn't know, so it can't be restored without losing data. Please restore it with the version that made it (database version {}).",
+                                column, table, backup.database_version
+                            )
+                        })
+                })
+                .collect::<Result<Vec<&str>, String>>()?;
+            if columns.is_empty() {
+                continue;
+            }
+            let placeholders = vec!["?"; columns.len()].join(", ");
+
+            let sql = if table == "identity" {
+                let updates: Vec<String> = columns
+                    .iter()
+                    .map(|column| format!("{column} = excluded.{column}"))
+                    .collect();
+                format!(
+                    "INSERT INTO identity ({}) VALUES ({}) ON CONFLICT(id) DO UPDATE SET {} WHERE identity.is_local = 0",
+                    columns.join(", "),
+                    placeholders,
+                    updates.join(", ")
+                )
+            } else {
+                format!(
+                    "INSERT OR IGNORE INTO {} ({}) VALUES ({})",
+                    table,
+                    columns.join(", "),
+                    placeholders
+                )
+            };
+
+            let mut stmt = tx.prepare(&sql).map_err(|e| e.to_string())?;
+            let mut count = 0;
+            for row in &backup_table.rows {
+                let values = (0..columns.len())
+                    .map(|index| row.get(index).map(Value::from).unwrap_or(Value::Null));
+                count += stmt
+                    .execute(params_from_iter(values))
+                    .map_err(|e| format!("Failed to restore {}: {}", table, e))?;
+            }
+            restored.insert(table.to_string(), count);
+        }
+
+        tx.commit().map_err(|e| e.to_string())?;
+        Ok(restored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_database(dir: &tempfile::TempDir, name: &str) -> Database {
+        let db_file_path = dir.path().join(name);
+        let db = Database::new(&db_file_path).unwrap();
+        db.initialize(&db_file_path).unwrap();
+        db
+    }
+
+    #[test]
+    fn test_backup_restores_once() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let source = test_database(&temp_dir, "source.db");
+        source
+            .execute(
+                "INSERT INTO wallet (seed_hash, encrypted_seed, salt, nonce, master_ecdsa_bip44_account_0_epk, alias, is_main, uses_password, network)
+                 VALUES (?, ?, X'', X'', X'00', 'main', 1, 0, 'testnet')",
+                rusqlite::params![vec![1u8; 32], vec![2u8; 64]],
+            )
+            .unwrap();
+        source
+            .execute(
+                "INSERT INTO identity (id, data, is_local, alias, network) VALUES (?, X'00', 1, 'local', 'testnet')",
+                rusqlite::params![vec![3u8; 32]],
+            )
+            .unwrap();
+        source
+            .execute(
+                "INSERT INTO identity (id, data, is_local, alias, network) VALUES (?, X'00', 0, 'remote', 'testnet')",
+                rusqlite::params![vec![4u8; 32]],
+            )
+            .unwrap();
+
+        let bytes = source
+            .create_backup()
+            .unwrap()
+            .to_encrypted_bytes("backup password")
+            .unwrap();
+        assert!(Backup::from_encrypted_bytes(&bytes, "wrong password").is_err());
+        let backup = Backup::from_encrypted_bytes(&bytes, "backup password").unwrap();
+
+        let target = test_database(&temp_dir, "target.db");
+        let restored = target.restore_backup(&backup).unwrap();
+        assert_eq!(restored.get("wallet"), Some(&1));
+        // Only local identities are backed up
+        assert_eq!(restored.get("identity"), Some(&1));
+
+        let restored_again = target.restore_backup(&backup).unwrap();
+        assert!(restored_again.values().all(|count| *count == 0));
+    }
+
+    #[test]
+    fn test_backup_restores_renamed_columns() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let seed_hash = BackupValue::Blob(vec![1u8; 32]);
+        let backup = Backup {
+            database_version: 16,
+            created_at: 0,
+            tables: vec![
+                BackupTable {
+                    name: "wallet".to_string(),
+                    columns: [
+                        "seed_hash",
+                        "master_ecdsa_bip44_account_0_epk",
+                        "uses_password",
+                        "network",
+                    ]
+                    .map(String::from)
+                    .to_vec(),
+                    rows: vec![vec![
+                        seed_hash.clone(),
+                        BackupValue::Blob(vec![0]),
+                        BackupValue::Integer(0),
+                        BackupValue::Text("testnet".to_string()),
+                    ]],
+                },
+                BackupTable {
+                    name: "wallet_addresses".to_string(),
+                    columns: [
+                        "seed_hash",
+                        "address",
+                        "derivation_path",
+                        "balance",
+                        "path_reference",
+                        "path_type",
+                    ]
+                    .map(String::from)
+                    .to_vec(),
+                    rows: vec![vec![
+                        seed_hash,
+                        BackupValue::Text("yNPbcFfabtNmmxKdGwhHomdYfVs6gikbPf".to_string()),
+                        BackupValue::Text("m/44'/1'/0'/0/0".to_string()),
+                        BackupValue::Integer(5_000),
+                        BackupValue::Integer(0),
+                        BackupValue::Integer(0),
+                    ]],
+                },
+            ],
+        };
+
+        let target = test_database(&temp_dir, "target.db");
+        let restored = target.restore_backup(&backup).unwrap();
+        assert_eq!(restored.get("wallet_addresses"), Some(&1));
+        let total_received: i64 = target
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT total_received FROM wallet_addresses", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(total_received, 5_000);
+
+        // Columns that were neither kept nor renamed are refused
+        let mut unknown = backup.clone();
+        unknown.tables[1].columns[3] = "dropped".to_string();
+        let other_target = test_database(&temp_dir, "other_target.db");
+        assert!(other_target.restore_backup(&unknown).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use rusqlite::{Connection, params};
 use std::fs;
 use std::path::Path;
 
-pub const DEFAULT_DB_VERSION: u16 = 11;
+pub const DEFAULT_DB_VERSION: u16 = 18;
 
 pub const DEFAULT_NETWORK: &str = "dash";
 
@@ -34,6 +34,28 @@ impl Database {
 
     fn apply_version_changes(&self, version: u16, tx: &Connection) -> rusqlite::Result<()> {
         match version {
+            18 => {
+                self.initialize_utxo_reservations_table(tx)?;
+            }
+            17 => {
+                self.rename_wallet_address_balance_to_total_received(tx)?;
+            }
+            16 => {
+                self.add_passphrase_hint_column_to_wallet(tx)?;
+            }
+            15 => {
+                self.make_wallet_seed_columns_nullable(tx)?;
+            }
+            14 => {
+                self.add_account_index_column_to_wallet_addresses(tx)?;
+                self.initialize_wallet_accounts_table(tx)?;
+            }
+            13 => {
+                self.initialize_wallet_transactions_table(tx)?;
+            }
+            12 => {
+                self.add_fallback_fee_rate_column(tx)?;
+            }
             11 => self.rename_identity_column_is_in_creation_to_status(tx)?,
             10 => {
                 self.add_theme_preference_column(tx)?;
@@ -114,15 +136,31 @@ impl Database {
                     .lock()
                     .expect("Failed to lock database connection");
 
-                for version in (original_version + 1)..=to_version {
-                    let tx = conn.transaction().map_err(|e| e.to_string())?;
-                    self.apply_version_changes(version, &tx)
+                // Migrations rebuild tables, and dropping a table referenced by others would
+                // cascade. The pragma is a no-op inside a transaction, so it is set around them.
+                let foreign_keys: bool = conn
+                    .query_row("PRAGMA foreign_keys", [], |row| row.get(0))
+                    .map_err(|e| e.to_string())?;
+                conn.execute("PRAGMA foreign_keys = OFF", [])
+                    .map_err(|e| e.to_string())?;
+
+                let result = (|| -> Result<bool, String> {
+                    for version in (original_version + 1)..=to_version {
+                        let tx = conn.transaction().map_err(|e| e.to_string())?;
+                        self.apply_version_changes(version, &tx)
+                            .map_err(|e| e.to_string())?;
+                        self.update_database_version(version, &tx)
+                            .map_err(|e| e.to_string())?;
+                        tx.commit().map_err(|e| e.to_string())?;
+                    }
+                    Ok(true)
+                })();
+
+                if foreign_keys {
+                    conn.execute("PRAGMA foreign_keys = ON", [])
                         .map_err(|e| e.to_string())?;
-                    self.update_database_version(version, &tx)
-                        .map_err(|e| e.to_string())?;
-                    tx.commit().map_err(|e| e.to_string())?;
                 }
-                Ok(true)
+                result
             }
         }
     }
@@ -216,6 +254,7 @@ impl Database {
             custom_dash_qt_path TEXT,
             overwrite_dash_conf INTEGER,
             theme_preference TEXT DEFAULT 'System',
+            fallback_fee_rate INTEGER,
             database_version INTEGER NOT NULL
         )",
             [],
@@ -225,14 +264,15 @@ impl Database {
         conn.execute(
             "CREATE TABLE IF NOT EXISTS wallet (
                 seed_hash BLOB NOT NULL PRIMARY KEY,
-                encrypted_seed BLOB NOT NULL,
-                salt BLOB NOT NULL,
-                nonce BLOB NOT NULL,
+                encrypted_seed BLOB,
+                salt BLOB,
+                nonce BLOB,
                 master_ecdsa_bip44_account_0_epk BLOB NOT NULL,
                 alias TEXT,
                 is_main INTEGER,
                 uses_password INTEGER NOT NULL,
                 password_hint TEXT,
+                passphrase_hint TEXT,
                 network TEXT NOT NULL
             )",
             [],
@@ -244,9 +284,10 @@ impl Database {
                 seed_hash BLOB NOT NULL,
                 address TEXT NOT NULL,
                 derivation_path TEXT NOT NULL,
-                balance INTEGER,
+                total_received INTEGER,
                 path_reference INTEGER NOT NULL,
                 path_type INTEGER NOT NULL,
+                account_index INTEGER,
                 PRIMARY KEY (seed_hash, address),
                 FOREIGN KEY (seed_hash) REFERENCES wallet(seed_hash) ON DELETE CASCADE
             )",
@@ -385,6 +426,9 @@ impl Database {
         self.initialize_identity_order_table(&conn)?;
         self.initialize_token_order_table(&conn)?;
         self.initialize_identity_token_balances_table(&conn)?;
+        self.initialize_wallet_transactions_table(&conn)?;
+        self.initialize_wallet_accounts_table(&conn)?;
+        self.initialize_utxo_reservations_table(&conn)?;
 
         Ok(())
     }
@@ -496,4 +540,71 @@ mod test {
             "Identity should not be deleted during migration failure"
         );
     }
+
+    // Given a wallet table from before version 15 with addresses referencing it,
+    // when the seed columns are made nullable with foreign keys enabled,
+    // then the addresses survive the table rebuild.
+    #[test]
+    fn test_wallet_rebuild_keeps_dependent_rows() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_file_path = temp_dir.path().join("test_data.db");
+        let db = super::Database::new(&db_file_path).unwrap();
+
+        db.create_tables().unwrap();
+        db.set_db_version(14).unwrap();
+
+        let conn = db.conn.lock().unwrap();
+        conn.execute("DROP TABLE wallet", []).unwrap();
+        conn.execute(
+            "CREATE TABLE wallet (
+                seed_hash BLOB NOT NULL PRIMARY KEY,
+                encrypted_seed BLOB NOT NULL,
+                salt BLOB NOT NULL,
+                nonce BLOB NOT NULL,
+                master_ecdsa_bip44_account_0_epk BLOB NOT NULL,
+                alias TEXT,
+                is_main INTEGER,
+                uses_password INTEGER NOT NULL,
+                password_hint TEXT,
+                network TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO wallet VALUES (X'01', X'02', X'03', X'04', X'05', NULL, 1, 0, NULL, 'regtest')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO wallet_addresses (seed_hash, address, derivation_path, path_reference, path_type)
+             VALUES (X'01', 'address', 'm/44''/1''/0''/0/0', 0, 0)",
+            [],
+        )
+        .unwrap();
+        conn.execute("PRAGMA foreign_keys = ON", []).unwrap();
+        drop(conn);
+
+        db.try_perform_migration(14, 15).unwrap();
+
+        let conn = db.conn.lock().unwrap();
+        let addresses: i64 = conn
+            .query_row("SELECT COUNT(*) FROM wallet_addresses", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(addresses, 1, "Addresses should survive the wallet rebuild");
+        let encrypted_seed_not_null: bool = conn
+            .query_row(
+                "SELECT \"notnull\" FROM pragma_table_info('wallet') WHERE name='encrypted_seed'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(!encrypted_seed_not_null);
+        let foreign_keys: bool = conn
+            .query_row("PRAGMA foreign_keys", [], |row| row.get(0))
+            .unwrap();
+        assert!(foreign_keys, "Foreign keys should be enabled again");
+    }
 }
@@ -1,4 +1,5 @@
 mod asset_lock_transaction;
+pub mod backup;
 mod contested_names;
 pub(crate) mod contracts;
 mod identities;
@@ -10,6 +11,8 @@ mod tokens;
 mod top_ups;
 mod utxo;
 mod wallet;
+mod wallet_accounts;
+mod wallet_transactions;
 
 use rusqlite::{Connection, Params};
 use std::sync::Mutex;
@@ -130,6 +130,51 @@ impl Database {
         Ok(())
     }
 
+    pub fn add_fallback_fee_rate_column(&self, conn: &rusqlite::Connection) -> Result<()> {
+        // Check if fallback_fee_rate column exists
+        let fallback_fee_rate_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('settings') WHERE name='fallback_fee_rate'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !fallback_fee_rate_exists {
+            conn.execute(
+                "ALTER TABLE settings ADD COLUMN fallback_fee_rate INTEGER DEFAULT NULL;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Fee rate in duffs per kilobyte to use when Dash Core can't estimate one.
+    pub fn get_fallback_fee_rate(&self) -> Result<Option<u64>> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT fallback_fee_rate FROM settings WHERE id = 1",
+            [],
+            |row| row.get::<_, Option<u64>>(0),
+        );
+
+        match result {
+            Ok(fee_rate) => Ok(fee_rate),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn update_fallback_fee_rate(&self, fallback_fee_rate: Option<u64>) -> Result<()> {
+        self.execute(
+            "UPDATE settings
+            SET fallback_fee_rate = ?
+            WHERE id = 1",
+            rusqlite::params![fallback_fee_rate],
+        )?;
+
+        Ok(())
+    }
+
     /// Updates the database version in the settings table.
     pub fn update_database_version(&self, new_version: u16, conn: &Connection) -> Result<()> {
         // Ensure the database version is updated
@@ -42,4 +42,18 @@ impl Database {
         )?;
         Ok(())
     }
+
+    /// Like [`Database::insert_top_up`], but keeps a top up that is already recorded.
+    pub fn insert_top_up_if_missing(
+        &self,
+        identity_id: &[u8],
+        top_up_index: u32,
+        amount: u64,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "INSERT OR IGNORE INTO top_up (identity_id, top_up_index, amount) VALUES (?, ?, ?)",
+            params![identity_id, top_up_index, amount],
+        )?;
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use crate::database::Database;
+use crate::model::wallet::utxo_reservation::UtxoReservation;
 use dash_sdk::dashcore_rpc::dashcore::{OutPoint, ScriptBuf, TxOut, Txid};
 use dash_sdk::dpp::dashcore::hashes::Hash;
 use dash_sdk::dpp::dashcore::{Address, Network};
@@ -42,6 +43,80 @@ impl Database {
         Ok(())
     }
 
+    pub fn initialize_utxo_reservations_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS utxo_reservations (
+                txid BLOB NOT NULL,
+                vout INTEGER NOT NULL,
+                wallet BLOB NOT NULL,
+                pending_txid BLOB NOT NULL,
+                reserved_at INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (txid, vout, network),
+                FOREIGN KEY (wallet) REFERENCES wallet(seed_hash) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Reserves a UTXO of `wallet_seed_hash` for a pending transaction, replacing any
+    /// earlier reservation of it.
+    pub fn reserve_utxo(
+        &self,
+        outpoint: &OutPoint,
+        wallet_seed_hash: &[u8; 32],
+        reservation: &UtxoReservation,
+        network: Network,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "INSERT OR REPLACE INTO utxo_reservations
+                (txid, vout, wallet, pending_txid, reserved_at, network)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                outpoint.txid.as_byte_array(),
+                outpoint.vout,
+                wallet_seed_hash,
+                reservation.pending_txid.as_byte_array(),
+                reservation.reserved_at,
+                network.to_string()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Releases all UTXOs reserved for `pending_txid`.
+    pub fn release_utxo_reservations(
+        &self,
+        pending_txid: &Txid,
+        network: Network,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "DELETE FROM utxo_reservations WHERE pending_txid = ? AND network = ?",
+            params![pending_txid.as_byte_array(), network.to_string()],
+        )?;
+        Ok(())
+    }
+
+    pub fn release_utxo_reservation(
+        &self,
+        outpoint: &OutPoint,
+        network: Network,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "DELETE FROM utxo_reservations WHERE txid = ? AND vout = ? AND network = ?",
+            params![
+                outpoint.txid.as_byte_array(),
+                outpoint.vout,
+                network.to_string()
+            ],
+        )?;
+        Ok(())
+    }
+
     #[allow(dead_code)] // May be used for address-specific UTXO queries
     fn get_utxos_by_address(
         &self,
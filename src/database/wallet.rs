@@ -1,5 +1,7 @@
 use crate::database::Database;
 use crate::model::qualified_identity::QualifiedIdentity;
+use crate::model::wallet::accounts::{WalletAccount, bip44_account_of_path};
+use crate::model::wallet::utxo_reservation::UtxoReservation;
 use crate::model::wallet::{
     AddressInfo, ClosedKeyItem, DerivationPathReference, DerivationPathType, OpenWalletSeed,
     Wallet, WalletSeed,
@@ -33,8 +35,8 @@ impl Database {
             wallet.master_bip44_ecdsa_extended_public_key.encode();
 
         self.execute(
-            "INSERT INTO wallet (seed_hash, encrypted_seed, salt, nonce, master_ecdsa_bip44_account_0_epk, alias, is_main, uses_password, password_hint, network)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO wallet (seed_hash, encrypted_seed, salt, nonce, master_ecdsa_bip44_account_0_epk, alias, is_main, uses_password, password_hint, passphrase_hint, network)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 wallet.seed_hash(),
                 wallet.encrypted_seed_slice(),
@@ -45,6 +47,7 @@ impl Database {
                 wallet.is_main as i32,
                 wallet.uses_password,
                 wallet.password_hint().clone(),
+                wallet.passphrase_hint().clone(),
                 network_str
             ],
         )?;
@@ -68,6 +71,41 @@ impl Database {
         Ok(())
     }
 
+    /// Stores the re-encrypted seed of a wallet after a password change, together with the
+    /// identities whose keys were re-encrypted along with it, in one transaction.
+    pub fn update_wallet_password(
+        &self,
+        wallet: &Wallet,
+        identities: &[QualifiedIdentity],
+    ) -> rusqlite::Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+
+        let rows_affected = tx.execute(
+            "UPDATE wallet SET encrypted_seed = ?, salt = ?, nonce = ?, uses_password = ?, password_hint = ? WHERE seed_hash = ?",
+            params![
+                wallet.encrypted_seed_slice(),
+                wallet.salt(),
+                wallet.nonce(),
+                wallet.uses_password,
+                wallet.password_hint().clone(),
+                wallet.seed_hash()
+            ],
+        )?;
+        if rows_affected == 0 {
+            return Err(rusqlite::Error::QueryReturnedNoRows);
+        }
+
+        for identity in identities {
+            tx.execute(
+                "UPDATE identity SET data = ? WHERE id = ?",
+                params![identity.to_bytes(), identity.identity.id().to_vec()],
+            )?;
+        }
+
+        tx.commit()
+    }
+
     /// Update only the alias and is_main fields of a wallet
     #[allow(dead_code)] // May be used for batch wallet metadata updates
     pub fn update_wallet_alias_and_main(
@@ -83,7 +121,7 @@ impl Database {
         Ok(())
     }
 
-    /// Add a new address to a wallet with optional balance.
+    /// Add a new address to a wallet with the optional amount it received so far.
     /// If the address already exists, it does nothing.
     #[allow(clippy::too_many_arguments)]
     pub fn add_address_if_not_exists(
@@ -94,7 +132,7 @@ impl Database {
         derivation_path: &DerivationPath,
         path_reference: DerivationPathReference,
         path_type: DerivationPathType,
-        balance: Option<u64>,
+        total_received: Option<u64>,
     ) -> rusqlite::Result<()> {
         let conn = self.conn.lock().unwrap();
 
@@ -113,33 +151,151 @@ impl Database {
         if count == 0 {
             conn.execute(
                 "INSERT INTO wallet_addresses
-             (seed_hash, address, derivation_path, path_reference, path_type, balance)
-             VALUES (?, ?, ?, ?, ?, ?)",
+             (seed_hash, address, derivation_path, path_reference, path_type, total_received, account_index)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
                 params![
                     seed_hash,
                     address.to_string(),
                     derivation_path.to_string(),
                     path_reference as u32,
                     path_type.bits(),
-                    balance,
+                    total_received,
+                    bip44_account_of_path(derivation_path),
                 ],
             )?;
         }
         Ok(())
     }
 
-    /// Update the balance of an existing address.
-    pub fn update_address_balance(
+    /// Adds the BIP44 account of each address to `wallet_addresses`. Addresses from before
+    /// accounts were supported all belong to account 0.
+    pub fn add_account_index_column_to_wallet_addresses(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        let account_index_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('wallet_addresses') WHERE name='account_index'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !account_index_exists {
+            conn.execute(
+                "ALTER TABLE wallet_addresses ADD COLUMN account_index INTEGER DEFAULT NULL;",
+                (),
+            )?;
+            conn.execute(
+                "UPDATE wallet_addresses SET account_index = 0 WHERE derivation_path LIKE 'm/44''/%'",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds `passphrase_hint` to `wallet`, for wallets derived with a BIP39 passphrase.
+    pub fn add_passphrase_hint_column_to_wallet(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        let passphrase_hint_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('wallet') WHERE name='passphrase_hint'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !passphrase_hint_exists {
+            conn.execute(
+                "ALTER TABLE wallet ADD COLUMN passphrase_hint TEXT DEFAULT NULL;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// The `balance` of `wallet_addresses` always held the total an address received, which
+    /// is no balance once funds are spent. Balances are derived from unspent outputs instead.
+    pub fn rename_wallet_address_balance_to_total_received(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        let balance_exists: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('wallet_addresses') WHERE name='balance'",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if balance_exists {
+            conn.execute(
+                "ALTER TABLE wallet_addresses RENAME COLUMN balance TO total_received;",
+                (),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Watch-only wallets have no seed, so the seed columns of `wallet` must allow NULL.
+    ///
+    /// SQLite can't drop a NOT NULL constraint, so the table is rebuilt. Tables referencing
+    /// `wallet` keep pointing at it by name, which is why the old table is dropped before the
+    /// new one takes its name rather than renamed away. Dropping it must not cascade, so this
+    /// has to run with foreign keys disabled, as migrations do.
+    pub fn make_wallet_seed_columns_nullable(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        let encrypted_seed_not_null: bool = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('wallet') WHERE name='encrypted_seed' AND \"notnull\" = 1",
+            [],
+            |row| row.get::<_, i32>(0).map(|count| count > 0),
+        )?;
+
+        if !encrypted_seed_not_null {
+            return Ok(());
+        }
+
+        conn.execute(
+            "CREATE TABLE wallet_new (
+                seed_hash BLOB NOT NULL PRIMARY KEY,
+                encrypted_seed BLOB,
+                salt BLOB,
+                nonce BLOB,
+                master_ecdsa_bip44_account_0_epk BLOB NOT NULL,
+                alias TEXT,
+                is_main INTEGER,
+                uses_password INTEGER NOT NULL,
+                password_hint TEXT,
+                network TEXT NOT NULL
+            )",
+            [],
+        )?;
+        conn.execute(
+            "INSERT INTO wallet_new
+             SELECT seed_hash, encrypted_seed, salt, nonce, master_ecdsa_bip44_account_0_epk,
+                    alias, is_main, uses_password, password_hint, network
+             FROM wallet",
+            [],
+        )?;
+        conn.execute("DROP TABLE wallet", [])?;
+        conn.execute("ALTER TABLE wallet_new RENAME TO wallet", [])?;
+
+        Ok(())
+    }
+
+    /// Update the total received by an existing address.
+    pub fn update_address_total_received(
         &self,
         seed_hash: &[u8; 32],
         address: &Address,
-        new_balance: u64,
+        total_received: u64,
     ) -> rusqlite::Result<()> {
         let rows_affected = self.execute(
             "UPDATE wallet_addresses
-         SET balance = ?
+         SET total_received = ?
          WHERE seed_hash = ? AND address = ?",
-            params![new_balance, seed_hash, address.to_string()],
+            params![total_received, seed_hash, address.to_string()],
         )?;
 
         if rows_affected == 0 {
@@ -149,18 +305,18 @@ impl Database {
         }
     }
 
-    /// Add a balance to an existing address.
-    pub fn add_to_address_balance(
+    /// Add a received amount to the total received by an existing address.
+    pub fn add_to_address_total_received(
         &self,
         seed_hash: &[u8; 32],
         address: &Address,
-        additional_balance: u64,
+        received: u64,
     ) -> rusqlite::Result<()> {
         let rows_affected = self.execute(
             "UPDATE wallet_addresses
-         SET balance = balance + ?
+         SET total_received = COALESCE(total_received, 0) + ?
          WHERE seed_hash = ? AND address = ?",
-            params![additional_balance, seed_hash, address.to_string()],
+            params![received, seed_hash, address.to_string()],
         )?;
 
         if rows_affected == 0 {
@@ -170,28 +326,29 @@ impl Database {
         }
     }
 
-    /// Retrieve all wallets for a specific network, including their addresses, balances, and known addresses.
+    /// Retrieve all wallets for a specific network, including their addresses, totals received, and known addresses.
     pub fn get_wallets(&self, network: &Network) -> rusqlite::Result<Vec<Wallet>> {
         let network_str = network.to_string();
         let conn = self.conn.lock().unwrap();
 
         tracing::trace!("step 1: retrieve all wallets for the given network");
         let mut stmt = conn.prepare(
-            "SELECT seed_hash, encrypted_seed, salt, nonce, master_ecdsa_bip44_account_0_epk, alias, is_main, uses_password, password_hint FROM wallet WHERE network = ?",
+            "SELECT seed_hash, encrypted_seed, salt, nonce, master_ecdsa_bip44_account_0_epk, alias, is_main, uses_password, password_hint, passphrase_hint FROM wallet WHERE network = ?",
         )?;
 
         let mut wallets_map: BTreeMap<[u8; 32], Wallet> = BTreeMap::new();
 
         let wallet_rows = stmt.query_map([network_str.clone()], |row| {
             let seed_hash: Vec<u8> = row.get(0)?;
-            let encrypted_seed: Vec<u8> = row.get(1)?;
-            let salt: Vec<u8> = row.get(2)?;
-            let nonce: Vec<u8> = row.get(3)?;
+            let encrypted_seed: Option<Vec<u8>> = row.get(1)?;
+            let salt: Option<Vec<u8>> = row.get(2)?;
+            let nonce: Option<Vec<u8>> = row.get(3)?;
             let master_ecdsa_bip44_account_0_epk_bytes: Vec<u8> = row.get(4)?;
             let alias: Option<String> = row.get(5)?;
             let is_main: bool = row.get(6)?;
             let uses_password: bool = row.get(7)?;
             let password_hint: Option<String> = row.get(8)?;
+            let passphrase_hint: Option<String> = row.get(9)?;
 
             // Reconstruct the extended public keys
             let master_ecdsa_extended_public_key =
@@ -200,22 +357,29 @@ impl Database {
 
             let seed_hash_array: [u8; 32] =
                 seed_hash.try_into().expect("Seed hash should be 32 bytes");
-            let closed_wallet_seed = ClosedKeyItem {
-                seed_hash: seed_hash_array,
-                encrypted_seed: encrypted_seed.clone(),
-                salt,
-                nonce,
-                password_hint,
-            };
-            let wallet_seed = if uses_password {
-                WalletSeed::Closed(closed_wallet_seed)
-            } else {
-                WalletSeed::Open(OpenWalletSeed {
-                    seed: encrypted_seed
-                        .try_into()
-                        .expect("expected to decrypt seed with no password"),
-                    wallet_info: closed_wallet_seed,
-                })
+            let wallet_seed = match encrypted_seed {
+                // Watch-only wallets are the only ones without a seed
+                None => WalletSeed::WatchOnly(seed_hash_array),
+                Some(encrypted_seed) => {
+                    let closed_wallet_seed = ClosedKeyItem {
+                        seed_hash: seed_hash_array,
+                        encrypted_seed: encrypted_seed.clone(),
+                        salt: salt.unwrap_or_default(),
+                        nonce: nonce.unwrap_or_default(),
+                        password_hint,
+                        passphrase_hint,
+                    };
+                    if uses_password {
+                        WalletSeed::Closed(closed_wallet_seed)
+                    } else {
+                        WalletSeed::Open(OpenWalletSeed {
+                            seed: encrypted_seed
+                                .try_into()
+                                .expect("expected to decrypt seed with no password"),
+                            wallet_info: closed_wallet_seed,
+                        })
+                    }
+                }
             };
 
             tracing::trace!(
@@ -232,13 +396,16 @@ impl Database {
                     wallet_seed,
                     uses_password,
                     master_bip44_ecdsa_extended_public_key: master_ecdsa_extended_public_key,
-                    address_balances: BTreeMap::new(),
+                    accounts: WalletAccount::default_accounts(master_ecdsa_extended_public_key),
+                    address_total_received: BTreeMap::new(),
                     known_addresses: BTreeMap::new(),
                     watched_addresses: BTreeMap::new(),
                     unused_asset_locks: vec![],
                     alias,
                     identities: HashMap::new(),
                     utxos: HashMap::new(),
+                    utxo_statuses: HashMap::new(),
+                    utxo_reservations: HashMap::new(),
                     is_main,
                 },
             );
@@ -251,18 +418,56 @@ impl Database {
             wallet?;
         }
 
+        tracing::trace!("step 1b: retrieve the BIP44 accounts of the wallets");
+        let mut account_stmt = conn.prepare(
+            "SELECT seed_hash, account_index, extended_public_key, name, hidden FROM wallet_accounts WHERE seed_hash IN (SELECT seed_hash FROM wallet WHERE network = ?)",
+        )?;
+
+        let account_rows = account_stmt.query_map([network_str.clone()], |row| {
+            let seed_hash: Vec<u8> = row.get(0)?;
+            let extended_public_key: Vec<u8> = row.get(2)?;
+
+            let seed_hash_array: [u8; 32] =
+                seed_hash.try_into().expect("Seed hash should be 32 bytes");
+            let extended_public_key =
+                ExtendedPubKey::decode(&extended_public_key).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        2,
+                        rusqlite::types::Type::Blob,
+                        Box::new(e),
+                    )
+                })?;
+
+            Ok((
+                seed_hash_array,
+                WalletAccount {
+                    index: row.get(1)?,
+                    name: row.get(3)?,
+                    extended_public_key,
+                    hidden: row.get(4)?,
+                },
+            ))
+        })?;
+
+        for row in account_rows {
+            let (seed_hash_array, account) = row?;
+            if let Some(wallet) = wallets_map.get_mut(&seed_hash_array) {
+                wallet.accounts.insert(account.index, account);
+            }
+        }
+
         tracing::trace!(
-            "step 2: retrieve all addresses, balances, and derivation paths associated with the wallets"
+            "step 2: retrieve all addresses, totals received, and derivation paths associated with the wallets"
         );
         let mut address_stmt = conn.prepare(
-            "SELECT seed_hash, address, derivation_path, balance, path_reference, path_type FROM wallet_addresses WHERE seed_hash IN (SELECT seed_hash FROM wallet WHERE network = ?)",
+            "SELECT seed_hash, address, derivation_path, total_received, path_reference, path_type FROM wallet_addresses WHERE seed_hash IN (SELECT seed_hash FROM wallet WHERE network = ?)",
         )?;
 
         let address_rows = address_stmt.query_map([network_str.clone()], |row| {
             let seed_hash: Vec<u8> = row.get(0)?;
             let address: String = row.get(1)?;
             let derivation_path: String = row.get(2)?;
-            let balance: Option<u64> = row.get(3)?;
+            let total_received: Option<u64> = row.get(3)?;
             let path_reference: u32 = row.get(4)?;
             let path_type: u32 = row.get(5)?;
 
@@ -290,22 +495,25 @@ impl Database {
                 seed_hash_array,
                 address,
                 derivation_path,
-                balance,
+                total_received,
                 path_reference,
                 path_type,
             ))
         })?;
 
-        tracing::trace!("step 3: add addresses, balances, and known addresses to wallets");
+        tracing::trace!("step 3: add addresses, totals received, and known addresses to wallets");
         for row in address_rows {
             if row.is_err() {
                 continue;
             }
-            let (seed_array, address, derivation_path, balance, path_reference, path_type) = row?;
+            let (seed_array, address, derivation_path, total_received, path_reference, path_type) =
+                row?;
             if let Some(wallet) = wallets_map.get_mut(&seed_array) {
-                // Update the address balance if available.
-                if let Some(balance) = balance {
-                    wallet.address_balances.insert(address.clone(), balance);
+                // Update the total received by the address if available.
+                if let Some(total_received) = total_received {
+                    wallet
+                        .address_total_received
+                        .insert(address.clone(), total_received);
                 }
 
                 // Add the address to the `known_addresses` map.
@@ -371,6 +579,38 @@ impl Database {
                 }
             }
         }
+
+        tracing::trace!("step 5b: add the UTXO reservations of pending transactions");
+        let mut reservation_stmt = conn.prepare(
+            "SELECT wallet, txid, vout, pending_txid, reserved_at FROM utxo_reservations WHERE network = ?",
+        )?;
+        let reservation_rows = reservation_stmt.query_map([network_str.clone()], |row| {
+            let wallet_seed: Vec<u8> = row.get(0)?;
+            let txid: Vec<u8> = row.get(1)?;
+            let vout: i64 = row.get(2)?;
+            let pending_txid: Vec<u8> = row.get(3)?;
+            let reserved_at: i64 = row.get(4)?;
+
+            let outpoint = OutPoint {
+                txid: Txid::from_slice(&txid).expect("Invalid txid"),
+                vout: vout as u32,
+            };
+            let reservation = UtxoReservation {
+                pending_txid: Txid::from_slice(&pending_txid).expect("Invalid txid"),
+                reserved_at,
+            };
+            Ok((wallet_seed, outpoint, reservation))
+        })?;
+        for row in reservation_rows {
+            let (wallet_seed, outpoint, reservation) = row?;
+            let Ok(wallet_seed) = <[u8; 32]>::try_from(wallet_seed) else {
+                continue;
+            };
+            if let Some(wallet) = wallets_map.get_mut(&wallet_seed) {
+                wallet.utxo_reservations.insert(outpoint, reservation);
+            }
+        }
+
         tracing::trace!("step 6: load asset lock transactions for each wallet");
         let mut asset_lock_stmt = conn.prepare(
             "SELECT wallet, amount, transaction_data, instant_lock_data, chain_locked_height FROM asset_lock_transaction where identity_id IS NULL AND network = ?",
@@ -0,0 +1,47 @@
+use crate::database::Database;
+use crate::model::wallet::accounts::WalletAccount;
+use rusqlite::params;
+
+impl Database {
+    pub fn initialize_wallet_accounts_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS wallet_accounts (
+                seed_hash BLOB NOT NULL,
+                account_index INTEGER NOT NULL,
+                extended_public_key BLOB NOT NULL,
+                name TEXT,
+                hidden INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (seed_hash, account_index),
+                FOREIGN KEY (seed_hash) REFERENCES wallet(seed_hash) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Inserts or updates a BIP44 account of a wallet.
+    pub fn store_wallet_account(
+        &self,
+        seed_hash: &[u8; 32],
+        account: &WalletAccount,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "INSERT INTO wallet_accounts (seed_hash, account_index, extended_public_key, name, hidden)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(seed_hash, account_index) DO UPDATE SET
+                name = excluded.name,
+                hidden = excluded.hidden",
+            params![
+                seed_hash,
+                account.index,
+                account.extended_public_key.encode().to_vec(),
+                account.name,
+                account.hidden,
+            ],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use crate::database::Database;
+use crate::model::wallet::wallet_transaction::{TransactionDirection, WalletTransaction};
+use dash_sdk::dpp::dashcore::hashes::Hash;
+use dash_sdk::dpp::dashcore::{Network, Txid};
+use dash_sdk::platform::Identifier;
+use rusqlite::params;
+
+impl Database {
+    pub fn initialize_wallet_transactions_table(
+        &self,
+        conn: &rusqlite::Connection,
+    ) -> rusqlite::Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS wallet_transactions (
+                txid BLOB NOT NULL,
+                wallet BLOB NOT NULL,
+                direction INTEGER NOT NULL,
+                amount INTEGER NOT NULL,
+                fee INTEGER,
+                confirmations INTEGER NOT NULL DEFAULT 0,
+                identity_id BLOB,
+                timestamp INTEGER NOT NULL,
+                network TEXT NOT NULL,
+                PRIMARY KEY (txid, wallet, network),
+                FOREIGN KEY (wallet) REFERENCES wallet(seed_hash) ON DELETE CASCADE
+            )",
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_wallet_transactions_wallet_network
+             ON wallet_transactions (wallet, network)",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Stores a wallet transaction.
+    ///
+    /// Rows carrying a fee come from the wallet's own broadcasts and know the direction and
+    /// amount best, so they overwrite what was learned from the network (e.g. the change
+    /// output of a payment coming back over ZMQ), while other rows never overwrite them.
+    /// Confirmations only ever go up and a missing fee or identity is filled in.
+    pub fn insert_wallet_transaction(
+        &self,
+        wallet_seed_hash: &[u8; 32],
+        transaction: &WalletTransaction,
+        network: Network,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "INSERT INTO wallet_transactions
+                (txid, wallet, direction, amount, fee, confirmations, identity_id, timestamp, network)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+             ON CONFLICT(txid, wallet, network) DO UPDATE SET
+                direction = CASE WHEN wallet_transactions.fee IS NULL AND excluded.fee IS NOT NULL
+                    THEN excluded.direction ELSE wallet_transactions.direction END,
+                amount = CASE WHEN wallet_transactions.fee IS NULL AND excluded.fee IS NOT NULL
+                    THEN excluded.amount ELSE wallet_transactions.amount END,
+                fee = COALESCE(wallet_transactions.fee, excluded.fee),
+                confirmations = MAX(wallet_transactions.confirmations, excluded.confirmations),
+                identity_id = COALESCE(wallet_transactions.identity_id, excluded.identity_id)",
+            params![
+                transaction.txid.as_byte_array(),
+                wallet_seed_hash,
+                transaction.direction.to_int(),
+                transaction.amount,
+                transaction.fee,
+                transaction.confirmations,
+                transaction.identity_id.map(|id| id.to_vec()),
+                transaction.timestamp,
+                network.to_string(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Updates the confirmations of a transaction in every wallet that knows it.
+    pub fn update_wallet_transaction_confirmations(
+        &self,
+        txid: &Txid,
+        confirmations: u32,
+        network: Network,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "UPDATE wallet_transactions
+             SET confirmations = MAX(confirmations, ?)
+             WHERE txid = ? AND network = ?",
+            params![confirmations, txid.as_byte_array(), network.to_string()],
+        )?;
+        Ok(())
+    }
+
+    /// Links an asset lock transaction to the identity it funded.
+    pub fn set_wallet_transaction_identity_id(
+        &self,
+        txid: &[u8; 32],
+        identity_id: &[u8; 32],
+        network: Network,
+    ) -> rusqlite::Result<()> {
+        self.execute(
+            "UPDATE wallet_transactions SET identity_id = ? WHERE txid = ? AND network = ?",
+            params![identity_id, txid, network.to_string()],
+        )?;
+        Ok(())
+    }
+
+    /// Retrieves the transactions of a wallet, newest first.
+    pub fn get_wallet_transactions(
+        &self,
+        wallet_seed_hash: &[u8; 32],
+        network: Network,
+    ) -> rusqlite::Result<Vec<WalletTransaction>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT txid, direction, amount, fee, confirmations, identity_id, timestamp
+             FROM wallet_transactions
+             WHERE wallet = ? AND network = ?
+             ORDER BY timestamp DESC",
+        )?;
+
+        let rows = stmt.query_map(params![wallet_seed_hash, network.to_string()], |row| {
+            let txid_bytes: Vec<u8> = row.get(0)?;
+            let direction: u8 = row.get(1)?;
+            let identity_id: Option<Vec<u8>> = row.get(5)?;
+
+            let txid = Txid::from_slice(&txid_bytes)
+                .map_err(|e| rusqlite::Error::UserFunctionError(Box::new(e)))?;
+            let direction =
+                TransactionDirection::from_int(direction).ok_or(rusqlite::Error::InvalidQuery)?;
+            let identity_id = identity_id
+                .map(|bytes| Identifier::from_bytes(&bytes))
+                .transpose()
+                .map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        5,
+                        rusqlite::types::Type::Blob,
+                        Box::new(e),
+                    )
+                })?;
+
+            Ok(WalletTransaction {
+                txid,
+                direction,
+                amount: row.get(2)?,
+                fee: row.get(3)?,
+                confirmations: row.get(4)?,
+                identity_id,
+                timestamp: row.get(6)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+}
@@ -2,6 +2,7 @@ pub mod app;
 pub mod app_dir;
 pub mod backend_task;
 pub mod bundled;
+pub mod chain_data;
 pub mod components;
 pub mod config;
 pub mod context;
@@ -0,0 +1,82 @@
+//! Identities looked up by their DPNS username, e.g. `alice.dash`.
+
+use dash_sdk::platform::Identifier;
+
+/// Parent domain of all usernames.
+pub const DPNS_PARENT_DOMAIN: &str = "dash";
+
+/// The identity a DPNS username points to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DpnsNameResolution {
+    /// Name as it was entered
+    pub query: String,
+    /// Label as registered. Lookups ignore case and fold characters that look alike, such as
+    /// `o` and `0`, so it can differ from the entered one.
+    pub label: String,
+    pub identity_id: Identifier,
+    /// Other usernames of the identity
+    pub other_names: Vec<String>,
+}
+
+impl DpnsNameResolution {
+    pub fn name(&self) -> String {
+        format!("{}.{}", self.label, DPNS_PARENT_DOMAIN)
+    }
+
+    /// A warning if the entered name only matched the registered one after folding
+    /// characters that look alike, e.g. `a1ice.dash` for `alice.dash`.
+    pub fn homograph_warning(&self) -> Option<String> {
+        let entered = dpns_label(&self.query)?;
+        if entered.eq_ignore_ascii_case(&self.label) {
+            return None;
+        }
+        Some(format!(
+            "You entered \"{}\", but the name is registered as \"{}\", which only looks alike. Make sure this is the identity you expect.",
+            entered, self.label
+        ))
+    }
+}
+
+/// The label of a DPNS username such as `alice.dash` or `alice`, or `None` if `input` isn't
+/// one.
+pub fn dpns_label(input: &str) -> Option<&str> {
+    let input = input.trim();
+    let label = match input.rsplit_once('.') {
+        Some((label, parent)) if parent.eq_ignore_ascii_case(DPNS_PARENT_DOMAIN) => label,
+        Some(_) => return None,
+        None => input,
+    };
+    let valid = (3..=63).contains(&label.len())
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !label.starts_with('-')
+        && !label.ends_with('-');
+    valid.then_some(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dpns_label() {
+        assert_eq!(dpns_label("alice.dash"), Some("alice"));
+        assert_eq!(dpns_label(" Alice.DASH "), Some("Alice"));
+        assert_eq!(dpns_label("alice"), Some("alice"));
+        assert_eq!(dpns_label("alice.eth"), None);
+        assert_eq!(dpns_label("al"), None);
+        assert_eq!(dpns_label("-alice.dash"), None);
+        assert_eq!(dpns_label("al ice.dash"), None);
+    }
+
+    #[test]
+    fn test_homograph_warning() {
+        let resolution = |query: &str| DpnsNameResolution {
+            query: query.to_string(),
+            label: "alice".to_string(),
+            identity_id: Identifier::default(),
+            other_names: vec![],
+        };
+        assert_eq!(resolution("Alice.dash").homograph_warning(), None);
+        assert!(resolution("a1ice.dash").homograph_warning().is_some());
+    }
+}
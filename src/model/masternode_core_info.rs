@@ -0,0 +1,147 @@
+//! Masternodes as registered on Core by their ProTx, used to load their identities.
+
+use crate::model::qualified_identity::IdentityType;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::{Address, Network, PrivateKey};
+use std::fmt;
+use zeroize::Zeroize;
+
+/// The keys and addresses of a masternode registration, from `protx info` / `protx list`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasternodeCoreInfo {
+    /// Hex, as Core shows it. It is also the ID of the masternode identity.
+    pub pro_tx_hash: String,
+    pub identity_type: IdentityType,
+    pub owner_address: Address,
+    pub voting_address: Address,
+    pub payout_address: Address,
+    /// BLS public key of the operator, hex
+    pub operator_public_key: String,
+    /// Private keys of the masternode held by the Core wallet, as WIF
+    pub wallet_keys: MasternodeWalletKeys,
+}
+
+/// WIF private keys, zeroized on drop and left out of debug output.
+#[derive(Clone, Default, PartialEq)]
+pub struct MasternodeWalletKeys {
+    pub owner: Option<String>,
+    pub voting: Option<String>,
+    pub payout: Option<String>,
+}
+
+impl fmt::Debug for MasternodeWalletKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted = |key: &Option<String>| key.as_ref().map(|_| "<redacted>");
+        f.debug_struct("MasternodeWalletKeys")
+            .field("owner", &redacted(&self.owner))
+            .field("voting", &redacted(&self.voting))
+            .field("payout", &redacted(&self.payout))
+            .finish()
+    }
+}
+
+impl Drop for MasternodeWalletKeys {
+    fn drop(&mut self) {
+        self.owner.zeroize();
+        self.voting.zeroize();
+        self.payout.zeroize();
+    }
+}
+
+impl MasternodeCoreInfo {
+    /// Hash of the voting key, hex. Masternodes vote on Platform through an identity derived
+    /// from it.
+    pub fn voting_key_hash(&self) -> Option<String> {
+        p2pkh_hash(&self.voting_address).map(hex::encode)
+    }
+
+    /// Describes each of the given private keys that doesn't belong to the address registered
+    /// for it.
+    pub fn key_mismatches(
+        &self,
+        voting_private_key: Option<[u8; 32]>,
+        owner_private_key: Option<[u8; 32]>,
+        payout_private_key: Option<[u8; 32]>,
+        network: Network,
+    ) -> Vec<String> {
+        [
+            ("Voting", voting_private_key, &self.voting_address),
+            ("Owner", owner_private_key, &self.owner_address),
+            ("Payout address", payout_private_key, &self.payout_address),
+        ]
+        .into_iter()
+        .filter_map(|(name, private_key, registered_address)| {
+            let private_key = private_key?;
+            match key_address(&private_key, network) {
+                Some(address) if address == *registered_address => None,
+                Some(address) => Some(format!(
+                    "{} key is for {}, but the masternode has {}",
+                    name, address, registered_address
+                )),
+                None => Some(format!("{} key is not a valid private key", name)),
+            }
+        })
+        .collect()
+    }
+}
+
+/// The P2PKH address of a private key.
+pub fn key_address(private_key: &[u8; 32], network: Network) -> Option<Address> {
+    let private_key = PrivateKey::from_slice(private_key, network).ok()?;
+    Some(Address::p2pkh(
+        &private_key.public_key(&Secp256k1::new()),
+        network,
+    ))
+}
+
+/// The public key hash a P2PKH address pays to.
+fn p2pkh_hash(address: &Address) -> Option<[u8; 20]> {
+    let script = address.script_pubkey();
+    if !script.is_p2pkh() {
+        return None;
+    }
+    // OP_DUP OP_HASH160 OP_PUSHBYTES_20 <hash> OP_EQUALVERIFY OP_CHECKSIG
+    script.as_bytes()[3..23].try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_mismatches() {
+        let network = Network::Testnet;
+        let voting_key = [1; 32];
+        let owner_key = [2; 32];
+        let info = MasternodeCoreInfo {
+            pro_tx_hash: "00".repeat(32),
+            identity_type: IdentityType::Masternode,
+            owner_address: key_address(&owner_key, network).unwrap(),
+            voting_address: key_address(&voting_key, network).unwrap(),
+            payout_address: key_address(&[3; 32], network).unwrap(),
+            operator_public_key: String::new(),
+            wallet_keys: MasternodeWalletKeys::default(),
+        };
+
+        assert!(
+            info.key_mismatches(Some(voting_key), Some(owner_key), None, network)
+                .is_empty()
+        );
+        let mismatches = info.key_mismatches(Some(owner_key), None, None, network);
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].starts_with("Voting key is for"));
+        assert_eq!(info.voting_key_hash().map(|hash| hash.len()), Some(40));
+    }
+
+    #[test]
+    fn test_wallet_keys_debug_is_redacted() {
+        let keys = MasternodeWalletKeys {
+            owner: Some("cMgZD2qsGReP1UvGbNQ7moL6PZFgzsuPFV3St8sGwpNxED4hqkEM".to_string()),
+            voting: None,
+            payout: None,
+        };
+        let debug = format!("{:?}", keys);
+        assert!(!debug.contains("cMgZD2"));
+        assert!(debug.contains("<redacted>"));
+    }
+}
@@ -1,6 +1,10 @@
 pub mod contested_name;
+pub mod dpns_name;
+pub mod masternode_core_info;
 pub mod password_info;
+pub mod payment_uri;
 pub mod proof_log_item;
 pub mod qualified_contract;
 pub mod qualified_identity;
+pub mod signed_message;
 pub mod wallet;
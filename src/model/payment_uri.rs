@@ -0,0 +1,228 @@
+//! BIP21 style `dash:` payment requests, as shown in QR codes and payment links.
+
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::{Address, Network};
+use std::str::FromStr;
+
+const DASH_URI_SCHEME: &str = "dash";
+const DUFFS_PER_DASH: Duffs = 100_000_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentUri {
+    pub address: Address,
+    pub amount: Option<Duffs>,
+    /// Name of the recipient
+    pub label: Option<String>,
+    /// What the payment is for
+    pub message: Option<String>,
+    /// The recipient asks for an InstantSend payment (`IS=1`)
+    pub instant_send: bool,
+}
+
+impl PaymentUri {
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            amount: None,
+            label: None,
+            message: None,
+            instant_send: false,
+        }
+    }
+
+    pub fn to_uri(&self) -> String {
+        let mut parameters = vec![];
+        if let Some(amount) = self.amount {
+            parameters.push(format!("amount={}", format_dash_amount(amount)));
+        }
+        if let Some(label) = &self.label {
+            parameters.push(format!("label={}", percent_encode(label)));
+        }
+        if let Some(message) = &self.message {
+            parameters.push(format!("message={}", percent_encode(message)));
+        }
+        if self.instant_send {
+            parameters.push("IS=1".to_string());
+        }
+
+        if parameters.is_empty() {
+            format!("{}:{}", DASH_URI_SCHEME, self.address)
+        } else {
+            format!(
+                "{}:{}?{}",
+                DASH_URI_SCHEME,
+                self.address,
+                parameters.join("&")
+            )
+        }
+    }
+
+    /// Parses a payment request for an address of `network`.
+    pub fn parse(uri: &str, network: Network) -> Result<Self, String> {
+        let (scheme, rest) = uri
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| "Not a dash: payment request".to_string())?;
+        if !scheme.eq_ignore_ascii_case(DASH_URI_SCHEME) {
+            return Err(format!(
+                "Expected a dash: payment request, got a {}: link",
+                scheme
+            ));
+        }
+        let rest = rest.trim_start_matches("//");
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let address = Address::from_str(address)
+            .map_err(|e| format!("Invalid address: {}", e))?
+            .require_network(network)
+            .map_err(|_| format!("Address is not valid for network {}", network))?;
+        let mut payment_uri = Self::new(address);
+
+        for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+            let (key, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            let value = percent_decode(value)?;
+            match key {
+                "amount" => payment_uri.amount = Some(parse_dash_amount(&value)?),
+                "label" => payment_uri.label = Some(value),
+                "message" => payment_uri.message = Some(value),
+                "IS" => payment_uri.instant_send = value == "1",
+                // Unknown parameters must be understood when required, and are ignored otherwise
+                key if key.starts_with("req-") => {
+                    return Err(format!(
+                        "The payment request requires \"{}\", which is not supported",
+                        key.trim_start_matches("req-")
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(payment_uri)
+    }
+}
+
+/// Finds a payment request in free text, such as a dropped link file or a pasted message.
+pub fn find_payment_uri(text: &str) -> Option<&str> {
+    // ASCII lowercasing keeps byte offsets
+    let start = text
+        .to_ascii_lowercase()
+        .find(&format!("{}:", DASH_URI_SCHEME))?;
+    let uri = &text[start..];
+    let end = uri
+        .find(|c: char| c.is_whitespace() || c == '<' || c == '"')
+        .unwrap_or(uri.len());
+    Some(&uri[..end])
+}
+
+/// Formats duffs as a DASH amount without trailing zeros, e.g. `1.5`.
+pub fn format_dash_amount(amount: Duffs) -> String {
+    let whole = amount / DUFFS_PER_DASH;
+    let fraction = amount % DUFFS_PER_DASH;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{:08}", fraction);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// Parses a DASH amount with up to 8 decimals into duffs, without rounding.
+pub fn parse_dash_amount(amount: &str) -> Result<Duffs, String> {
+    let invalid = || format!("Invalid amount: {}", amount);
+    let (whole, fraction) = amount.trim().split_once('.').unwrap_or((amount.trim(), ""));
+    if (whole.is_empty() && fraction.is_empty())
+        || fraction.len() > 8
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let whole: Duffs = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| invalid())?
+    };
+    let fraction: Duffs = format!("{:0<8}", fraction).parse().map_err(|_| invalid())?;
+    whole
+        .checked_mul(DUFFS_PER_DASH)
+        .and_then(|duffs| duffs.checked_add(fraction))
+        .ok_or_else(invalid)
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn percent_decode(value: &str) -> Result<String, String> {
+    let invalid = || format!("Invalid percent encoding in \"{}\"", value);
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+    while let Some(byte) = input.next() {
+        if byte == b'%' {
+            let hex = [
+                input.next().ok_or_else(invalid)?,
+                input.next().ok_or_else(invalid)?,
+            ];
+            let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTNET_ADDRESS: &str = "yLKU4EJxjbv8peagVRM3UykZDJoaUUrXSn";
+
+    #[test]
+    fn test_payment_uri_round_trip() {
+        let address = Address::from_str(TESTNET_ADDRESS)
+            .unwrap()
+            .require_network(Network::Testnet)
+            .unwrap();
+        let payment_uri = PaymentUri {
+            address,
+            amount: Some(150_000_000),
+            label: Some("Evo Shop".to_string()),
+            message: Some("Order #42 & co".to_string()),
+            instant_send: true,
+        };
+
+        let uri = payment_uri.to_uri();
+        assert_eq!(
+            uri,
+            format!(
+                "dash:{}?amount=1.5&label=Evo%20Shop&message=Order%20%2342%20%26%20co&IS=1",
+                TESTNET_ADDRESS
+            )
+        );
+        assert_eq!(PaymentUri::parse(&uri, Network::Testnet), Ok(payment_uri));
+    }
+
+    #[test]
+    fn test_payment_uri_parse_errors() {
+        let uri = |query: &str| format!("dash:{}?{}", TESTNET_ADDRESS, query);
+        assert!(PaymentUri::parse(&uri("amount=0.000000001"), Network::Testnet).is_err());
+        assert!(PaymentUri::parse(&uri("req-expires=1"), Network::Testnet).is_err());
+        assert!(PaymentUri::parse(&uri(""), Network::Dash).is_err());
+        assert!(PaymentUri::parse(TESTNET_ADDRESS, Network::Testnet).is_err());
+
+        let parsed = PaymentUri::parse(&uri("amount=.25&foo=bar"), Network::Testnet).unwrap();
+        assert_eq!(parsed.amount, Some(25_000_000));
+        assert!(!parsed.instant_send);
+    }
+}
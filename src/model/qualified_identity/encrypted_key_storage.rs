@@ -1,5 +1,6 @@
 use crate::model::qualified_identity::PrivateKeyTarget;
 use crate::model::qualified_identity::qualified_identity_public_key::QualifiedIdentityPublicKey;
+use crate::model::wallet::encryption::{decrypt_private_key, encrypt_private_key};
 use crate::model::wallet::{Wallet, WalletSeedHash};
 use bincode::de::{BorrowDecoder, Decoder};
 use bincode::enc::Encoder;
@@ -7,11 +8,13 @@ use bincode::error::{DecodeError, EncodeError};
 use bincode::{BorrowDecode, Decode, Encode};
 use dash_sdk::dashcore_rpc::dashcore::bip32::DerivationPath;
 use dash_sdk::dpp::dashcore::bip32::ChildNumber;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
-use dash_sdk::dpp::identity::{KeyID, Purpose, SecurityLevel};
+use dash_sdk::dpp::identity::{Identity, KeyID, Purpose, SecurityLevel};
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::sync::{Arc, RwLock};
+use zeroize::Zeroize;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct WalletDerivationPath {
@@ -388,6 +391,53 @@ impl KeyStorage {
             .collect()
     }
 
+    /// Re-encrypts the keys stored as [`PrivateKeyData::Encrypted`] with `new_password`, or
+    /// stores them in clear when it is `None`. Keys that aren't encrypted are left as they are.
+    ///
+    /// Returns whether any key changed.
+    pub fn change_password(
+        &mut self,
+        current_password: Option<&str>,
+        new_password: Option<&str>,
+    ) -> Result<bool, String> {
+        let mut changed = false;
+        for (public_key, private_key_data) in self.private_keys.values_mut() {
+            let PrivateKeyData::Encrypted(encrypted) = private_key_data else {
+                continue;
+            };
+            let current_password = current_password
+                .ok_or_else(|| "Key is encrypted, please enter password".to_string())?;
+            let mut private_key = decrypt_private_key(encrypted, current_password)?;
+            *private_key_data = match new_password {
+                Some(new_password) => {
+                    PrivateKeyData::Encrypted(encrypt_private_key(&private_key, new_password)?)
+                }
+                None if public_key.identity_public_key.security_level()
+                    == SecurityLevel::MEDIUM =>
+                {
+                    PrivateKeyData::AlwaysClear(private_key)
+                }
+                None => PrivateKeyData::Clear(private_key),
+            };
+            private_key.zeroize();
+            changed = true;
+        }
+        Ok(changed)
+    }
+
+    /// Updates the stored public keys of the main identity from `identity`, e.g. after some
+    /// were disabled.
+    pub fn refresh_public_keys(&mut self, identity: &Identity) {
+        for ((target, key_id), (public_key, _)) in self.private_keys.iter_mut() {
+            if *target != PrivateKeyTarget::PrivateKeyOnMainIdentity {
+                continue;
+            }
+            if let Some(identity_public_key) = identity.get_public_key_by_id(*key_id) {
+                public_key.identity_public_key = identity_public_key.clone();
+            }
+        }
+    }
+
     /// Inserts an unencrypted key into `ClearKeyStorage`. Returns an error if the storage is closed.
     pub fn insert_non_encrypted(
         &mut self,
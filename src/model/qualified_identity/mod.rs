@@ -426,6 +426,34 @@ impl QualifiedIdentity {
         None
     }
 
+    /// Why the key `key_id` can't be disabled or rotated by an identity update, or `None` if
+    /// it can. Updates are signed with the master key, so that key can't be replaced by one.
+    pub fn key_update_blocker(&self, key_id: KeyID) -> Option<String> {
+        let Some(signing_key) = self.can_sign_with_master_key() else {
+            return Some(
+                "Key updates are signed with the master key, which isn't loaded".to_string(),
+            );
+        };
+        let Some(key) = self.identity.get_public_key_by_id(key_id) else {
+            return Some(format!("Key {} not found on identity", key_id));
+        };
+        if key.is_disabled() {
+            Some(format!("Key {} is already disabled", key_id))
+        } else if key.read_only() {
+            Some(format!(
+                "Key {} is read-only, Platform doesn't allow disabling or rotating it",
+                key_id
+            ))
+        } else if signing_key.identity_public_key.id() == key_id {
+            Some(format!(
+                "Key {} is the master key that signs key updates, so it can't be disabled or rotated",
+                key_id
+            ))
+        } else {
+            None
+        }
+    }
+
     pub fn document_signing_key(
         &self,
         document_type: &DocumentTypeRef,
@@ -628,3 +656,53 @@ impl From<Identity> for QualifiedIdentity {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::qualified_identity::encrypted_key_storage::PrivateKeyData;
+    use dash_sdk::dpp::identity::accessors::IdentitySettersV0;
+    use dash_sdk::dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
+    use dash_sdk::dpp::version::PlatformVersion;
+    use dash_sdk::platform::Identifier;
+
+    #[test]
+    fn test_key_update_blocker() {
+        let key = |id, security_level, read_only, disabled_at| {
+            IdentityPublicKey::from(IdentityPublicKeyV0 {
+                id,
+                key_type: KeyType::ECDSA_SECP256K1,
+                purpose: Purpose::AUTHENTICATION,
+                security_level,
+                data: BinaryData::new(vec![2; 33]),
+                read_only,
+                disabled_at,
+                contract_bounds: None,
+            })
+        };
+        let master_key = key(0, SecurityLevel::MASTER, false, None);
+        let mut identity =
+            Identity::create_basic_identity(Identifier::new([1; 32]), PlatformVersion::latest())
+                .expect("identity");
+        for public_key in [
+            master_key.clone(),
+            key(1, SecurityLevel::HIGH, false, None),
+            key(2, SecurityLevel::HIGH, true, None),
+            key(3, SecurityLevel::HIGH, false, Some(1)),
+        ] {
+            identity.add_public_key(public_key);
+        }
+        let mut qualified_identity = QualifiedIdentity::from(identity);
+        assert!(qualified_identity.key_update_blocker(1).is_some());
+
+        qualified_identity.private_keys.private_keys.insert(
+            (PrivateKeyTarget::PrivateKeyOnMainIdentity, 0),
+            (master_key.into(), PrivateKeyData::Clear([7; 32])),
+        );
+        assert!(qualified_identity.key_update_blocker(0).is_some());
+        assert_eq!(qualified_identity.key_update_blocker(1), None);
+        assert!(qualified_identity.key_update_blocker(2).is_some());
+        assert!(qualified_identity.key_update_blocker(3).is_some());
+        assert!(qualified_identity.key_update_blocker(4).is_some());
+    }
+}
@@ -0,0 +1,130 @@
+//! Dash signed messages, as made by Dash Core's `signmessage` and checked by
+//! `verifymessage`: a compact recoverable signature of the prefixed message hash, base64
+//! encoded, from which the signing address can be recovered.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use dash_sdk::dpp::dashcore::consensus::encode::{VarInt, serialize};
+use dash_sdk::dpp::dashcore::hashes::{Hash, sha256d};
+use dash_sdk::dpp::dashcore::secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+use dash_sdk::dpp::dashcore::secp256k1::{Message, Secp256k1};
+use dash_sdk::dpp::dashcore::{Address, PrivateKey, PublicKey};
+
+/// Prefix Dash Core hashes signed messages with, kept from its days as DarkCoin
+const SIGNED_MESSAGE_MAGIC: &[u8] = b"\x19DarkCoin Signed Message:\n";
+
+/// Header byte of a signature made with recovery id 0 for an uncompressed public key
+const HEADER_BASE: u8 = 27;
+/// Added to the header byte when the public key is compressed
+const HEADER_COMPRESSED: u8 = 4;
+
+fn message_digest(message: &str) -> Message {
+    let data = [
+        SIGNED_MESSAGE_MAGIC,
+        &serialize(&VarInt(message.len() as u64)),
+        message.as_bytes(),
+    ]
+    .concat();
+    Message::from_digest(sha256d::Hash::hash(&data).to_byte_array())
+}
+
+/// Signs `message` with `private_key`, returning the base64 encoded signature.
+pub fn sign_message(message: &str, private_key: &PrivateKey) -> String {
+    let secp = Secp256k1::new();
+    let signature = secp.sign_ecdsa_recoverable(&message_digest(message), &private_key.inner);
+    let (recovery_id, compact) = signature.serialize_compact();
+
+    let mut header = HEADER_BASE + recovery_id.to_i32() as u8;
+    if private_key.compressed {
+        header += HEADER_COMPRESSED;
+    }
+    let mut serialized = Vec::with_capacity(65);
+    serialized.push(header);
+    serialized.extend_from_slice(&compact);
+    STANDARD.encode(serialized)
+}
+
+/// Decodes a base64 encoded signature into the recoverable signature and whether the
+/// signing key is compressed.
+fn decode_signature(signature: &str) -> Result<(RecoverableSignature, bool), String> {
+    let bytes = STANDARD
+        .decode(signature.trim())
+        .map_err(|e| format!("Signature is not valid base64: {}", e))?;
+    let (header, compact) = bytes
+        .split_first()
+        .filter(|(_, compact)| compact.len() == 64)
+        .ok_or("Signature must be 65 bytes long".to_string())?;
+    let header = header
+        .checked_sub(HEADER_BASE)
+        .filter(|header| *header < 2 * HEADER_COMPRESSED)
+        .ok_or(format!("Invalid signature header byte {}", header))?;
+
+    let recovery_id =
+        RecoveryId::from_i32((header % HEADER_COMPRESSED) as i32).map_err(|e| e.to_string())?;
+    let signature =
+        RecoverableSignature::from_compact(compact, recovery_id).map_err(|e| e.to_string())?;
+    Ok((signature, header >= HEADER_COMPRESSED))
+}
+
+/// Whether `signature` is a signature of `message` by the key of `address`. Malformed
+/// signatures are errors, while signatures by another key or of another message are
+/// `Ok(false)`.
+pub fn verify_message(address: &Address, message: &str, signature: &str) -> Result<bool, String> {
+    let (signature, compressed) = decode_signature(signature)?;
+    let Ok(public_key) = Secp256k1::new().recover_ecdsa(&message_digest(message), &signature)
+    else {
+        return Ok(false);
+    };
+    let signer = PublicKey {
+        compressed,
+        inner: public_key,
+    };
+    Ok(Address::p2pkh(&signer, *address.network()) == *address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dash_sdk::dpp::dashcore::Network;
+    use dash_sdk::dpp::dashcore::secp256k1::SecretKey;
+
+    #[test]
+    fn test_sign_and_verify_message() {
+        let secp = Secp256k1::new();
+        let private_key =
+            PrivateKey::new(SecretKey::from_slice(&[3; 32]).unwrap(), Network::Testnet);
+        let address = Address::p2pkh(&private_key.public_key(&secp), Network::Testnet);
+        let other_key = PrivateKey::new(SecretKey::from_slice(&[4; 32]).unwrap(), Network::Testnet);
+        let other_address = Address::p2pkh(&other_key.public_key(&secp), Network::Testnet);
+
+        let signature = sign_message("I own this collateral", &private_key);
+        assert_eq!(
+            verify_message(&address, "I own this collateral", &signature),
+            Ok(true)
+        );
+        assert_eq!(
+            verify_message(&address, "I own this payout address", &signature),
+            Ok(false)
+        );
+        assert_eq!(
+            verify_message(&other_address, "I own this collateral", &signature),
+            Ok(false)
+        );
+        assert!(verify_message(&address, "I own this collateral", "not base64!").is_err());
+    }
+
+    #[test]
+    fn test_dash_core_signmessage_vector() {
+        // What `signmessage` of Dash Core returns for this key and message: an RFC 6979
+        // nonce, a low S value and the header byte of a compressed key
+        let private_key =
+            PrivateKey::from_wif("cMgZD2qsGReP1UvGbNQ7moL6PZFgzsuPFV3St8sGwpNxED4hqkEM").unwrap();
+        let address = Address::p2pkh(&private_key.public_key(&Secp256k1::new()), Network::Testnet);
+        assert_eq!(address.to_string(), "ySHisRjMmo2kKMXkUuojqSdftoXciGdrDV");
+
+        let message = "Dash Evo Tool signmessage test vector";
+        let signature = "H+CsrUOavylVAonZvCSGw1yGAXPrkO5U9rlwcqXqpikpY7yn6zXeefUpQvYUMy4O7mg0lvRmgbLRwbax3KWjyOw=";
+        assert_eq!(sign_message(message, &private_key), signature);
+        assert_eq!(verify_message(&address, message, signature), Ok(true));
+    }
+}
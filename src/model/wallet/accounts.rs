@@ -0,0 +1,178 @@
+use super::Wallet;
+use crate::context::AppContext;
+use crate::model::wallet::coin_selection::SpendableUtxo;
+use dash_sdk::dashcore_rpc::dashcore::bip32::{ChildNumber, ExtendedPubKey};
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::bip32::DerivationPath;
+use dash_sdk::dpp::dashcore::{Address, Network};
+use std::collections::BTreeMap;
+
+/// A BIP44 account of a wallet, `m/44'/coin_type'/index'`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletAccount {
+    pub index: u32,
+    pub name: Option<String>,
+    pub extended_public_key: ExtendedPubKey,
+    /// Hidden accounts keep their funds but are left out of account choosers.
+    pub hidden: bool,
+}
+
+impl WalletAccount {
+    pub fn new(index: u32, extended_public_key: ExtendedPubKey) -> Self {
+        WalletAccount {
+            index,
+            name: None,
+            extended_public_key,
+            hidden: false,
+        }
+    }
+
+    /// The accounts of a freshly created or imported wallet, only account 0.
+    pub fn default_accounts(
+        master_bip44_ecdsa_extended_public_key: ExtendedPubKey,
+    ) -> BTreeMap<u32, WalletAccount> {
+        BTreeMap::from([(
+            0,
+            WalletAccount::new(0, master_bip44_ecdsa_extended_public_key),
+        )])
+    }
+
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("Account {}", self.index))
+    }
+}
+
+/// The BIP44 account an address was derived for, `None` for addresses outside of BIP44.
+pub fn bip44_account_of_path(derivation_path: &DerivationPath) -> Option<u32> {
+    let components = derivation_path.as_ref();
+    if components.len() != 5 || components[0] != (ChildNumber::Hardened { index: 44 }) {
+        return None;
+    }
+    match components[2] {
+        ChildNumber::Hardened { index } => Some(index),
+        _ => None,
+    }
+}
+
+impl Wallet {
+    pub fn account_extended_public_key(&self, account: u32) -> Result<&ExtendedPubKey, String> {
+        self.accounts
+            .get(&account)
+            .map(|wallet_account| &wallet_account.extended_public_key)
+            .ok_or_else(|| format!("Account {} does not exist in this wallet", account))
+    }
+
+    /// Accounts that are not hidden, always including account 0.
+    pub fn visible_accounts(&self) -> impl Iterator<Item = &WalletAccount> {
+        self.accounts
+            .values()
+            .filter(|account| !account.hidden || account.index == 0)
+    }
+
+    /// The account funds on `address` belong to.
+    ///
+    /// Addresses outside of BIP44, such as identity funding addresses, count towards account 0.
+    pub fn account_of_address(&self, address: &Address) -> u32 {
+        self.known_addresses
+            .get(address)
+            .and_then(bip44_account_of_path)
+            .unwrap_or(0)
+    }
+
+    /// Total of the outputs of `account` that can be spent right now.
+    pub fn account_balance(&self, account: u32) -> u64 {
+        self.balance_of_account(account).spendable()
+    }
+
+    pub fn account_utxos(&self, account: u32) -> Vec<SpendableUtxo> {
+        self.spendable_utxos()
+            .into_iter()
+            .filter(|utxo| self.account_of_address(&utxo.address) == account)
+            .collect()
+    }
+
+    /// Derive the next BIP44 account from the seed and store it.
+    ///
+    /// Accounts are hardened, so the wallet has to be open.
+    pub fn add_account(
+        &mut self,
+        network: Network,
+        name: Option<String>,
+        app_context: &AppContext,
+    ) -> Result<u32, String> {
+        let index = self
+            .accounts
+            .keys()
+            .next_back()
+            .map_or(0, |index| index + 1);
+        let coin_type = match network {
+            Network::Dash => 5,
+            _ => 1,
+        };
+        let account_path = DerivationPath::from(
+            [
+                ChildNumber::Hardened { index: 44 },
+                ChildNumber::Hardened { index: coin_type },
+                ChildNumber::Hardened { index },
+            ]
+            .as_slice(),
+        );
+        let extended_private_key = account_path
+            .derive_priv_ecdsa_for_master_seed(self.seed_bytes()?, network)
+            .map_err(|e| e.to_string())?;
+        let secp = Secp256k1::new();
+        let account = WalletAccount {
+            name,
+            ..WalletAccount::new(
+                index,
+                ExtendedPubKey::from_priv(&secp, &extended_private_key),
+            )
+        };
+
+        app_context
+            .db
+            .store_wallet_account(&self.seed_hash(), &account)
+            .map_err(|e| e.to_string())?;
+        self.accounts.insert(index, account);
+        Ok(index)
+    }
+
+    pub fn rename_account(
+        &mut self,
+        account: u32,
+        name: Option<String>,
+        app_context: &AppContext,
+    ) -> Result<(), String> {
+        let wallet_account = self
+            .accounts
+            .get_mut(&account)
+            .ok_or_else(|| format!("Account {} does not exist in this wallet", account))?;
+        wallet_account.name = name;
+        app_context
+            .db
+            .store_wallet_account(&self.seed_hash(), &self.accounts[&account])
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn set_account_hidden(
+        &mut self,
+        account: u32,
+        hidden: bool,
+        app_context: &AppContext,
+    ) -> Result<(), String> {
+        if account == 0 && hidden {
+            return Err("The default account can not be hidden".to_string());
+        }
+        let wallet_account = self
+            .accounts
+            .get_mut(&account)
+            .ok_or_else(|| format!("Account {} does not exist in this wallet", account))?;
+        wallet_account.hidden = hidden;
+        app_context
+            .db
+            .store_wallet_account(&self.seed_hash(), &self.accounts[&account])
+            .map_err(|e| e.to_string())
+    }
+}
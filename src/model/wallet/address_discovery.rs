@@ -0,0 +1,78 @@
+use super::{DerivationPathReference, DerivationPathType, Wallet};
+use crate::context::AppContext;
+use dash_sdk::dashcore_rpc::dashcore::bip32::ChildNumber;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::bip32::DerivationPath;
+use dash_sdk::dpp::dashcore::{Address, Network};
+
+/// Number of consecutive unused addresses after which a BIP44 chain is considered exhausted,
+/// as recommended by BIP44.
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// How far an address discovery has come, reported to the UI after each batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AddressDiscoveryProgress {
+    pub checked_addresses: u32,
+    pub used_addresses: u32,
+}
+
+impl Wallet {
+    /// The BIP44 address at `index` of the external or change chain of `account`.
+    pub fn bip44_address_at(
+        &self,
+        network: Network,
+        account: u32,
+        change: bool,
+        index: u32,
+    ) -> Result<(Address, DerivationPath), String> {
+        let derivation_path_extension = DerivationPath::from(
+            [
+                ChildNumber::Normal {
+                    index: change.into(),
+                },
+                ChildNumber::Normal { index },
+            ]
+            .as_slice(),
+        );
+        let secp = Secp256k1::new();
+        let public_key = self
+            .account_extended_public_key(account)?
+            .derive_pub(&secp, &derivation_path_extension)
+            .map_err(|e| e.to_string())?
+            .to_pub();
+        Ok((
+            Address::p2pkh(&public_key, network),
+            DerivationPath::bip_44_payment_path(network, account, change, index),
+        ))
+    }
+
+    /// Registers a BIP44 address with the wallet and imports it into Core without a rescan.
+    ///
+    /// Returns false if the address was already known.
+    pub fn register_bip44_address(
+        &mut self,
+        address: &Address,
+        derivation_path: &DerivationPath,
+        app_context: &AppContext,
+    ) -> Result<bool, String> {
+        if self.known_addresses.contains_key(address) {
+            return Ok(false);
+        }
+
+        let label = format!(
+            "Managed by Dash Evo Tool {} {}",
+            self.alias.clone().unwrap_or_default(),
+            derivation_path
+        );
+        app_context.with_chain_data(|chain_data| chain_data.watch_address(address, &label))?;
+
+        self.register_address(
+            address.clone(),
+            derivation_path,
+            DerivationPathType::CLEAR_FUNDS,
+            DerivationPathReference::BIP44,
+            app_context,
+        )?;
+        Ok(true)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
+use crate::model::wallet::fee_estimation::{FeeEstimator, FeeRate};
 use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
 use dash_sdk::dpp::dashcore::psbt::serialize::Serialize;
 use dash_sdk::dpp::dashcore::secp256k1::Message;
@@ -7,18 +9,22 @@ use dash_sdk::dpp::dashcore::sighash::SighashCache;
 use dash_sdk::dpp::dashcore::transaction::special_transaction::TransactionPayload;
 use dash_sdk::dpp::dashcore::transaction::special_transaction::asset_lock::AssetLockPayload;
 use dash_sdk::dpp::dashcore::{
-    Address, Network, OutPoint, PrivateKey, ScriptBuf, Transaction, TxIn, TxOut,
+    Address, Network, OutPoint, PrivateKey, PubkeyHash, ScriptBuf, Transaction, TxIn, TxOut,
 };
 use std::collections::BTreeMap;
 
 impl Wallet {
     #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments)]
     pub fn registration_asset_lock_transaction(
         &mut self,
         network: Network,
         amount: u64,
         allow_take_fee_from_amount: bool,
         identity_index: u32,
+        account: u32,
+        coin_selection: CoinSelectionStrategy,
+        fee_rate: FeeRate,
         register_addresses: Option<&AppContext>,
     ) -> Result<
         (
@@ -39,11 +45,15 @@ impl Wallet {
             amount,
             allow_take_fee_from_amount,
             private_key,
+            account,
+            coin_selection,
+            fee_rate,
             register_addresses,
         )
     }
 
     #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments)]
     pub fn top_up_asset_lock_transaction(
         &mut self,
         network: Network,
@@ -51,6 +61,9 @@ impl Wallet {
         allow_take_fee_from_amount: bool,
         identity_index: u32,
         top_up_index: u32,
+        account: u32,
+        coin_selection: CoinSelectionStrategy,
+        fee_rate: FeeRate,
         register_addresses: Option<&AppContext>,
     ) -> Result<
         (
@@ -72,17 +85,24 @@ impl Wallet {
             amount,
             allow_take_fee_from_amount,
             private_key,
+            account,
+            coin_selection,
+            fee_rate,
             register_addresses,
         )
     }
 
     #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments)]
     fn asset_lock_transaction_from_private_key(
         &mut self,
         network: Network,
         amount: u64,
         allow_take_fee_from_amount: bool,
         private_key: PrivateKey,
+        account: u32,
+        coin_selection: CoinSelectionStrategy,
+        fee_rate: FeeRate,
         register_addresses: Option<&AppContext>,
     ) -> Result<
         (
@@ -96,11 +116,55 @@ impl Wallet {
         let secp = Secp256k1::new();
         let asset_lock_public_key = private_key.public_key(&secp);
 
-        let one_time_key_hash = asset_lock_public_key.pubkey_hash();
-        let fee = 3_000;
+        let (mut tx, change_address, utxos) = self.unsigned_asset_lock_transaction(
+            network,
+            amount,
+            allow_take_fee_from_amount,
+            asset_lock_public_key.pubkey_hash(),
+            account,
+            coin_selection,
+            fee_rate,
+            register_addresses,
+        )?;
+
+        self.sign_p2pkh_inputs(&mut tx, &utxos, network)?;
+
+        Ok((tx, private_key, change_address, utxos))
+    }
 
-        let (utxos, change_option) = self
-            .take_unspent_utxos_for(amount, fee, allow_take_fee_from_amount)
+    /// Build an asset lock crediting `one_time_key_hash` without signing it.
+    ///
+    /// Inputs are taken from `account` like for [`Wallet::unsigned_payment_transaction`].
+    #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn unsigned_asset_lock_transaction(
+        &mut self,
+        network: Network,
+        amount: u64,
+        allow_take_fee_from_amount: bool,
+        one_time_key_hash: PubkeyHash,
+        account: u32,
+        coin_selection: CoinSelectionStrategy,
+        fee_rate: FeeRate,
+        register_addresses: Option<&AppContext>,
+    ) -> Result<
+        (
+            Transaction,
+            Option<Address>,
+            BTreeMap<OutPoint, (TxOut, Address)>,
+        ),
+        String,
+    > {
+        let fee_estimator = FeeEstimator::for_asset_lock(fee_rate);
+
+        let (utxos, change_option, fee) = self
+            .take_unspent_utxos_for(
+                amount,
+                &fee_estimator,
+                allow_take_fee_from_amount,
+                account,
+                coin_selection,
+            )
             .ok_or("take_unspent_utxos_for() returned None".to_string())?;
 
         let actual_amount = if change_option.is_none() && allow_take_fee_from_amount {
@@ -122,7 +186,7 @@ impl Wallet {
         };
 
         let (change_output, change_address) = if let Some(change) = change_option {
-            let change_address = self.change_address(network, register_addresses)?;
+            let change_address = self.change_address(network, account, register_addresses)?;
             (
                 Some(TxOut {
                     value: change,
@@ -148,7 +212,7 @@ impl Wallet {
             })
             .collect();
 
-        let mut tx = Transaction {
+        let tx = Transaction {
             version: 3,
             lock_time: 0,
             input: inputs,
@@ -162,76 +226,10 @@ impl Wallet {
             special_transaction_payload: Some(TransactionPayload::AssetLockPayloadType(payload)),
         };
 
-        let sighash_u32 = 1u32;
-
-        let cache = SighashCache::new(&tx);
-
-        // Next, collect the sighashes for each input since that's what we need from the
-        // cache
-        let sighashes: Vec<_> = tx
-            .input
-            .iter()
-            .enumerate()
-            .map(|(i, input)| {
-                let script_pubkey = utxos
-                    .get(&input.previous_output)
-                    .expect("expected a txout")
-                    .0
-                    .script_pubkey
-                    .clone();
-                cache
-                    .legacy_signature_hash(i, &script_pubkey, sighash_u32)
-                    .expect("expected sighash")
-            })
-            .collect();
-
-        // Now we can drop the cache to end the immutable borrow
-        #[allow(clippy::drop_non_drop)]
-        drop(cache);
-
-        let mut check_utxos = utxos.clone();
-
-        tx.input
-            .iter_mut()
-            .zip(sighashes.into_iter())
-            .try_for_each(|(input, sighash)| {
-                // You need to provide the actual script_pubkey of the UTXO being spent
-                let (_, input_address) = check_utxos
-                    .remove(&input.previous_output)
-                    .expect("expected a txout");
-                let message = Message::from_digest(sighash.into());
-
-                let private_key = self
-                    .private_key_for_address(&input_address, network)?
-                    .ok_or(format!(
-                        "Expected address {} to be in wallet",
-                        input_address
-                    ))?;
-
-                // Sign the message with the private key
-                let sig = secp.sign_ecdsa(&message, &private_key.inner);
-
-                // Serialize the DER-encoded signature and append the sighash type
-                let mut serialized_sig = sig.serialize_der().to_vec();
-
-                let mut sig_script = vec![serialized_sig.len() as u8 + 1];
-
-                sig_script.append(&mut serialized_sig);
-
-                sig_script.push(1);
-
-                let mut serialized_pub_key = private_key.public_key(&secp).serialize();
-
-                sig_script.push(serialized_pub_key.len() as u8);
-                sig_script.append(&mut serialized_pub_key);
-                // Create script_sig
-                input.script_sig = ScriptBuf::from_bytes(sig_script);
-                Ok::<(), String>(())
-            })?;
-
-        Ok((tx, private_key, change_address, utxos))
+        Ok((tx, change_address, utxos))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn registration_asset_lock_transaction_for_utxo(
         &mut self,
         network: Network,
@@ -239,6 +237,7 @@ impl Wallet {
         previous_tx_output: TxOut,
         input_address: Address,
         identity_index: u32,
+        fee_rate: FeeRate,
         register_addresses: Option<&AppContext>,
     ) -> Result<(Transaction, PrivateKey), String> {
         let private_key = self.identity_registration_ecdsa_private_key(
@@ -252,6 +251,7 @@ impl Wallet {
             previous_tx_output,
             input_address,
             private_key,
+            fee_rate,
         )
     }
 
@@ -264,6 +264,7 @@ impl Wallet {
         input_address: Address,
         identity_index: u32,
         top_up_index: u32,
+        fee_rate: FeeRate,
         register_addresses: Option<&AppContext>,
     ) -> Result<(Transaction, PrivateKey), String> {
         let private_key = self.identity_top_up_ecdsa_private_key(
@@ -278,6 +279,7 @@ impl Wallet {
             previous_tx_output,
             input_address,
             private_key,
+            fee_rate,
         )
     }
 
@@ -288,13 +290,21 @@ impl Wallet {
         previous_tx_output: TxOut,
         input_address: Address,
         private_key: PrivateKey,
+        fee_rate: FeeRate,
     ) -> Result<(Transaction, PrivateKey), String> {
         let secp = Secp256k1::new();
         let asset_lock_public_key = private_key.public_key(&secp);
 
         let one_time_key_hash = asset_lock_public_key.pubkey_hash();
-        let fee = 3_000;
-        let output_amount = previous_tx_output.value - fee;
+        let fee = FeeEstimator::for_asset_lock(fee_rate).fee(1, false);
+        let output_amount = previous_tx_output
+            .value
+            .checked_sub(fee)
+            .filter(|amount| *amount > 0)
+            .ok_or(format!(
+                "Output of {} duffs is too small to pay the fee of {} duffs",
+                previous_tx_output.value, fee
+            ))?;
 
         let payload_output = TxOut {
             value: output_amount,
@@ -0,0 +1,162 @@
+//! Wallet balances derived from unspent outputs, split by how settled the funds are.
+
+use crate::model::wallet::Wallet;
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::{Address, OutPoint};
+use std::collections::BTreeMap;
+
+/// Confirmations a coinbase output needs before it can be spent
+pub const COINBASE_MATURITY: u32 = 100;
+
+/// How settled an unspent output is, as last reported by the chain data backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UtxoStatus {
+    pub confirmations: u32,
+    /// Locked by InstantSend, so it can't be double spent even before it is mined
+    pub instant_locked: bool,
+    pub coinbase: bool,
+}
+
+impl UtxoStatus {
+    /// Coinbase outputs that can't be spent yet.
+    pub fn is_immature(&self) -> bool {
+        self.coinbase && self.confirmations < COINBASE_MATURITY
+    }
+
+    /// Combines two reports about the same output, keeping whatever is most settled.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            confirmations: self.confirmations.max(other.confirmations),
+            instant_locked: self.instant_locked || other.instant_locked,
+            coinbase: self.coinbase || other.coinbase,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WalletBalance {
+    /// Mined at least once
+    pub confirmed: Duffs,
+    /// Not mined yet, but locked by InstantSend
+    pub instant_locked: Duffs,
+    /// Neither mined nor InstantSend locked
+    pub unconfirmed: Duffs,
+    /// Coinbase outputs waiting for [`COINBASE_MATURITY`]
+    pub immature: Duffs,
+}
+
+impl WalletBalance {
+    /// Adds an output. Outputs without a known status were loaded from the database and
+    /// count as confirmed until the next refresh.
+    fn add(&mut self, value: Duffs, status: Option<&UtxoStatus>) {
+        match status {
+            Some(status) if status.is_immature() => self.immature += value,
+            Some(status) if status.confirmations > 0 => self.confirmed += value,
+            Some(status) if status.instant_locked => self.instant_locked += value,
+            Some(_) => self.unconfirmed += value,
+            None => self.confirmed += value,
+        }
+    }
+
+    pub fn total(&self) -> Duffs {
+        self.confirmed + self.instant_locked + self.unconfirmed + self.immature
+    }
+
+    /// Everything but immature coinbase outputs.
+    pub fn spendable(&self) -> Duffs {
+        self.confirmed + self.instant_locked + self.unconfirmed
+    }
+
+    /// Funds that are not settled or not spendable yet.
+    pub fn pending(&self) -> Duffs {
+        self.unconfirmed + self.immature
+    }
+}
+
+impl Wallet {
+    /// Whether the unspent output `outpoint` can be spent now, i.e. it is mature and not
+    /// reserved by a pending transaction.
+    pub fn is_utxo_spendable(&self, outpoint: &OutPoint) -> bool {
+        !self
+            .utxo_statuses
+            .get(outpoint)
+            .is_some_and(UtxoStatus::is_immature)
+            && self.utxo_reserved_for(outpoint).is_none()
+    }
+
+    /// Balance of the unspent outputs of the addresses `include` accepts.
+    fn balance_where(&self, include: impl Fn(&Address) -> bool) -> WalletBalance {
+        let mut balance = WalletBalance::default();
+        for (address, outpoints) in &self.utxos {
+            if !include(address) {
+                continue;
+            }
+            for (outpoint, tx_out) in outpoints {
+                balance.add(tx_out.value, self.utxo_statuses.get(outpoint));
+            }
+        }
+        balance
+    }
+
+    pub fn balance(&self) -> WalletBalance {
+        self.balance_where(|_| true)
+    }
+
+    pub fn balance_of_account(&self, account: u32) -> WalletBalance {
+        self.balance_where(|address| self.account_of_address(address) == account)
+    }
+
+    pub fn address_balance(&self, address: &Address) -> WalletBalance {
+        self.balance_where(|candidate| candidate == address)
+    }
+
+    /// Balances of every address holding unspent outputs.
+    pub fn address_balances(&self) -> BTreeMap<Address, WalletBalance> {
+        self.utxos
+            .keys()
+            .map(|address| (address.clone(), self.address_balance(address)))
+            .collect()
+    }
+
+    /// Whether `address` ever received funds, so it shouldn't be handed out again.
+    pub fn is_address_used(&self, address: &Address) -> bool {
+        self.utxos.contains_key(address)
+            || self
+                .address_total_received
+                .get(address)
+                .is_some_and(|received| *received > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_categories() {
+        let mut balance = WalletBalance::default();
+        let status = |confirmations, instant_locked, coinbase| UtxoStatus {
+            confirmations,
+            instant_locked,
+            coinbase,
+        };
+        balance.add(1, Some(&status(3, true, false)));
+        balance.add(2, Some(&status(0, true, false)));
+        balance.add(4, Some(&status(0, false, false)));
+        balance.add(8, Some(&status(99, false, true)));
+        balance.add(16, Some(&status(100, false, true)));
+        balance.add(32, None);
+
+        assert_eq!(
+            balance,
+            WalletBalance {
+                confirmed: 49,
+                instant_locked: 2,
+                unconfirmed: 4,
+                immature: 8,
+            }
+        );
+        assert_eq!(balance.total(), 63);
+        assert_eq!(balance.spendable(), 55);
+    }
+}
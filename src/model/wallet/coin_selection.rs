@@ -0,0 +1,385 @@
+use dash_sdk::dpp::dashcore::{Address, OutPoint, TxOut};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Change below this many duffs costs more to create and later spend than it is worth,
+/// so it is left to the fee instead of creating a change output.
+pub const MIN_CHANGE_VALUE: u64 = 1_000;
+
+/// Upper bound on the number of branches explored by [`BranchAndBound`].
+const BRANCH_AND_BOUND_MAX_TRIES: usize = 100_000;
+
+/// A wallet output that can be used as an input of a new transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpendableUtxo {
+    pub outpoint: OutPoint,
+    pub tx_out: TxOut,
+    pub address: Address,
+}
+
+impl SpendableUtxo {
+    pub fn value(&self) -> u64 {
+        self.tx_out.value
+    }
+}
+
+/// Chooses which UTXOs fund a spend of `target` duffs (amount plus fee).
+///
+/// Implementations must be deterministic: the same candidates and target always produce
+/// the same selection, independent of the order the candidates are given in.
+pub trait CoinSelector {
+    /// Returns the selected UTXOs, or `None` if the candidates cannot cover `target`.
+    fn select(&self, candidates: &[SpendableUtxo], target: u64) -> Option<Vec<SpendableUtxo>>;
+}
+
+/// Strategy used to pick the inputs of a wallet transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CoinSelectionStrategy {
+    /// Look for a combination that needs no change output, otherwise use largest first.
+    #[default]
+    BranchAndBound,
+    /// Spend the biggest UTXOs first, keeping the number of inputs low.
+    LargestFirst,
+    /// Spend the smallest UTXOs first, consolidating dust.
+    SmallestFirst,
+    /// Always spend every UTXO of an address together, so no address is left half spent.
+    AvoidAddressReuse,
+}
+
+impl CoinSelectionStrategy {
+    pub const ALL: [CoinSelectionStrategy; 4] = [
+        CoinSelectionStrategy::BranchAndBound,
+        CoinSelectionStrategy::LargestFirst,
+        CoinSelectionStrategy::SmallestFirst,
+        CoinSelectionStrategy::AvoidAddressReuse,
+    ];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            CoinSelectionStrategy::BranchAndBound => {
+                "Tries to find inputs that match the amount exactly so no change output is needed."
+            }
+            CoinSelectionStrategy::LargestFirst => {
+                "Uses the largest outputs first. Results in few inputs and a low fee."
+            }
+            CoinSelectionStrategy::SmallestFirst => {
+                "Uses the smallest outputs first. Cleans up small outputs at the cost of a higher fee."
+            }
+            CoinSelectionStrategy::AvoidAddressReuse => {
+                "Spends all outputs of an address together, so funds are never left on an address that has already revealed its public key."
+            }
+        }
+    }
+
+    pub fn selector(&self) -> &'static dyn CoinSelector {
+        match self {
+            CoinSelectionStrategy::BranchAndBound => &BranchAndBound,
+            CoinSelectionStrategy::LargestFirst => &LargestFirst,
+            CoinSelectionStrategy::SmallestFirst => &SmallestFirst,
+            CoinSelectionStrategy::AvoidAddressReuse => &AvoidAddressReuse,
+        }
+    }
+}
+
+impl fmt::Display for CoinSelectionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CoinSelectionStrategy::BranchAndBound => "Exact match (branch and bound)",
+            CoinSelectionStrategy::LargestFirst => "Largest first",
+            CoinSelectionStrategy::SmallestFirst => "Smallest first",
+            CoinSelectionStrategy::AvoidAddressReuse => "Avoid address reuse",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Sorts candidates by value, biggest first, breaking ties by outpoint.
+fn sorted_largest_first(candidates: &[SpendableUtxo]) -> Vec<&SpendableUtxo> {
+    let mut sorted: Vec<_> = candidates.iter().collect();
+    sorted.sort_by(|a, b| {
+        b.value()
+            .cmp(&a.value())
+            .then_with(|| a.outpoint.cmp(&b.outpoint))
+    });
+    sorted
+}
+
+/// Takes UTXOs in the given order until `target` is covered.
+fn accumulate<'a>(
+    ordered: impl IntoIterator<Item = &'a SpendableUtxo>,
+    target: u64,
+) -> Option<Vec<SpendableUtxo>> {
+    let mut selected = Vec::new();
+    let mut total = 0u64;
+    for utxo in ordered {
+        if total >= target {
+            break;
+        }
+        total += utxo.value();
+        selected.push(utxo.clone());
+    }
+    (total >= target).then_some(selected)
+}
+
+pub struct LargestFirst;
+
+impl CoinSelector for LargestFirst {
+    fn select(&self, candidates: &[SpendableUtxo], target: u64) -> Option<Vec<SpendableUtxo>> {
+        accumulate(sorted_largest_first(candidates), target)
+    }
+}
+
+pub struct SmallestFirst;
+
+impl CoinSelector for SmallestFirst {
+    fn select(&self, candidates: &[SpendableUtxo], target: u64) -> Option<Vec<SpendableUtxo>> {
+        let mut sorted: Vec<_> = candidates.iter().collect();
+        sorted.sort_by(|a, b| {
+            a.value()
+                .cmp(&b.value())
+                .then_with(|| a.outpoint.cmp(&b.outpoint))
+        });
+        accumulate(sorted, target)
+    }
+}
+
+/// Depth-first search for a selection whose excess over the target is smaller than
+/// [`MIN_CHANGE_VALUE`], so the transaction needs no change output. Falls back to
+/// [`LargestFirst`] when no such selection is found.
+pub struct BranchAndBound;
+
+impl BranchAndBound {
+    #[allow(clippy::too_many_arguments)]
+    fn search(
+        values: &[u64],
+        remaining: &[u64],
+        target: u64,
+        index: usize,
+        current: u64,
+        selected: &mut Vec<usize>,
+        tries: &mut usize,
+        best: &mut Option<(u64, Vec<usize>)>,
+    ) {
+        if *tries == 0 || best.as_ref().is_some_and(|(excess, _)| *excess == 0) {
+            return;
+        }
+        *tries -= 1;
+
+        if current >= target {
+            let excess = current - target;
+            if excess < MIN_CHANGE_VALUE
+                && best
+                    .as_ref()
+                    .is_none_or(|(best_excess, _)| excess < *best_excess)
+            {
+                *best = Some((excess, selected.clone()));
+            }
+            // Adding more inputs only increases the excess
+            return;
+        }
+
+        if index == values.len() || current + remaining[index] < target {
+            return;
+        }
+
+        selected.push(index);
+        Self::search(
+            values,
+            remaining,
+            target,
+            index + 1,
+            current + values[index],
+            selected,
+            tries,
+            best,
+        );
+        selected.pop();
+
+        Self::search(
+            values,
+            remaining,
+            target,
+            index + 1,
+            current,
+            selected,
+            tries,
+            best,
+        );
+    }
+}
+
+impl CoinSelector for BranchAndBound {
+    fn select(&self, candidates: &[SpendableUtxo], target: u64) -> Option<Vec<SpendableUtxo>> {
+        let sorted = sorted_largest_first(candidates);
+        let values: Vec<u64> = sorted.iter().map(|utxo| utxo.value()).collect();
+
+        // remaining[i] is the value of all candidates from index i onwards
+        let mut remaining = vec![0u64; values.len() + 1];
+        for i in (0..values.len()).rev() {
+            remaining[i] = remaining[i + 1] + values[i];
+        }
+        if remaining[0] < target {
+            return None;
+        }
+
+        let mut best = None;
+        let mut tries = BRANCH_AND_BOUND_MAX_TRIES;
+        Self::search(
+            &values,
+            &remaining,
+            target,
+            0,
+            0,
+            &mut Vec::new(),
+            &mut tries,
+            &mut best,
+        );
+
+        match best {
+            Some((_, indexes)) => Some(indexes.into_iter().map(|i| sorted[i].clone()).collect()),
+            None => LargestFirst.select(candidates, target),
+        }
+    }
+}
+
+/// Treats all UTXOs of an address as a single coin. Prefers the smallest address that
+/// covers the target on its own, otherwise combines the biggest addresses.
+pub struct AvoidAddressReuse;
+
+impl CoinSelector for AvoidAddressReuse {
+    fn select(&self, candidates: &[SpendableUtxo], target: u64) -> Option<Vec<SpendableUtxo>> {
+        let mut by_address: BTreeMap<&Address, Vec<&SpendableUtxo>> = BTreeMap::new();
+        for utxo in candidates {
+            by_address.entry(&utxo.address).or_default().push(utxo);
+        }
+
+        let mut groups: Vec<(u64, Vec<&SpendableUtxo>)> = by_address
+            .into_values()
+            .map(|mut utxos| {
+                utxos.sort_by_key(|utxo| utxo.outpoint);
+                (utxos.iter().map(|utxo| utxo.value()).sum(), utxos)
+            })
+            .collect();
+        // Stable sort keeps the address order for groups of equal value
+        groups.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        if let Some((_, utxos)) = groups.iter().rev().find(|(value, _)| *value >= target) {
+            return Some(utxos.iter().map(|utxo| (*utxo).clone()).collect());
+        }
+
+        let mut selected = Vec::new();
+        let mut total = 0u64;
+        for (value, utxos) in groups {
+            if total >= target {
+                break;
+            }
+            total += value;
+            selected.extend(utxos.into_iter().cloned());
+        }
+        (total >= target).then_some(selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dash_sdk::dpp::dashcore::hashes::Hash;
+    use dash_sdk::dpp::dashcore::{Network, PubkeyHash, ScriptBuf, Txid};
+
+    fn address(id: u8) -> Address {
+        let script = ScriptBuf::new_p2pkh(&PubkeyHash::from_byte_array([id; 20]));
+        Address::from_script(&script, Network::Testnet).expect("valid p2pkh script")
+    }
+
+    fn utxo(txid: u8, value: u64, address_id: u8) -> SpendableUtxo {
+        let address = address(address_id);
+        SpendableUtxo {
+            outpoint: OutPoint::new(Txid::from_byte_array([txid; 32]), 0),
+            tx_out: TxOut {
+                value,
+                script_pubkey: address.script_pubkey(),
+            },
+            address,
+        }
+    }
+
+    fn values(selection: &[SpendableUtxo]) -> Vec<u64> {
+        let mut values: Vec<u64> = selection.iter().map(|utxo| utxo.value()).collect();
+        values.sort_unstable();
+        values
+    }
+
+    fn fixture() -> Vec<SpendableUtxo> {
+        vec![
+            utxo(1, 500, 1),
+            utxo(2, 10_000, 1),
+            utxo(3, 25_000, 2),
+            utxo(4, 40_000, 3),
+            utxo(5, 100_000, 4),
+            utxo(6, 700, 5),
+        ]
+    }
+
+    #[test]
+    fn test_largest_first() {
+        let selection = LargestFirst.select(&fixture(), 120_000).unwrap();
+        assert_eq!(values(&selection), vec![40_000, 100_000]);
+    }
+
+    #[test]
+    fn test_smallest_first() {
+        let selection = SmallestFirst.select(&fixture(), 11_000).unwrap();
+        assert_eq!(values(&selection), vec![500, 700, 10_000]);
+    }
+
+    #[test]
+    fn test_branch_and_bound_finds_changeless_match() {
+        let selection = BranchAndBound.select(&fixture(), 65_000).unwrap();
+        assert_eq!(values(&selection), vec![25_000, 40_000]);
+
+        // Within MIN_CHANGE_VALUE of the target is good enough
+        let selection = BranchAndBound.select(&fixture(), 34_600).unwrap();
+        assert_eq!(values(&selection), vec![10_000, 25_000]);
+    }
+
+    #[test]
+    fn test_branch_and_bound_falls_back_to_largest_first() {
+        let selection = BranchAndBound.select(&fixture(), 2_000).unwrap();
+        assert_eq!(values(&selection), vec![100_000]);
+    }
+
+    #[test]
+    fn test_avoid_address_reuse_spends_whole_addresses() {
+        // Address 1 holds 10_500, the smallest address covering the target on its own
+        let selection = AvoidAddressReuse.select(&fixture(), 10_200).unwrap();
+        assert_eq!(values(&selection), vec![500, 10_000]);
+
+        // No single address covers this, so the biggest addresses are combined
+        let selection = AvoidAddressReuse.select(&fixture(), 130_000).unwrap();
+        assert_eq!(values(&selection), vec![40_000, 100_000]);
+    }
+
+    #[test]
+    fn test_insufficient_funds() {
+        for strategy in CoinSelectionStrategy::ALL {
+            assert!(strategy.selector().select(&fixture(), 1_000_000).is_none());
+        }
+    }
+
+    #[test]
+    fn test_selection_is_independent_of_candidate_order() {
+        let mut reversed = fixture();
+        reversed.reverse();
+        for strategy in CoinSelectionStrategy::ALL {
+            for target in [1_000, 35_000, 76_000, 150_000] {
+                assert_eq!(
+                    strategy.selector().select(&fixture(), target),
+                    strategy.selector().select(&reversed, target),
+                    "{} selected differently for target {}",
+                    strategy,
+                    target
+                );
+            }
+        }
+    }
+}
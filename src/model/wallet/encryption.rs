@@ -56,6 +56,46 @@ pub fn encrypt_message(
     Ok((encrypted_seed, salt, nonce))
 }
 
+/// Decrypt a message encrypted with [`encrypt_message`].
+pub fn decrypt_message(
+    encrypted_message: &[u8],
+    salt: &[u8],
+    nonce: &[u8],
+    password: &str,
+) -> Result<Vec<u8>, String> {
+    let key = derive_password_key(password, salt)?;
+    let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| e.to_string())?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), encrypted_message)
+        .map_err(|e| e.to_string())
+}
+
+/// Encrypt an identity private key for storage as `PrivateKeyData::Encrypted`.
+///
+/// The result is the salt, then the nonce, then the ciphertext.
+pub fn encrypt_private_key(private_key: &[u8; 32], password: &str) -> Result<Vec<u8>, String> {
+    let (ciphertext, salt, nonce) = encrypt_message(private_key, password)?;
+    Ok([salt, nonce, ciphertext].concat())
+}
+
+/// Decrypt an identity private key encrypted with [`encrypt_private_key`].
+pub fn decrypt_private_key(encrypted: &[u8], password: &str) -> Result<[u8; 32], String> {
+    if encrypted.len() <= SALT_SIZE + NONCE_SIZE {
+        return Err("Encrypted private key is too short".to_string());
+    }
+    let (salt, rest) = encrypted.split_at(SALT_SIZE);
+    let (nonce, ciphertext) = rest.split_at(NONCE_SIZE);
+
+    let private_key = decrypt_message(ciphertext, salt, nonce, password)?;
+
+    private_key.try_into().map_err(|e: Vec<u8>| {
+        format!(
+            "invalid private key length, expected 32 bytes, got {} bytes",
+            e.len()
+        )
+    })
+}
+
 impl ClosedKeyItem {
     pub fn compute_seed_hash(seed: &[u8]) -> [u8; 32] {
         let mut hasher = Sha256::new();
@@ -124,6 +164,7 @@ mod tests {
             salt,
             nonce,
             password_hint: None, // Set password hint if needed
+            passphrase_hint: None,
         };
 
         // Decrypt the seed using the instance method
@@ -135,6 +176,18 @@ mod tests {
         assert_eq!(seed, decrypted_seed);
     }
 
+    #[test]
+    fn test_encrypt_decrypt_private_key() {
+        let private_key = [7u8; 32];
+
+        let encrypted = encrypt_private_key(&private_key, "securepassword").unwrap();
+        assert_eq!(
+            decrypt_private_key(&encrypted, "securepassword").unwrap(),
+            private_key
+        );
+        assert!(decrypt_private_key(&encrypted, "wrongpassword").is_err());
+    }
+
     #[test]
     fn test_incorrect_password() {
         let seed = [42u8; 64]; // A 64-byte seed
@@ -155,6 +208,7 @@ mod tests {
             salt,
             nonce,
             password_hint: None,
+            passphrase_hint: None,
         };
 
         // Attempt to decrypt with the wrong password
@@ -0,0 +1,168 @@
+use dash_sdk::dpp::dashcore::ScriptBuf;
+use std::fmt;
+
+/// Lowest fee rate Dash Core relays by default, in duffs per kilobyte.
+pub const MIN_RELAY_FEE_RATE: u64 = 1_000;
+
+/// Serialized size of an input spending a P2PKH output: outpoint (36), script length (1),
+/// signature script with a low-S DER signature and a compressed public key (107) and
+/// sequence (4).
+pub const P2PKH_INPUT_SIZE: usize = 148;
+
+/// Serialized size of a P2PKH output: value (8), script length (1) and script (25).
+pub const P2PKH_OUTPUT_SIZE: usize = 34;
+
+/// Fee rate in duffs per kilobyte, the unit Dash Core uses for `estimatesmartfee`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FeeRate(u64);
+
+impl FeeRate {
+    /// Rates below the minimum relay fee are raised to it, as Core would not relay them.
+    pub fn from_duffs_per_kb(duffs_per_kb: u64) -> Self {
+        FeeRate(duffs_per_kb.max(MIN_RELAY_FEE_RATE))
+    }
+
+    pub fn duffs_per_kb(&self) -> u64 {
+        self.0
+    }
+
+    /// Fee for a transaction of `size` bytes, rounded up to the next duff.
+    pub fn fee_for_size(&self, size: usize) -> u64 {
+        (size as u64 * self.0).div_ceil(1_000)
+    }
+}
+
+impl Default for FeeRate {
+    fn default() -> Self {
+        FeeRate(MIN_RELAY_FEE_RATE)
+    }
+}
+
+impl fmt::Display for FeeRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} duffs/kB", self.0)
+    }
+}
+
+fn var_int_size(n: usize) -> usize {
+    match n {
+        0..=0xfc => 1,
+        0xfd..=0xffff => 3,
+        0x10000..=0xffff_ffff => 5,
+        _ => 9,
+    }
+}
+
+fn output_size(script_pubkey: &ScriptBuf) -> usize {
+    8 + var_int_size(script_pubkey.len()) + script_pubkey.len()
+}
+
+/// Estimates the fee of a wallet transaction from its shape.
+///
+/// The outputs and special transaction payload are known up front, while the number of
+/// inputs and whether a change output is needed depend on coin selection, so those are
+/// supplied when asking for the fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimator {
+    fee_rate: FeeRate,
+    output_count: usize,
+    outputs_size: usize,
+    payload_size: usize,
+}
+
+impl FeeEstimator {
+    /// A payment to `recipient_script` with P2PKH inputs.
+    pub fn for_payment(fee_rate: FeeRate, recipient_script: &ScriptBuf) -> Self {
+        FeeEstimator {
+            fee_rate,
+            output_count: 1,
+            outputs_size: output_size(recipient_script),
+            payload_size: 0,
+        }
+    }
+
+    /// An asset lock with a single P2PKH credit output, as built for identity registrations
+    /// and top ups.
+    pub fn for_asset_lock(fee_rate: FeeRate) -> Self {
+        // Payload version (1), credit output count (1) and the credit output itself
+        let payload_size = 1 + 1 + P2PKH_OUTPUT_SIZE;
+        FeeEstimator {
+            fee_rate,
+            output_count: 1,
+            outputs_size: output_size(&ScriptBuf::new_op_return(&[])),
+            payload_size: var_int_size(payload_size) + payload_size,
+        }
+    }
+
+    pub fn fee_rate(&self) -> FeeRate {
+        self.fee_rate
+    }
+
+    /// Serialized size of the transaction with `input_count` P2PKH inputs.
+    pub fn transaction_size(&self, input_count: usize, with_change: bool) -> usize {
+        let (output_count, outputs_size) = if with_change {
+            (self.output_count + 1, self.outputs_size + P2PKH_OUTPUT_SIZE)
+        } else {
+            (self.output_count, self.outputs_size)
+        };
+
+        // Version and transaction type (4) and lock time (4)
+        4 + var_int_size(input_count)
+            + input_count * P2PKH_INPUT_SIZE
+            + var_int_size(output_count)
+            + outputs_size
+            + 4
+            + self.payload_size
+    }
+
+    pub fn fee(&self, input_count: usize, with_change: bool) -> u64 {
+        self.fee_rate
+            .fee_for_size(self.transaction_size(input_count, with_change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dash_sdk::dpp::dashcore::PubkeyHash;
+    use dash_sdk::dpp::dashcore::hashes::Hash;
+
+    fn p2pkh_script() -> ScriptBuf {
+        ScriptBuf::new_p2pkh(&PubkeyHash::all_zeros())
+    }
+
+    #[test]
+    fn test_fee_rate() {
+        assert_eq!(
+            FeeRate::from_duffs_per_kb(10).duffs_per_kb(),
+            MIN_RELAY_FEE_RATE
+        );
+        assert_eq!(FeeRate::default().fee_for_size(226), 226);
+        // Rounded up to the next duff
+        assert_eq!(FeeRate::from_duffs_per_kb(1_500).fee_for_size(225), 338);
+    }
+
+    #[test]
+    fn test_payment_size() {
+        let estimator = FeeEstimator::for_payment(FeeRate::default(), &p2pkh_script());
+        // The classic one input, two output P2PKH transaction
+        assert_eq!(estimator.transaction_size(1, true), 226);
+        assert_eq!(estimator.transaction_size(2, false), 340);
+        assert_eq!(estimator.fee(1, true), 226);
+
+        // The input count takes three bytes from 253 inputs on
+        assert_eq!(
+            estimator.transaction_size(253, false) - estimator.transaction_size(252, false),
+            P2PKH_INPUT_SIZE + 2
+        );
+    }
+
+    #[test]
+    fn test_asset_lock_size() {
+        let estimator = FeeEstimator::for_asset_lock(FeeRate::from_duffs_per_kb(2_000));
+        // Empty OP_RETURN output (11) and the payload with its length (37)
+        assert_eq!(estimator.transaction_size(1, false), 206);
+        assert_eq!(estimator.transaction_size(1, true), 206 + P2PKH_OUTPUT_SIZE);
+        assert_eq!(estimator.fee(1, false), 412);
+    }
+}
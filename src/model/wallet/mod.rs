@@ -1,6 +1,18 @@
+pub mod accounts;
+pub mod address_discovery;
 mod asset_lock_transaction;
+pub mod balance;
+pub mod coin_selection;
 pub mod encryption;
+pub mod fee_estimation;
+mod payment_transaction;
+pub mod slip39;
+pub mod sweep;
+pub mod unsigned_transaction;
+pub mod utxo_reservation;
 mod utxos;
+pub mod wallet_transaction;
+mod watch_only;
 
 use dash_sdk::dashcore_rpc::dashcore::bip32::{ChildNumber, ExtendedPubKey, KeyDerivationType};
 
@@ -63,8 +75,11 @@ impl TryFrom<u32> for DerivationPathReference {
 }
 
 use crate::context::AppContext;
+use crate::model::wallet::accounts::WalletAccount;
+use crate::model::wallet::balance::UtxoStatus;
+use crate::model::wallet::slip39::{GroupConfig, Share};
+use crate::model::wallet::utxo_reservation::UtxoReservation;
 use bitflags::bitflags;
-use dash_sdk::dashcore_rpc::RpcApi;
 use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
 use dash_sdk::dpp::balances::credits::Duffs;
 use dash_sdk::dpp::dashcore::hashes::Hash;
@@ -125,7 +140,10 @@ pub struct Wallet {
     pub wallet_seed: WalletSeed,
     pub uses_password: bool,
     pub master_bip44_ecdsa_extended_public_key: ExtendedPubKey,
-    pub address_balances: BTreeMap<Address, u64>,
+    /// BIP44 accounts by index, account 0 uses `master_bip44_ecdsa_extended_public_key`.
+    pub accounts: BTreeMap<u32, WalletAccount>,
+    /// Total ever received by each address, including funds spent since
+    pub address_total_received: BTreeMap<Address, u64>,
     pub known_addresses: BTreeMap<Address, DerivationPath>,
     pub watched_addresses: BTreeMap<DerivationPath, AddressInfo>,
     #[allow(clippy::type_complexity)]
@@ -139,15 +157,24 @@ pub struct Wallet {
     pub alias: Option<String>,
     pub identities: HashMap<u32, Identity>,
     pub utxos: HashMap<Address, HashMap<OutPoint, TxOut>>,
+    /// Settlement of the outputs in `utxos`, known once the chain was asked about them
+    pub utxo_statuses: HashMap<OutPoint, UtxoStatus>,
+    /// Outputs taken by pending transactions, which must not be spent by anything else
+    pub utxo_reservations: HashMap<OutPoint, UtxoReservation>,
     pub is_main: bool,
 }
 
 pub type WalletSeedHash = [u8; 32];
 
+const WATCH_ONLY_ERROR: &str = "This is a watch-only wallet, it can not sign transactions";
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum WalletSeed {
     Open(OpenWalletSeed),
     Closed(ClosedWalletSeed),
+    /// A wallet imported from an extended public key, identified by the hash of that key.
+    /// It has no seed and can never sign.
+    WatchOnly(WalletSeedHash),
 }
 #[derive(Clone, PartialEq)]
 pub struct OpenKeyItem<const N: usize> {
@@ -174,6 +201,9 @@ pub struct ClosedKeyItem {
     pub salt: Vec<u8>,
     pub nonce: Vec<u8>,
     pub password_hint: Option<String>,
+    /// Reminder of the BIP39 passphrase the seed was derived with. The passphrase itself is
+    /// never stored.
+    pub passphrase_hint: Option<String>,
 }
 
 pub type ClosedWalletSeed = ClosedKeyItem;
@@ -186,6 +216,7 @@ impl WalletSeed {
                 // Wallet is already open
                 Ok(())
             }
+            WalletSeed::WatchOnly(_) => Err(WATCH_ONLY_ERROR.to_string()),
             WalletSeed::Closed(closed_seed) => {
                 // Try to decrypt the seed
                 let seed = closed_seed.decrypt_seed(password)?;
@@ -206,6 +237,7 @@ impl WalletSeed {
                 // Wallet is already open
                 Ok(())
             }
+            WalletSeed::WatchOnly(_) => Err(WATCH_ONLY_ERROR.to_string()),
             WalletSeed::Closed(closed_seed) => {
                 let open_wallet_seed =
                     OpenWalletSeed {
@@ -235,7 +267,7 @@ impl WalletSeed {
                 let closed_seed = open_seed.wallet_info.clone();
                 *self = WalletSeed::Closed(closed_seed);
             }
-            WalletSeed::Closed(_) => {
+            WalletSeed::Closed(_) | WalletSeed::WatchOnly(_) => {
                 // Wallet is already closed
             }
         }
@@ -255,6 +287,10 @@ impl Wallet {
     pub fn is_open(&self) -> bool {
         matches!(self.wallet_seed, WalletSeed::Open(_))
     }
+
+    pub fn is_watch_only(&self) -> bool {
+        matches!(self.wallet_seed, WalletSeed::WatchOnly(_))
+    }
     pub fn has_balance(&self) -> bool {
         self.max_balance() > 0
     }
@@ -263,45 +299,80 @@ impl Wallet {
         !self.unused_asset_locks.is_empty()
     }
 
+    /// Total of the outputs the wallet can spend right now.
     pub fn max_balance(&self) -> u64 {
-        self.utxos
-            .values()
-            .flat_map(|outpoints_to_tx_out| outpoints_to_tx_out.values().map(|tx_out| tx_out.value))
-            .sum::<Duffs>()
+        self.balance().spendable()
     }
 
     fn seed_bytes(&self) -> Result<&[u8; 64], String> {
         match &self.wallet_seed {
             WalletSeed::Open(opened) => Ok(&opened.seed),
             WalletSeed::Closed(_) => Err("Wallet is closed, please decrypt it first".to_string()),
+            WalletSeed::WatchOnly(_) => Err(WATCH_ONLY_ERROR.to_string()),
+        }
+    }
+
+    /// Splits the wallet seed into SLIP-39 shares, checking that the fewest shares allowed
+    /// recover it before they are handed out.
+    pub fn slip39_shares(
+        &self,
+        passphrase: &str,
+        group_threshold: u8,
+        groups: &[GroupConfig],
+    ) -> Result<Vec<Vec<Share>>, String> {
+        let seed = self.seed_bytes()?;
+        let shares = slip39::generate_shares(seed, passphrase, group_threshold, groups)?;
+
+        let fewest_shares: Vec<Share> = shares
+            .iter()
+            .zip(groups)
+            .take(group_threshold as usize)
+            .flat_map(|(group_shares, group)| {
+                group_shares
+                    .iter()
+                    .take(group.member_threshold as usize)
+                    .cloned()
+            })
+            .collect();
+        let mut recovered = slip39::combine_shares(&fewest_shares, passphrase)?;
+        let recovers_seed = recovered.as_slice() == seed.as_slice();
+        recovered.zeroize();
+        if !recovers_seed {
+            return Err("The generated shares do not recover the wallet seed".to_string());
         }
+
+        Ok(shares)
     }
 
     pub fn seed_hash(&self) -> [u8; 32] {
         match &self.wallet_seed {
             WalletSeed::Open(opened) => opened.wallet_info.seed_hash,
             WalletSeed::Closed(closed) => closed.seed_hash,
+            WalletSeed::WatchOnly(seed_hash) => *seed_hash,
         }
     }
 
-    pub fn encrypted_seed_slice(&self) -> &[u8] {
+    pub fn encrypted_seed_slice(&self) -> Option<&[u8]> {
         match &self.wallet_seed {
-            WalletSeed::Open(opened) => opened.wallet_info.encrypted_seed.as_slice(),
-            WalletSeed::Closed(closed) => closed.encrypted_seed.as_slice(),
+            WalletSeed::Open(opened) => Some(opened.wallet_info.encrypted_seed.as_slice()),
+            WalletSeed::Closed(closed) => Some(closed.encrypted_seed.as_slice()),
+            WalletSeed::WatchOnly(_) => None,
         }
     }
 
-    pub fn salt(&self) -> &[u8] {
+    pub fn salt(&self) -> Option<&[u8]> {
         match &self.wallet_seed {
-            WalletSeed::Open(opened) => opened.wallet_info.salt.as_slice(),
-            WalletSeed::Closed(closed) => closed.salt.as_slice(),
+            WalletSeed::Open(opened) => Some(opened.wallet_info.salt.as_slice()),
+            WalletSeed::Closed(closed) => Some(closed.salt.as_slice()),
+            WalletSeed::WatchOnly(_) => None,
         }
     }
 
-    pub fn nonce(&self) -> &[u8] {
+    pub fn nonce(&self) -> Option<&[u8]> {
         match &self.wallet_seed {
-            WalletSeed::Open(opened) => opened.wallet_info.nonce.as_slice(),
-            WalletSeed::Closed(closed) => closed.nonce.as_slice(),
+            WalletSeed::Open(opened) => Some(opened.wallet_info.nonce.as_slice()),
+            WalletSeed::Closed(closed) => Some(closed.nonce.as_slice()),
+            WalletSeed::WatchOnly(_) => None,
         }
     }
 
@@ -309,6 +380,73 @@ impl Wallet {
         match &self.wallet_seed {
             WalletSeed::Open(opened) => &opened.wallet_info.password_hint,
             WalletSeed::Closed(closed) => &closed.password_hint,
+            WalletSeed::WatchOnly(_) => &None,
+        }
+    }
+
+    /// Changes, adds or removes the password protecting the seed.
+    ///
+    /// `current_password` is checked whenever the wallet uses a password, even if it is
+    /// open. A `new_password` of `None` stores the seed without encryption. Encryption
+    /// always uses a fresh salt and nonce.
+    pub fn change_password(
+        &mut self,
+        current_password: Option<&str>,
+        new_password: Option<&str>,
+        new_password_hint: Option<String>,
+    ) -> Result<(), String> {
+        let wallet_info = match &self.wallet_seed {
+            WalletSeed::Open(opened) => &opened.wallet_info,
+            WalletSeed::Closed(closed) => closed,
+            WalletSeed::WatchOnly(_) => return Err(WATCH_ONLY_ERROR.to_string()),
+        };
+
+        let mut seed: [u8; 64] = if self.uses_password {
+            let current_password =
+                current_password.ok_or_else(|| "Enter the current password".to_string())?;
+            wallet_info
+                .decrypt_seed(current_password)
+                .map_err(|_| "Incorrect Password".to_string())?
+        } else {
+            wallet_info
+                .encrypted_seed
+                .clone()
+                .try_into()
+                .map_err(|e: Vec<u8>| {
+                    format!("incorrect seed size, expected 64 bytes, got {}", e.len())
+                })?
+        };
+
+        let (encrypted_seed, salt, nonce) = match new_password {
+            Some(new_password) => ClosedKeyItem::encrypt_seed(&seed, new_password)?,
+            None => (seed.to_vec(), vec![], vec![]),
+        };
+        let wallet_info = ClosedKeyItem {
+            seed_hash: wallet_info.seed_hash,
+            encrypted_seed,
+            salt,
+            nonce,
+            password_hint: new_password.and(new_password_hint),
+            passphrase_hint: wallet_info.passphrase_hint.clone(),
+        };
+
+        // A closed wallet stays closed unless there is no password left to open it with
+        self.wallet_seed = if self.is_open() || new_password.is_none() {
+            WalletSeed::Open(OpenWalletSeed { seed, wallet_info })
+        } else {
+            WalletSeed::Closed(wallet_info)
+        };
+        seed.zeroize();
+        self.uses_password = new_password.is_some();
+
+        Ok(())
+    }
+
+    pub fn passphrase_hint(&self) -> &Option<String> {
+        match &self.wallet_seed {
+            WalletSeed::Open(opened) => &opened.wallet_info.passphrase_hint,
+            WalletSeed::Closed(closed) => &closed.passphrase_hint,
+            WalletSeed::WatchOnly(_) => &None,
         }
     }
 
@@ -382,10 +520,12 @@ impl Wallet {
     pub fn unused_bip_44_public_key(
         &mut self,
         network: Network,
+        account: u32,
         skip_known_addresses_with_no_funds: bool,
         change: bool,
         register: Option<&AppContext>,
     ) -> Result<(PublicKey, DerivationPath), String> {
+        let account_extended_public_key = *self.account_extended_public_key(account)?;
         let mut address_index = 0;
         let mut found_unused_derivation_path = None;
         let mut known_public_key = None;
@@ -402,26 +542,22 @@ impl Wallet {
                 .as_slice(),
             );
             let derivation_path =
-                DerivationPath::bip_44_payment_path(network, 0, change, address_index);
+                DerivationPath::bip_44_payment_path(network, account, change, address_index);
 
             if let Some(address_info) = self.watched_addresses.get(&derivation_path) {
                 // Address is known
-                let address = &address_info.address;
-                let balance = self.address_balances.get(address).cloned().unwrap_or(0);
-
-                if balance > 0 {
-                    // Address has funds, skip it
+                if self.is_address_used(&address_info.address) {
+                    // Address received funds, skip it
                     address_index += 1;
                     continue;
                 }
 
-                // Address is known and has zero balance
+                // Address is known and never received anything
                 if !skip_known_addresses_with_no_funds {
                     // We can use this address
                     found_unused_derivation_path = Some(derivation_path.clone());
                     let secp = Secp256k1::new();
-                    let public_key = self
-                        .master_bip44_ecdsa_extended_public_key
+                    let public_key = account_extended_public_key
                         .derive_pub(&secp, &derivation_path_extension)
                         .map_err(|e| e.to_string())?
                         .to_pub();
@@ -434,39 +570,14 @@ impl Wallet {
                 }
             } else {
                 let secp = Secp256k1::new();
-                let public_key = self
-                    .master_bip44_ecdsa_extended_public_key
+                let public_key = account_extended_public_key
                     .derive_pub(&secp, &derivation_path_extension)
                     .map_err(|e| e.to_string())?
                     .to_pub();
                 known_public_key = Some(public_key);
                 if let Some(app_context) = register {
                     let address = Address::p2pkh(&public_key, network);
-                    app_context
-                        .core_client
-                        .read()
-                        .expect("Core client lock was poisoned")
-                        .import_address(
-                            &address,
-                            Some(
-                                format!(
-                                    "Managed by Dash Evo Tool {} {}",
-                                    self.alias.clone().unwrap_or_default(),
-                                    derivation_path
-                                )
-                                .as_str(),
-                            ),
-                            Some(false),
-                        )
-                        .map_err(|e| e.to_string())?;
-
-                    self.register_address(
-                        address,
-                        &derivation_path,
-                        DerivationPathType::CLEAR_FUNDS,
-                        DerivationPathReference::BIP44,
-                        app_context,
-                    )?;
+                    self.register_bip44_address(&address, &derivation_path, app_context)?;
                 }
                 found_unused_derivation_path = Some(derivation_path.clone());
                 break;
@@ -706,6 +817,7 @@ impl Wallet {
     pub fn receive_address(
         &mut self,
         network: Network,
+        account: u32,
         skip_known_addresses_with_no_funds: bool,
         register: Option<&AppContext>,
     ) -> Result<Address, String> {
@@ -713,6 +825,7 @@ impl Wallet {
             &self
                 .unused_bip_44_public_key(
                     network,
+                    account,
                     skip_known_addresses_with_no_funds,
                     false,
                     register,
@@ -728,10 +841,11 @@ impl Wallet {
     pub fn receive_address_with_derivation_path(
         &mut self,
         network: Network,
+        account: u32,
         register: Option<&AppContext>,
     ) -> Result<(Address, DerivationPath), String> {
         let (receive_public_key, derivation_path) =
-            self.unused_bip_44_public_key(network, false, false, register)?;
+            self.unused_bip_44_public_key(network, account, false, false, register)?;
         Ok((
             Address::p2pkh(&receive_public_key, network),
             derivation_path,
@@ -741,11 +855,12 @@ impl Wallet {
     pub fn change_address(
         &mut self,
         network: Network,
+        account: u32,
         register: Option<&AppContext>,
     ) -> Result<Address, String> {
         Ok(Address::p2pkh(
             &self
-                .unused_bip_44_public_key(network, false, true, register)?
+                .unused_bip_44_public_key(network, account, false, true, register)?
                 .0,
             network,
         ))
@@ -757,37 +872,39 @@ impl Wallet {
     pub fn change_address_with_derivation_path(
         &mut self,
         network: Network,
+        account: u32,
         register: Option<&AppContext>,
     ) -> Result<(Address, DerivationPath), String> {
         let (receive_public_key, derivation_path) =
-            self.unused_bip_44_public_key(network, false, true, register)?;
+            self.unused_bip_44_public_key(network, account, false, true, register)?;
         Ok((
             Address::p2pkh(&receive_public_key, network),
             derivation_path,
         ))
     }
 
-    pub fn update_address_balance(
+    pub fn update_address_total_received(
         &mut self,
         address: &Address,
-        new_balance: Duffs,
+        total_received: Duffs,
         context: &AppContext,
     ) -> Result<(), String> {
-        // Check if the new balance differs from the current one.
-        if let Some(current_balance) = self.address_balances.get(address) {
-            if *current_balance == new_balance {
-                // If the balance hasn't changed, skip the update.
+        // Check if the total differs from the current one.
+        if let Some(current_total) = self.address_total_received.get(address) {
+            if *current_total == total_received {
+                // If the total hasn't changed, skip the update.
                 return Ok(());
             }
         }
 
-        // If there's no current balance or it has changed, update it.
-        self.address_balances.insert(address.clone(), new_balance);
+        // If there's no current total or it has changed, update it.
+        self.address_total_received
+            .insert(address.clone(), total_received);
 
-        // Update the database with the new balance.
+        // Update the database with the new total.
         context
             .db
-            .update_address_balance(&self.seed_hash(), address, new_balance)
+            .update_address_total_received(&self.seed_hash(), address, total_received)
             .map_err(|e| e.to_string())
     }
 }
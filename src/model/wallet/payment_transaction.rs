@@ -0,0 +1,247 @@
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
+use crate::model::wallet::fee_estimation::{FeeEstimator, FeeRate};
+use crate::model::wallet::sweep::P2PKH_DUST_THRESHOLD;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::secp256k1::Message;
+use dash_sdk::dpp::dashcore::sighash::SighashCache;
+use dash_sdk::dpp::dashcore::{
+    Address, Network, OutPoint, PrivateKey, ScriptBuf, Transaction, TxIn, TxOut,
+};
+use std::collections::BTreeMap;
+
+impl Wallet {
+    /// Build and sign a standard P2PKH payment of `amount` duffs to `recipient`.
+    ///
+    /// See [`Wallet::unsigned_payment_transaction`] for how the transaction is built.
+    #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn payment_transaction(
+        &mut self,
+        network: Network,
+        recipient: &Address,
+        amount: u64,
+        subtract_fee_from_amount: bool,
+        account: u32,
+        coin_selection: CoinSelectionStrategy,
+        fee_rate: FeeRate,
+        register_addresses: Option<&AppContext>,
+    ) -> Result<
+        (
+            Transaction,
+            Option<Address>,
+            BTreeMap<OutPoint, (TxOut, Address)>,
+        ),
+        String,
+    > {
+        let (mut tx, change_address, utxos) = self.unsigned_payment_transaction(
+            network,
+            recipient,
+            amount,
+            subtract_fee_from_amount,
+            account,
+            coin_selection,
+            fee_rate,
+            register_addresses,
+        )?;
+
+        self.sign_p2pkh_inputs(&mut tx, &utxos, network)?;
+
+        Ok((tx, change_address, utxos))
+    }
+
+    /// Build a standard P2PKH payment of `amount` duffs to `recipient` without signing it.
+    ///
+    /// Inputs are picked from `account` with `coin_selection` and the fee is sized for them
+    /// at `fee_rate`. With `subtract_fee_from_amount` the recipient receives `amount` minus
+    /// the fee, and never more than `amount`. Any change is sent to a fresh change address of the same account. The UTXOs
+    /// spent by the transaction are removed from the in-memory UTXO map and returned, so the
+    /// caller can drop them from the database once the transaction is broadcast.
+    #[allow(clippy::type_complexity)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn unsigned_payment_transaction(
+        &mut self,
+        network: Network,
+        recipient: &Address,
+        amount: u64,
+        subtract_fee_from_amount: bool,
+        account: u32,
+        coin_selection: CoinSelectionStrategy,
+        fee_rate: FeeRate,
+        register_addresses: Option<&AppContext>,
+    ) -> Result<
+        (
+            Transaction,
+            Option<Address>,
+            BTreeMap<OutPoint, (TxOut, Address)>,
+        ),
+        String,
+    > {
+        let fee_estimator = FeeEstimator::for_payment(fee_rate, &recipient.script_pubkey());
+
+        let (utxos, change_option, fee) = self
+            .take_unspent_utxos_for(
+                amount,
+                &fee_estimator,
+                subtract_fee_from_amount,
+                account,
+                coin_selection,
+            )
+            .ok_or("Insufficient funds in wallet for this payment".to_string())?;
+
+        let (actual_amount, change_option) = match change_option {
+            // The recipient pays the fee, so the change is what is left of the inputs after
+            // the full amount. Without change, what is left below the change minimum goes to
+            // the fee.
+            change_option if subtract_fee_from_amount => match amount.checked_sub(fee) {
+                Some(actual_amount) => (actual_amount, change_option.map(|change| change + fee)),
+                None => {
+                    self.restore_utxos(&utxos);
+                    return Err(format!("The amount doesn't cover the fee of {} duffs", fee));
+                }
+            },
+            change_option => (amount, change_option),
+        };
+        if actual_amount < P2PKH_DUST_THRESHOLD {
+            self.restore_utxos(&utxos);
+            return Err(format!(
+                "The recipient would receive {} duffs, which is below the dust limit of {} duffs, so the payment would not be relayed",
+                actual_amount, P2PKH_DUST_THRESHOLD
+            ));
+        }
+
+        let payment_output = TxOut {
+            value: actual_amount,
+            script_pubkey: recipient.script_pubkey(),
+        };
+
+        let (change_output, change_address) = if let Some(change) = change_option {
+            let change_address = self.change_address(network, account, register_addresses)?;
+            (
+                Some(TxOut {
+                    value: change,
+                    script_pubkey: change_address.script_pubkey(),
+                }),
+                Some(change_address),
+            )
+        } else {
+            (None, None)
+        };
+
+        let inputs = utxos
+            .keys()
+            .map(|utxo| TxIn {
+                previous_output: *utxo,
+                ..Default::default()
+            })
+            .collect();
+
+        let tx = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: inputs,
+            output: {
+                let mut outputs = vec![payment_output];
+                if let Some(change_output) = change_output {
+                    outputs.push(change_output);
+                }
+                outputs
+            },
+            special_transaction_payload: None,
+        };
+
+        Ok((tx, change_address, utxos))
+    }
+
+    /// Sign every input of `tx` with the wallet key of the address that owns the spent output.
+    ///
+    /// `utxos` must contain the previous output and owning address of every input.
+    pub(crate) fn sign_p2pkh_inputs(
+        &self,
+        tx: &mut Transaction,
+        utxos: &BTreeMap<OutPoint, (TxOut, Address)>,
+        network: Network,
+    ) -> Result<(), String> {
+        sign_p2pkh_inputs_with(tx, |outpoint| {
+            let (previous_output, input_address) = utxos
+                .get(outpoint)
+                .ok_or(format!("Missing previous output for input {}", outpoint))?;
+            let private_key = self
+                .private_key_for_address(input_address, network)?
+                .ok_or(format!(
+                    "Expected address {} to be in wallet",
+                    input_address
+                ))?;
+            Ok((previous_output.script_pubkey.clone(), private_key))
+        })
+    }
+
+    /// Put UTXOs that were taken for a transaction back into the wallet, e.g. after a
+    /// failed broadcast.
+    pub fn restore_utxos(&mut self, utxos: &BTreeMap<OutPoint, (TxOut, Address)>) {
+        for (outpoint, (tx_out, address)) in utxos {
+            self.utxos
+                .entry(address.clone())
+                .or_default()
+                .insert(*outpoint, tx_out.clone());
+        }
+    }
+}
+
+/// Sign every P2PKH input of `tx`. `input_key` returns the script of the spent output and the
+/// key that owns it for an input's previous outpoint.
+pub(crate) fn sign_p2pkh_inputs_with<F>(tx: &mut Transaction, input_key: F) -> Result<(), String>
+where
+    F: Fn(&OutPoint) -> Result<(ScriptBuf, PrivateKey), String>,
+{
+    let secp = Secp256k1::new();
+    let sighash_u32 = 1u32;
+
+    let keys = tx
+        .input
+        .iter()
+        .map(|input| input_key(&input.previous_output))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let cache = SighashCache::new(&*tx);
+
+    let sighashes = keys
+        .iter()
+        .enumerate()
+        .map(|(i, (script_pubkey, _))| {
+            cache
+                .legacy_signature_hash(i, script_pubkey, sighash_u32)
+                .map_err(|e| e.to_string())
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    // Now we can drop the cache to end the immutable borrow
+    #[allow(clippy::drop_non_drop)]
+    drop(cache);
+
+    for ((input, sighash), (_, private_key)) in tx.input.iter_mut().zip(sighashes).zip(keys) {
+        let message = Message::from_digest(sighash.into());
+
+        // Sign the message with the private key
+        let sig = secp.sign_ecdsa(&message, &private_key.inner);
+
+        // Serialize the DER-encoded signature and append the sighash type
+        let mut serialized_sig = sig.serialize_der().to_vec();
+
+        let mut sig_script = vec![serialized_sig.len() as u8 + 1];
+
+        sig_script.append(&mut serialized_sig);
+
+        sig_script.push(1);
+
+        let mut serialized_pub_key = private_key.public_key(&secp).serialize();
+
+        sig_script.push(serialized_pub_key.len() as u8);
+        sig_script.append(&mut serialized_pub_key);
+        // Create script_sig
+        input.script_sig = ScriptBuf::from_bytes(sig_script);
+    }
+
+    Ok(())
+}
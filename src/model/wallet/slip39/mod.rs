@@ -0,0 +1,614 @@
+//! SLIP-0039 Shamir backups: a wallet's master secret split into mnemonic shares, organized in
+//! groups, so that a threshold of groups each holding a threshold of their shares recovers it.
+//!
+//! See <https://github.com/satoshilabs/slips/blob/master/slip-0039.md>.
+
+mod wordlist;
+
+use bip39::rand::{RngCore, rngs::OsRng};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use wordlist::WORDLIST;
+use zeroize::Zeroize;
+
+const RADIX_BITS: usize = 10;
+const CHECKSUM_LENGTH_WORDS: usize = 3;
+/// Identifier, iteration exponent, group and member parameters, and checksum
+const METADATA_LENGTH_WORDS: usize = 7;
+const MIN_STRENGTH_BITS: usize = 128;
+const MIN_MNEMONIC_LENGTH_WORDS: usize =
+    METADATA_LENGTH_WORDS + MIN_STRENGTH_BITS.div_ceil(RADIX_BITS);
+const DIGEST_LENGTH_BYTES: usize = 4;
+const CUSTOMIZATION_STRING_ORIG: &[u8] = b"shamir";
+const CUSTOMIZATION_STRING_EXTENDABLE: &[u8] = b"shamir_extendable";
+const BASE_ITERATION_COUNT: u32 = 10000;
+const ROUND_COUNT: u8 = 4;
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+
+/// Each step doubles the work needed to decrypt the master secret from the shares.
+const ITERATION_EXPONENT: u8 = 1;
+
+/// Groups per backup, and shares per group, are stored in 4 bits.
+pub const MAX_SHARE_COUNT: u8 = 16;
+
+const RS1024_GENERATOR: [u32; 10] = [
+    0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412, 0x38086C24, 0x3090FC48,
+    0x21B1F890, 0x3F3F120,
+];
+
+/// Exponent and logarithm tables of GF(256) with the polynomial x^8 + x^4 + x^3 + x + 1.
+const GF256_TABLES: ([u8; 255], [u8; 256]) = {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11B;
+        }
+        i += 1;
+    }
+    (exp, log)
+};
+const EXP_TABLE: [u8; 255] = GF256_TABLES.0;
+const LOG_TABLE: [u8; 256] = GF256_TABLES.1;
+
+/// A single SLIP-0039 share, as written down in one mnemonic.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Share {
+    /// Random identifier shared by all shares of one backup
+    pub identifier: u16,
+    pub extendable: bool,
+    pub iteration_exponent: u8,
+    pub group_index: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+    pub member_index: u8,
+    pub member_threshold: u8,
+    value: Vec<u8>,
+}
+
+impl Drop for Share {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+/// How many of the shares of a group are needed, and how many are created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupConfig {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+impl Share {
+    /// Parses a share mnemonic, verifying its checksum.
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Self, String> {
+        let words = mnemonic
+            .split_whitespace()
+            .map(word_index)
+            .collect::<Result<Vec<u16>, String>>()?;
+
+        if words.len() < MIN_MNEMONIC_LENGTH_WORDS {
+            return Err(format!(
+                "A share has at least {} words, this one has {}",
+                MIN_MNEMONIC_LENGTH_WORDS,
+                words.len()
+            ));
+        }
+        let padding_bits = (RADIX_BITS * (words.len() - METADATA_LENGTH_WORDS)) % 16;
+        if padding_bits > 8 {
+            return Err(format!("A share can not have {} words", words.len()));
+        }
+
+        let id_exp = ((words[0] as u32) << RADIX_BITS) | words[1] as u32;
+        let extendable = (id_exp >> 4) & 1 == 1;
+        if rs1024_polymod(customization_string(extendable), &words) != 1 {
+            return Err(
+                "The share checksum is invalid, please check the words for typos".to_string(),
+            );
+        }
+
+        let parameters = ((words[2] as u32) << RADIX_BITS) | words[3] as u32;
+        let parameter = |position: u32| ((parameters >> (16 - 4 * position)) & 0xF) as u8;
+        let group_threshold = parameter(1) + 1;
+        let group_count = parameter(2) + 1;
+        if group_threshold > group_count {
+            return Err("The share requires more groups than the backup has".to_string());
+        }
+
+        let value_words = &words[4..words.len() - CHECKSUM_LENGTH_WORDS];
+        let value_byte_count = (RADIX_BITS * value_words.len() - padding_bits) / 8;
+
+        Ok(Share {
+            identifier: (id_exp >> 5) as u16,
+            extendable,
+            iteration_exponent: (id_exp & 0xF) as u8,
+            group_index: parameter(0),
+            group_threshold,
+            group_count,
+            member_index: parameter(3),
+            member_threshold: parameter(4) + 1,
+            value: words_to_bytes(value_words, value_byte_count)?,
+        })
+    }
+
+    pub fn to_mnemonic(&self) -> String {
+        let id_exp = ((self.identifier as u32) << 5)
+            | ((self.extendable as u32) << 4)
+            | self.iteration_exponent as u32;
+        let parameters = ((self.group_index as u32) << 16)
+            | (((self.group_threshold - 1) as u32) << 12)
+            | (((self.group_count - 1) as u32) << 8)
+            | ((self.member_index as u32) << 4)
+            | (self.member_threshold - 1) as u32;
+
+        let mut words = vec![
+            (id_exp >> RADIX_BITS) as u16,
+            (id_exp & 0x3FF) as u16,
+            (parameters >> RADIX_BITS) as u16,
+            (parameters & 0x3FF) as u16,
+        ];
+        words.extend(bytes_to_words(&self.value));
+        let checksum = rs1024_create_checksum(customization_string(self.extendable), &words);
+        words.extend(checksum);
+
+        let mnemonic = words
+            .iter()
+            .map(|word| WORDLIST[*word as usize])
+            .collect::<Vec<_>>()
+            .join(" ");
+        words.zeroize();
+        mnemonic
+    }
+
+    /// Whether both shares can belong to the same backup.
+    pub fn is_compatible_with(&self, other: &Share) -> bool {
+        self.identifier == other.identifier
+            && self.extendable == other.extendable
+            && self.iteration_exponent == other.iteration_exponent
+            && self.group_threshold == other.group_threshold
+            && self.group_count == other.group_count
+            && self.value.len() == other.value.len()
+    }
+}
+
+fn word_index(word: &str) -> Result<u16, String> {
+    WORDLIST
+        .binary_search(&word.to_lowercase().as_str())
+        .map(|index| index as u16)
+        .map_err(|_| format!("\"{}\" is not a SLIP-39 word", word))
+}
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_STRING_EXTENDABLE
+    } else {
+        CUSTOMIZATION_STRING_ORIG
+    }
+}
+
+fn rs1024_polymod(customization_string: &[u8], words: &[u16]) -> u32 {
+    let values = customization_string
+        .iter()
+        .map(|byte| *byte as u32)
+        .chain(words.iter().map(|word| *word as u32));
+
+    let mut checksum: u32 = 1;
+    for value in values {
+        let top = checksum >> 20;
+        checksum = ((checksum & 0xFFFFF) << RADIX_BITS) ^ value;
+        for (i, generator) in RS1024_GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn rs1024_create_checksum(customization_string: &[u8], words: &[u16]) -> [u16; 3] {
+    let padded = [words, &[0; CHECKSUM_LENGTH_WORDS]].concat();
+    let polymod = rs1024_polymod(customization_string, &padded) ^ 1;
+    [2, 1, 0].map(|i| ((polymod >> (RADIX_BITS * i)) & 0x3FF) as u16)
+}
+
+/// Packs 10-bit words into bytes, the first word starting with zero padding.
+fn words_to_bytes(words: &[u16], byte_count: usize) -> Result<Vec<u8>, String> {
+    let padding_bits = RADIX_BITS * words.len() - 8 * byte_count;
+    let mut bytes = Vec::with_capacity(byte_count);
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    for (i, word) in words.iter().enumerate() {
+        accumulator = (accumulator << RADIX_BITS) | *word as u32;
+        bits += RADIX_BITS;
+        if i == 0 {
+            if accumulator >> (RADIX_BITS - padding_bits) != 0 {
+                return Err("The share has invalid padding".to_string());
+            }
+            bits -= padding_bits;
+        }
+        while bits >= 8 {
+            bits -= 8;
+            bytes.push((accumulator >> bits) as u8);
+        }
+        accumulator &= (1 << bits) - 1;
+    }
+    Ok(bytes)
+}
+
+fn bytes_to_words(bytes: &[u8]) -> Vec<u16> {
+    let word_count = (8 * bytes.len()).div_ceil(RADIX_BITS);
+    let mut words = Vec::with_capacity(word_count);
+    let mut accumulator: u32 = 0;
+    // Leading zero bits that pad the value to a whole number of words
+    let mut bits = RADIX_BITS * word_count - 8 * bytes.len();
+    for byte in bytes {
+        accumulator = (accumulator << 8) | *byte as u32;
+        bits += 8;
+        while bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            words.push(((accumulator >> bits) & 0x3FF) as u16);
+        }
+        accumulator &= (1 << bits) - 1;
+    }
+    words
+}
+
+/// Evaluates at `x` the polynomial running through the given points, byte by byte.
+fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Vec<u8> {
+    if let Some((_, value)) = shares.iter().find(|(index, _)| *index == x) {
+        return value.clone();
+    }
+
+    let log = |value: u8| LOG_TABLE[value as usize] as i64;
+    let log_product: i64 = shares.iter().map(|(index, _)| log(index ^ x)).sum();
+
+    let mut result = vec![0u8; shares[0].1.len()];
+    for (index, value) in shares {
+        let log_denominator: i64 = shares
+            .iter()
+            .filter(|(other, _)| other != index)
+            .map(|(other, _)| log(index ^ other))
+            .sum();
+        let log_basis = (log_product - log(index ^ x) - log_denominator).rem_euclid(255);
+        for (result_byte, byte) in result.iter_mut().zip(value) {
+            if *byte != 0 {
+                *result_byte ^= EXP_TABLE[((log(*byte) + log_basis) % 255) as usize];
+            }
+        }
+    }
+    result
+}
+
+fn create_digest(random_data: &[u8], shared_secret: &[u8]) -> Vec<u8> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(random_data).expect("HMAC accepts keys of any length");
+    mac.update(shared_secret);
+    mac.finalize().into_bytes()[..DIGEST_LENGTH_BYTES].to_vec()
+}
+
+fn split_secret(
+    threshold: u8,
+    share_count: u8,
+    shared_secret: &[u8],
+) -> Result<Vec<(u8, Vec<u8>)>, String> {
+    if threshold == 0 || threshold > share_count {
+        return Err("The threshold must be between 1 and the number of shares".to_string());
+    }
+    if threshold == 1 {
+        return Ok((0..share_count)
+            .map(|index| (index, shared_secret.to_vec()))
+            .collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares: Vec<(u8, Vec<u8>)> = (0..random_share_count)
+        .map(|index| {
+            let mut value = vec![0u8; shared_secret.len()];
+            OsRng.fill_bytes(&mut value);
+            (index, value)
+        })
+        .collect();
+
+    let mut random_part = vec![0u8; shared_secret.len() - DIGEST_LENGTH_BYTES];
+    OsRng.fill_bytes(&mut random_part);
+    let digest = [create_digest(&random_part, shared_secret), random_part].concat();
+
+    let mut base_shares = shares.clone();
+    base_shares.push((DIGEST_INDEX, digest));
+    base_shares.push((SECRET_INDEX, shared_secret.to_vec()));
+
+    for index in random_share_count..share_count {
+        shares.push((index, interpolate(&base_shares, index)));
+    }
+    base_shares
+        .iter_mut()
+        .for_each(|(_, value)| value.zeroize());
+
+    Ok(shares)
+}
+
+fn recover_secret(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    if threshold == 1 {
+        return Ok(shares[0].1.clone());
+    }
+
+    let shared_secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH_BYTES);
+    if digest != create_digest(random_part, &shared_secret) {
+        return Err("The shares do not fit together, one of them is wrong".to_string());
+    }
+    Ok(shared_secret)
+}
+
+/// The 4-round Feistel network encrypting the master secret with the passphrase.
+fn feistel(
+    secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    rounds: impl Iterator<Item = u8>,
+) -> Vec<u8> {
+    let salt_prefix = if extendable {
+        vec![]
+    } else {
+        [CUSTOMIZATION_STRING_ORIG, &identifier.to_be_bytes()].concat()
+    };
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+
+    let (left, right) = secret.split_at(secret.len() / 2);
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    for round in rounds {
+        let password = [&[round], passphrase].concat();
+        let salt = [salt_prefix.as_slice(), &right].concat();
+        let mut round_output = vec![0u8; right.len()];
+        pbkdf2::pbkdf2_hmac::<Sha256>(&password, &salt, iterations, &mut round_output);
+
+        left.iter_mut()
+            .zip(&round_output)
+            .for_each(|(byte, key)| *byte ^= key);
+        std::mem::swap(&mut left, &mut right);
+        round_output.zeroize();
+    }
+
+    let output = [right.as_slice(), &left].concat();
+    left.zeroize();
+    right.zeroize();
+    output
+}
+
+fn check_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.bytes().all(|byte| (32..=126).contains(&byte)) {
+        Ok(())
+    } else {
+        Err("A SLIP-39 passphrase may only contain printable ASCII characters".to_string())
+    }
+}
+
+/// Splits a master secret into shares: `group_threshold` of the `groups` are needed to recover
+/// it, each with the member threshold of its config. Returns the shares of each group.
+pub fn generate_shares(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[GroupConfig],
+) -> Result<Vec<Vec<Share>>, String> {
+    if master_secret.len() * 8 < MIN_STRENGTH_BITS || !master_secret.len().is_multiple_of(2) {
+        return Err(format!(
+            "The master secret must be an even number of bytes, at least {} bits long",
+            MIN_STRENGTH_BITS
+        ));
+    }
+    check_passphrase(passphrase)?;
+    if groups.is_empty() || groups.len() > MAX_SHARE_COUNT as usize {
+        return Err(format!(
+            "A backup has between 1 and {} groups",
+            MAX_SHARE_COUNT
+        ));
+    }
+    if group_threshold == 0 || group_threshold as usize > groups.len() {
+        return Err("The group threshold must be between 1 and the number of groups".to_string());
+    }
+    for (index, group) in groups.iter().enumerate() {
+        if group.member_count == 0 || group.member_count > MAX_SHARE_COUNT {
+            return Err(format!(
+                "Group {} must have between 1 and {} shares",
+                index + 1,
+                MAX_SHARE_COUNT
+            ));
+        }
+        if group.member_threshold == 0 || group.member_threshold > group.member_count {
+            return Err(format!(
+                "The threshold of group {} must be between 1 and its number of shares",
+                index + 1
+            ));
+        }
+        if group.member_threshold == 1 && group.member_count > 1 {
+            return Err(format!(
+                "Group {} has a threshold of 1, so it must have a single share: all its shares would be identical",
+                index + 1
+            ));
+        }
+    }
+
+    let identifier = (OsRng.next_u32() & 0x7FFF) as u16;
+    let extendable = true;
+    let mut encrypted_secret = feistel(
+        master_secret,
+        passphrase.as_bytes(),
+        ITERATION_EXPONENT,
+        identifier,
+        extendable,
+        0..ROUND_COUNT,
+    );
+
+    let group_secrets = split_secret(group_threshold, groups.len() as u8, &encrypted_secret)?;
+    encrypted_secret.zeroize();
+
+    let mut shares = Vec::with_capacity(groups.len());
+    for ((group_index, mut group_secret), group) in group_secrets.into_iter().zip(groups) {
+        let member_secrets =
+            split_secret(group.member_threshold, group.member_count, &group_secret)?;
+        group_secret.zeroize();
+        shares.push(
+            member_secrets
+                .into_iter()
+                .map(|(member_index, value)| Share {
+                    identifier,
+                    extendable,
+                    iteration_exponent: ITERATION_EXPONENT,
+                    group_index,
+                    group_threshold,
+                    group_count: groups.len() as u8,
+                    member_index,
+                    member_threshold: group.member_threshold,
+                    value,
+                })
+                .collect(),
+        );
+    }
+    Ok(shares)
+}
+
+/// Recovers the master secret from shares of one backup. Extra shares and incomplete groups
+/// are ignored, as long as enough groups are complete.
+pub fn combine_shares(shares: &[Share], passphrase: &str) -> Result<Vec<u8>, String> {
+    let first = shares
+        .first()
+        .ok_or_else(|| "No shares were entered".to_string())?;
+    if shares.iter().any(|share| !first.is_compatible_with(share)) {
+        return Err("The shares do not all belong to the same backup".to_string());
+    }
+    check_passphrase(passphrase)?;
+
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+    for share in shares {
+        groups.entry(share.group_index).or_default().push(share);
+    }
+
+    let mut group_secrets: Vec<(u8, Vec<u8>)> = vec![];
+    for (group_index, members) in groups {
+        let member_threshold = members[0].member_threshold;
+        if members
+            .iter()
+            .any(|share| share.member_threshold != member_threshold)
+        {
+            return Err(format!(
+                "The shares of group {} disagree on its threshold",
+                group_index + 1
+            ));
+        }
+        let mut member_shares: Vec<(u8, Vec<u8>)> = vec![];
+        for share in members {
+            if member_shares
+                .iter()
+                .any(|(member_index, _)| *member_index == share.member_index)
+            {
+                return Err(format!(
+                    "Share {} of group {} was entered twice",
+                    share.member_index + 1,
+                    group_index + 1
+                ));
+            }
+            member_shares.push((share.member_index, share.value.clone()));
+        }
+        if member_shares.len() >= member_threshold as usize {
+            member_shares.truncate(member_threshold as usize);
+            group_secrets.push((
+                group_index,
+                recover_secret(member_threshold, &member_shares)?,
+            ));
+        }
+        member_shares
+            .iter_mut()
+            .for_each(|(_, value)| value.zeroize());
+        if group_secrets.len() == first.group_threshold as usize {
+            break;
+        }
+    }
+
+    if group_secrets.len() < first.group_threshold as usize {
+        return Err(format!(
+            "{} of the {} groups needed are complete",
+            group_secrets.len(),
+            first.group_threshold
+        ));
+    }
+
+    let mut encrypted_secret = recover_secret(first.group_threshold, &group_secrets)?;
+    group_secrets
+        .iter_mut()
+        .for_each(|(_, value)| value.zeroize());
+    let master_secret = feistel(
+        &encrypted_secret,
+        passphrase.as_bytes(),
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+        (0..ROUND_COUNT).rev(),
+    );
+    encrypted_secret.zeroize();
+    Ok(master_secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine_reference_share() {
+        // Test vector 1 of SLIP-0039
+        let mnemonic = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+        let share = Share::from_mnemonic(mnemonic).unwrap();
+        assert_eq!(share.to_mnemonic(), mnemonic);
+        assert_eq!(
+            hex::encode(combine_shares(&[share], "TREZOR").unwrap()),
+            "bb54aac4b89dc868ba37d9cc21b2cece"
+        );
+
+        // Test vector 2, the same share with a wrong checksum
+        let mnemonic = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney";
+        assert!(Share::from_mnemonic(mnemonic).is_err());
+    }
+
+    #[test]
+    fn test_generate_and_combine_groups() {
+        let master_secret = [7u8; 64];
+        let groups = [
+            GroupConfig {
+                member_threshold: 2,
+                member_count: 3,
+            },
+            GroupConfig {
+                member_threshold: 1,
+                member_count: 1,
+            },
+            GroupConfig {
+                member_threshold: 3,
+                member_count: 5,
+            },
+        ];
+        let shares = generate_shares(&master_secret, "passphrase", 2, &groups).unwrap();
+        assert_eq!(shares.iter().map(Vec::len).collect::<Vec<_>>(), [3, 1, 5]);
+
+        let parse = |share: &Share| Share::from_mnemonic(&share.to_mnemonic()).unwrap();
+        let mut entered: Vec<Share> = shares[0][1..].iter().map(parse).collect();
+        entered.extend(shares[2][..2].iter().map(parse));
+        assert!(combine_shares(&entered, "passphrase").is_err());
+
+        entered.push(parse(&shares[2][4]));
+        assert_eq!(
+            combine_shares(&entered, "passphrase").unwrap(),
+            master_secret
+        );
+        assert_ne!(combine_shares(&entered, "other").unwrap(), master_secret);
+    }
+}
@@ -0,0 +1,207 @@
+//! Sweeping funds held by a standalone private key, such as a paper wallet, into a wallet.
+
+use crate::model::wallet::fee_estimation::{FeeEstimator, FeeRate};
+use crate::model::wallet::payment_transaction::sign_p2pkh_inputs_with;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::{Address, Network, OutPoint, PrivateKey, Transaction, TxIn, TxOut};
+
+/// Extra signature script bytes of an input signed with an uncompressed public key, which
+/// older paper wallets use.
+const UNCOMPRESSED_KEY_EXTRA_SIZE: usize = 32;
+
+/// Smallest P2PKH output Dash Core relays at its default dust relay fee of 3000 duffs/kB.
+pub const P2PKH_DUST_THRESHOLD: Duffs = 546;
+
+/// A signed transaction moving everything a private key holds to a wallet address.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepTransaction {
+    pub transaction: Transaction,
+    /// Address of the swept key
+    pub source: Address,
+    pub destination: Address,
+    pub input_value: Duffs,
+    pub fee: Duffs,
+}
+
+impl SweepTransaction {
+    /// Builds and signs a transaction spending all `utxos` of `private_key` to
+    /// `destination`, paying the fee from the swept funds.
+    pub fn new(
+        private_key: &PrivateKey,
+        utxos: Vec<(OutPoint, TxOut)>,
+        destination: Address,
+        fee_rate: FeeRate,
+        network: Network,
+    ) -> Result<Self, String> {
+        let secp = Secp256k1::new();
+        let source = Address::p2pkh(&private_key.public_key(&secp), network);
+        if utxos.is_empty() {
+            return Err(format!("No unspent outputs found for {}", source));
+        }
+
+        let input_value: Duffs = utxos.iter().map(|(_, tx_out)| tx_out.value).sum();
+        let estimator = FeeEstimator::for_payment(fee_rate, &destination.script_pubkey());
+        let mut size = estimator.transaction_size(utxos.len(), false);
+        if !private_key.compressed {
+            size += utxos.len() * UNCOMPRESSED_KEY_EXTRA_SIZE;
+        }
+        let fee = fee_rate.fee_for_size(size);
+        let amount = input_value
+            .checked_sub(fee)
+            .filter(|amount| *amount > 0)
+            .ok_or(format!(
+                "The {} duffs held by {} don't cover the fee of {} duffs",
+                input_value, source, fee
+            ))?;
+        if amount < P2PKH_DUST_THRESHOLD {
+            return Err(format!(
+                "Only {} duffs held by {} would be left after the fee of {} duffs, which is below the dust limit of {} duffs, so the sweep would not be relayed",
+                amount, source, fee, P2PKH_DUST_THRESHOLD
+            ));
+        }
+
+        let mut transaction = Transaction {
+            version: 2,
+            lock_time: 0,
+            input: utxos
+                .iter()
+                .map(|(outpoint, _)| TxIn {
+                    previous_output: *outpoint,
+                    ..Default::default()
+                })
+                .collect(),
+            output: vec![TxOut {
+                value: amount,
+                script_pubkey: destination.script_pubkey(),
+            }],
+            special_transaction_payload: None,
+        };
+        sign_p2pkh_inputs_with(&mut transaction, |_| {
+            Ok((source.script_pubkey(), *private_key))
+        })?;
+
+        Ok(Self {
+            transaction,
+            source,
+            destination,
+            input_value,
+            fee,
+        })
+    }
+
+    /// What arrives in the wallet.
+    pub fn amount(&self) -> Duffs {
+        self.input_value - self.fee
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dash_sdk::dpp::dashcore::secp256k1::SecretKey;
+    use dash_sdk::dpp::dashcore::{ScriptBuf, Txid};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_sweep_spends_everything_minus_fee() {
+        let network = Network::Testnet;
+        let private_key = PrivateKey::new(SecretKey::from_slice(&[7; 32]).unwrap(), network);
+        let destination = Address::p2pkh(
+            &PrivateKey::new(SecretKey::from_slice(&[8; 32]).unwrap(), network)
+                .public_key(&Secp256k1::new()),
+            network,
+        );
+        let source_script =
+            Address::p2pkh(&private_key.public_key(&Secp256k1::new()), network).script_pubkey();
+        let txid =
+            Txid::from_str("0101010101010101010101010101010101010101010101010101010101010101")
+                .unwrap();
+        let utxos = (0..2)
+            .map(|vout| {
+                (
+                    OutPoint::new(txid, vout),
+                    TxOut {
+                        value: 50_000_000,
+                        script_pubkey: source_script.clone(),
+                    },
+                )
+            })
+            .collect();
+
+        let sweep = SweepTransaction::new(
+            &private_key,
+            utxos,
+            destination.clone(),
+            FeeRate::default(),
+            network,
+        )
+        .unwrap();
+        assert_eq!(sweep.input_value, 100_000_000);
+        assert_eq!(sweep.transaction.output.len(), 1);
+        assert_eq!(sweep.transaction.output[0].value, sweep.amount());
+        assert_eq!(
+            sweep.transaction.output[0].script_pubkey,
+            destination.script_pubkey()
+        );
+        assert!(sweep.fee > 0);
+        assert!(
+            sweep
+                .transaction
+                .input
+                .iter()
+                .all(|input| input.script_sig != ScriptBuf::new())
+        );
+
+        assert!(
+            SweepTransaction::new(
+                &private_key,
+                vec![],
+                destination.clone(),
+                FeeRate::default(),
+                network
+            )
+            .is_err()
+        );
+
+        // What is left after the fee must not be dust
+        let single_utxo = |value| {
+            vec![(
+                OutPoint::new(txid, 0),
+                TxOut {
+                    value,
+                    script_pubkey: source_script.clone(),
+                },
+            )]
+        };
+        let fee = SweepTransaction::new(
+            &private_key,
+            single_utxo(50_000_000),
+            destination.clone(),
+            FeeRate::default(),
+            network,
+        )
+        .unwrap()
+        .fee;
+        assert!(
+            SweepTransaction::new(
+                &private_key,
+                single_utxo(fee + P2PKH_DUST_THRESHOLD - 1),
+                destination.clone(),
+                FeeRate::default(),
+                network
+            )
+            .is_err()
+        );
+        assert!(
+            SweepTransaction::new(
+                &private_key,
+                single_utxo(fee + P2PKH_DUST_THRESHOLD),
+                destination,
+                FeeRate::default(),
+                network
+            )
+            .is_ok()
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use super::Wallet;
+use super::payment_transaction::sign_p2pkh_inputs_with;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::bip32::DerivationPath;
+use dash_sdk::dpp::dashcore::consensus::{deserialize, serialize};
+use dash_sdk::dpp::dashcore::{Address, Network, OutPoint, Transaction, TxOut, Txid};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Version of the exported file format, bumped on incompatible changes.
+const UNSIGNED_TRANSACTION_FORMAT_VERSION: u8 = 2;
+
+/// A spent output together with what an offline wallet needs to sign for it.
+///
+/// Legacy signatures don't commit to the values of the spent outputs, so the whole previous
+/// transaction is carried along, like a PSBT `non_witness_utxo`. Values are only ever taken
+/// from it after checking it hashes to the spent txid, never from the exporter's word.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsignedInput {
+    previous_transaction: Transaction,
+    previous_output: TxOut,
+    pub address: Address,
+    pub derivation_path: DerivationPath,
+}
+
+impl UnsignedInput {
+    /// Checks that `previous_transaction` created the output `outpoint` spends.
+    pub fn new(
+        outpoint: &OutPoint,
+        previous_transaction: Transaction,
+        address: Address,
+        derivation_path: DerivationPath,
+    ) -> Result<Self, String> {
+        if previous_transaction.txid() != outpoint.txid {
+            return Err(format!(
+                "The previous transaction of input {} is {}",
+                outpoint,
+                previous_transaction.txid()
+            ));
+        }
+        let previous_output = previous_transaction
+            .output
+            .get(outpoint.vout as usize)
+            .cloned()
+            .ok_or(format!(
+                "The previous transaction of input {} has no output {}",
+                outpoint, outpoint.vout
+            ))?;
+        Ok(Self {
+            previous_transaction,
+            previous_output,
+            address,
+            derivation_path,
+        })
+    }
+
+    pub fn previous_output(&self) -> &TxOut {
+        &self.previous_output
+    }
+}
+
+/// A transaction built by an online wallet that still has to be signed by the wallet holding
+/// the seed, in the spirit of a PSBT.
+///
+/// The online wallet is often a watch-only copy, which is identified by its extended public
+/// key rather than the seed, so the signer is matched per input by derivation path instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsignedTransaction {
+    pub network: Network,
+    pub transaction: Transaction,
+    pub inputs: BTreeMap<OutPoint, UnsignedInput>,
+}
+
+/// Serialized form of [`UnsignedTransaction`], binary values are hex encoded.
+#[derive(Serialize, Deserialize)]
+struct UnsignedTransactionFile {
+    version: u8,
+    network: String,
+    transaction: String,
+    inputs: Vec<UnsignedInputFile>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct UnsignedInputFile {
+    outpoint: String,
+    previous_transaction: String,
+    address: String,
+    derivation_path: String,
+}
+
+impl UnsignedTransaction {
+    pub fn input_value(&self) -> u64 {
+        self.inputs
+            .values()
+            .map(|input| input.previous_output.value)
+            .sum()
+    }
+
+    pub fn fee(&self) -> u64 {
+        let output_value: u64 = self.transaction.output.iter().map(|o| o.value).sum();
+        self.input_value().saturating_sub(output_value)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        let file = UnsignedTransactionFile {
+            version: UNSIGNED_TRANSACTION_FORMAT_VERSION,
+            network: self.network.to_string(),
+            transaction: hex::encode(serialize(&self.transaction)),
+            inputs: self
+                .inputs
+                .iter()
+                .map(|(outpoint, input)| UnsignedInputFile {
+                    outpoint: outpoint.to_string(),
+                    previous_transaction: hex::encode(serialize(&input.previous_transaction)),
+                    address: input.address.to_string(),
+                    derivation_path: input.derivation_path.to_string(),
+                })
+                .collect(),
+        };
+        serde_json::to_string(&file).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let file: UnsignedTransactionFile = serde_json::from_str(json.trim())
+            .map_err(|e| format!("Not an unsigned transaction: {}", e))?;
+        if file.version != UNSIGNED_TRANSACTION_FORMAT_VERSION {
+            return Err(format!(
+                "Unsupported unsigned transaction version {}",
+                file.version
+            ));
+        }
+
+        let network = Network::from_str(&file.network).map_err(|e| e.to_string())?;
+        let transaction: Transaction =
+            deserialize(&hex::decode(&file.transaction).map_err(|e| e.to_string())?)
+                .map_err(|e| format!("Invalid transaction: {}", e))?;
+
+        let mut inputs = BTreeMap::new();
+        for input in file.inputs {
+            let outpoint = OutPoint::from_str(&input.outpoint).map_err(|e| e.to_string())?;
+            let address = Address::from_str(&input.address)
+                .map_err(|e| e.to_string())?
+                .require_network(network)
+                .map_err(|e| e.to_string())?;
+            let previous_transaction: Transaction =
+                deserialize(&hex::decode(&input.previous_transaction).map_err(|e| e.to_string())?)
+                    .map_err(|e| format!("Invalid previous transaction of {}: {}", outpoint, e))?;
+            let derivation_path =
+                DerivationPath::from_str(&input.derivation_path).map_err(|e| e.to_string())?;
+            inputs.insert(
+                outpoint,
+                UnsignedInput::new(&outpoint, previous_transaction, address, derivation_path)?,
+            );
+        }
+
+        if let Some(input) = transaction
+            .input
+            .iter()
+            .find(|input| !inputs.contains_key(&input.previous_output))
+        {
+            return Err(format!(
+                "Missing the spent output of input {}",
+                input.previous_output
+            ));
+        }
+
+        Ok(UnsignedTransaction {
+            network,
+            transaction,
+            inputs,
+        })
+    }
+}
+
+impl Wallet {
+    /// Attach the spent outputs, the transactions that created them and their derivation
+    /// paths to a transaction built by this wallet, so that a wallet holding the seed can
+    /// sign it.
+    pub fn export_unsigned_transaction(
+        &self,
+        network: Network,
+        transaction: Transaction,
+        utxos: &BTreeMap<OutPoint, (TxOut, Address)>,
+        previous_transactions: &BTreeMap<Txid, Transaction>,
+    ) -> Result<UnsignedTransaction, String> {
+        let inputs = utxos
+            .iter()
+            .map(|(outpoint, (_, address))| {
+                let derivation_path = self
+                    .known_addresses
+                    .get(address)
+                    .ok_or(format!("Expected address {} to be in wallet", address))?;
+                let previous_transaction = previous_transactions
+                    .get(&outpoint.txid)
+                    .ok_or(format!("Missing the previous transaction of {}", outpoint))?;
+                Ok((
+                    *outpoint,
+                    UnsignedInput::new(
+                        outpoint,
+                        previous_transaction.clone(),
+                        address.clone(),
+                        derivation_path.clone(),
+                    )?,
+                ))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(UnsignedTransaction {
+            network,
+            transaction,
+            inputs,
+        })
+    }
+
+    /// Sign a transaction exported by an online copy of this wallet.
+    ///
+    /// Keys are derived from the paths in the export, and every path has to lead to the
+    /// address owning the spent output, so a tampered export can't make us sign for
+    /// anything else.
+    pub fn sign_unsigned_transaction(
+        &self,
+        unsigned: &UnsignedTransaction,
+    ) -> Result<Transaction, String> {
+        let secp = Secp256k1::new();
+        let mut transaction = unsigned.transaction.clone();
+        sign_p2pkh_inputs_with(&mut transaction, |outpoint| {
+            let input = unsigned
+                .inputs
+                .get(outpoint)
+                .ok_or(format!("Missing previous output for input {}", outpoint))?;
+            let private_key = self.private_key_at_derivation_path(&input.derivation_path)?;
+            let address = Address::p2pkh(&private_key.public_key(&secp), unsigned.network);
+            if address != input.address
+                || address.script_pubkey() != input.previous_output.script_pubkey
+            {
+                return Err(format!(
+                    "Input {} is not owned by this wallet at {}",
+                    outpoint, input.derivation_path
+                ));
+            }
+            Ok((input.previous_output.script_pubkey.clone(), private_key))
+        })?;
+        Ok(transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dash_sdk::dpp::dashcore::secp256k1::SecretKey;
+    use dash_sdk::dpp::dashcore::{PrivateKey, ScriptBuf, TxIn};
+
+    fn previous_transaction(address: &Address, value: u64) -> Transaction {
+        Transaction {
+            version: 1,
+            lock_time: 0,
+            input: vec![TxIn::default()],
+            output: vec![TxOut {
+                value,
+                script_pubkey: address.script_pubkey(),
+            }],
+            special_transaction_payload: None,
+        }
+    }
+
+    #[test]
+    fn test_input_values_come_from_the_previous_transaction() {
+        let network = Network::Testnet;
+        let private_key = PrivateKey::new(SecretKey::from_slice(&[7; 32]).unwrap(), network);
+        let address = Address::p2pkh(&private_key.public_key(&Secp256k1::new()), network);
+        let derivation_path = DerivationPath::from_str("m/44'/1'/0'/0/0").unwrap();
+        let funding = previous_transaction(&address, 50_000_000);
+        let outpoint = OutPoint::new(funding.txid(), 0);
+
+        let input = UnsignedInput::new(
+            &outpoint,
+            funding.clone(),
+            address.clone(),
+            derivation_path.clone(),
+        )
+        .unwrap();
+        assert_eq!(input.previous_output().value, 50_000_000);
+
+        // A transaction claiming a bigger value for the same output doesn't hash to its txid
+        let inflated = previous_transaction(&address, 60_000_000);
+        assert!(
+            UnsignedInput::new(
+                &outpoint,
+                inflated,
+                address.clone(),
+                derivation_path.clone()
+            )
+            .is_err()
+        );
+        assert!(
+            UnsignedInput::new(
+                &OutPoint::new(funding.txid(), 1),
+                funding.clone(),
+                address,
+                derivation_path
+            )
+            .is_err()
+        );
+
+        let unsigned = UnsignedTransaction {
+            network,
+            transaction: Transaction {
+                version: 1,
+                lock_time: 0,
+                input: vec![TxIn {
+                    previous_output: outpoint,
+                    ..Default::default()
+                }],
+                output: vec![TxOut {
+                    value: 49_990_000,
+                    script_pubkey: ScriptBuf::new_op_return(&[]),
+                }],
+                special_transaction_payload: None,
+            },
+            inputs: BTreeMap::from([(outpoint, input)]),
+        };
+        assert_eq!(unsigned.fee(), 10_000);
+        let json = unsigned.to_json().unwrap();
+        assert_eq!(UnsignedTransaction::from_json(&json).unwrap(), unsigned);
+    }
+}
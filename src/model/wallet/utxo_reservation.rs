@@ -0,0 +1,108 @@
+//! Unspent outputs reserved by transactions that are still pending, so concurrent tasks and
+//! refreshes can't hand the same coins to another transaction.
+
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use chrono::Utc;
+use dash_sdk::dpp::dashcore::{OutPoint, Txid};
+use std::collections::HashSet;
+
+/// Seconds after which a reservation lapses and its output can be spent again. Long enough
+/// for an exported transaction to be signed offline.
+pub const UTXO_RESERVATION_TIMEOUT_SECS: i64 = 30 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtxoReservation {
+    /// Transaction the output was taken for
+    pub pending_txid: Txid,
+    /// Unix timestamp of the reservation
+    pub reserved_at: i64,
+}
+
+impl UtxoReservation {
+    pub fn is_expired(&self, now: i64) -> bool {
+        now - self.reserved_at >= UTXO_RESERVATION_TIMEOUT_SECS
+    }
+}
+
+impl Wallet {
+    /// The pending transaction `outpoint` is reserved for, unless the reservation lapsed.
+    pub fn utxo_reserved_for(&self, outpoint: &OutPoint) -> Option<Txid> {
+        let now = Utc::now().timestamp();
+        self.utxo_reservations
+            .get(outpoint)
+            .filter(|reservation| !reservation.is_expired(now))
+            .map(|reservation| reservation.pending_txid)
+    }
+
+    /// Reserves `outpoints` for the pending transaction `pending_txid`. Reservations are
+    /// persisted if `save` is set, so they outlive a restart.
+    pub fn reserve_utxos(
+        &mut self,
+        outpoints: impl IntoIterator<Item = OutPoint>,
+        pending_txid: Txid,
+        save: Option<&AppContext>,
+    ) -> Result<(), String> {
+        let reservation = UtxoReservation {
+            pending_txid,
+            reserved_at: Utc::now().timestamp(),
+        };
+        let seed_hash = self.seed_hash();
+        for outpoint in outpoints {
+            if let Some(app_context) = save {
+                app_context
+                    .db
+                    .reserve_utxo(&outpoint, &seed_hash, &reservation, app_context.network)
+                    .map_err(|e| e.to_string())?;
+            }
+            self.utxo_reservations.insert(outpoint, reservation);
+        }
+        Ok(())
+    }
+
+    /// Releases the outputs reserved for `pending_txid`, e.g. after its broadcast failed.
+    pub fn release_utxo_reservations(
+        &mut self,
+        pending_txid: &Txid,
+        save: Option<&AppContext>,
+    ) -> Result<(), String> {
+        if let Some(app_context) = save {
+            app_context
+                .db
+                .release_utxo_reservations(pending_txid, app_context.network)
+                .map_err(|e| e.to_string())?;
+        }
+        self.utxo_reservations
+            .retain(|_, reservation| &reservation.pending_txid != pending_txid);
+        Ok(())
+    }
+
+    /// Forgets lapsed reservations and those of outputs that are no longer in `unspent`,
+    /// as the pending transaction spent them.
+    pub(super) fn prune_utxo_reservations(
+        &mut self,
+        unspent: &HashSet<OutPoint>,
+        save: Option<&AppContext>,
+    ) -> Result<(), String> {
+        let now = Utc::now().timestamp();
+        let released: Vec<OutPoint> = self
+            .utxo_reservations
+            .iter()
+            .filter(|(outpoint, reservation)| {
+                reservation.is_expired(now) || !unspent.contains(outpoint)
+            })
+            .map(|(outpoint, _)| *outpoint)
+            .collect();
+
+        for outpoint in released {
+            if let Some(app_context) = save {
+                app_context
+                    .db
+                    .release_utxo_reservation(&outpoint, app_context.network)
+                    .map_err(|e| e.to_string())?;
+            }
+            self.utxo_reservations.remove(&outpoint);
+        }
+        Ok(())
+    }
+}
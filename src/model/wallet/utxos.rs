@@ -1,120 +1,166 @@
+use crate::chain_data::ChainDataBackend;
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
-use dash_sdk::dashcore_rpc::json::ListUnspentResultEntry;
-use dash_sdk::dashcore_rpc::{Client, RpcApi};
+use crate::model::wallet::coin_selection::{
+    CoinSelectionStrategy, MIN_CHANGE_VALUE, SpendableUtxo,
+};
+use crate::model::wallet::fee_estimation::FeeEstimator;
 use dash_sdk::dpp::dashcore::{Address, Network, OutPoint, TxOut};
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 impl Wallet {
+    /// Remove UTXOs of `account` covering `amount` plus the estimated fee from the wallet,
+    /// picked by `strategy`.
+    ///
+    /// Returns the taken UTXOs, the change, if any, and the fee of the resulting
+    /// transaction. See [`Wallet::select_unspent_utxos_for`] for how they are chosen.
+    /// A refresh puts them back, so callers reserve them with [`Wallet::reserve_utxos`]
+    /// before releasing the wallet lock.
     #[allow(clippy::type_complexity)]
     pub fn take_unspent_utxos_for(
         &mut self,
         amount: u64,
-        fee: u64,
+        fee_estimator: &FeeEstimator,
         allow_take_fee_from_amount: bool,
-    ) -> Option<(BTreeMap<OutPoint, (TxOut, Address)>, Option<u64>)> {
-        // Ensure UTXOs exist
-        let utxos = &mut self.utxos;
-
-        let mut required: i64 = (amount + fee) as i64;
+        account: u32,
+        strategy: CoinSelectionStrategy,
+    ) -> Option<(BTreeMap<OutPoint, (TxOut, Address)>, Option<u64>, u64)> {
+        let (selected, change_option, fee) = self.select_unspent_utxos_for(
+            amount,
+            fee_estimator,
+            allow_take_fee_from_amount,
+            account,
+            strategy,
+        )?;
+
+        // Remove the collected UTXOs from the wallet's UTXO map
         let mut taken_utxos = BTreeMap::new();
-        let mut utxos_to_remove = Vec::new();
-
-        // Iterate over the UTXOs to collect enough to cover the required amount
-        for (address, outpoints) in utxos.iter_mut() {
-            for (outpoint, tx_out) in outpoints.iter() {
-                if required <= 0 {
-                    break;
+        for utxo in selected {
+            if let Some(outpoints) = self.utxos.get_mut(&utxo.address) {
+                outpoints.remove(&utxo.outpoint);
+                if outpoints.is_empty() {
+                    self.utxos.remove(&utxo.address);
                 }
-
-                // Add the UTXO to the result
-                taken_utxos.insert(*outpoint, (tx_out.clone(), address.clone()));
-
-                required -= tx_out.value as i64;
-                utxos_to_remove.push((address.clone(), *outpoint));
             }
+            taken_utxos.insert(utxo.outpoint, (utxo.tx_out, utxo.address));
         }
 
-        // If not enough UTXOs were found, try to adjust if allowed
-        if required > 0 {
-            if allow_take_fee_from_amount {
-                let total_collected = (amount + fee) as i64 - required;
-                if total_collected >= amount as i64 {
-                    // We have enough to cover the amount, but not the fee
-                    // So we can reduce the amount by the missing fee
-                    let missing_fee = required; // required > 0
-                    let adjusted_amount = amount as i64 - missing_fee;
-                    if adjusted_amount <= 0 {
-                        // Cannot adjust amount to cover missing fee
-                        return None;
-                    }
-                    // Remove UTXOs from wallet
-                    for (address, outpoint) in utxos_to_remove {
-                        if let Some(outpoints) = utxos.get_mut(&address) {
-                            outpoints.remove(&outpoint);
-                            if outpoints.is_empty() {
-                                utxos.remove(&address);
-                            }
-                        }
-                    }
-                    // Return collected UTXOs and None for change
-                    Some((taken_utxos, None))
-                } else {
-                    // Not enough to cover amount even after adjusting
-                    None
-                }
-            } else {
-                // Not enough UTXOs and not allowed to take fee from amount
-                None
-            }
-        } else {
-            // Remove the collected UTXOs from the wallet's UTXO map
-            for (address, outpoint) in utxos_to_remove {
-                if let Some(outpoints) = utxos.get_mut(&address) {
-                    outpoints.remove(&outpoint);
-                    if outpoints.is_empty() {
-                        utxos.remove(&address);
+        Some((taken_utxos, change_option, fee))
+    }
+
+    /// Pick UTXOs of `account` covering `amount` plus the fee for the inputs they add,
+    /// without removing them from the wallet.
+    ///
+    /// Change below [`MIN_CHANGE_VALUE`] is left to the fee. If the wallet cannot cover the
+    /// fee on top of the amount and `allow_take_fee_from_amount` is set, all UTXOs are
+    /// selected and no change is returned, so the caller can take the fee from the amount.
+    pub fn select_unspent_utxos_for(
+        &self,
+        amount: u64,
+        fee_estimator: &FeeEstimator,
+        allow_take_fee_from_amount: bool,
+        account: u32,
+        strategy: CoinSelectionStrategy,
+    ) -> Option<(Vec<SpendableUtxo>, Option<u64>, u64)> {
+        let candidates = self.account_utxos(account);
+
+        // Every input adds to the fee, so select again until the selection pays for itself.
+        // The target only ever grows, so this ends once the wallet can no longer cover it.
+        let mut fee = fee_estimator.fee(1, true);
+        let selected = loop {
+            match strategy.selector().select(&candidates, amount + fee) {
+                Some(selected) => {
+                    let required_fee = fee_estimator.fee(selected.len(), true);
+                    if required_fee <= fee {
+                        break selected;
                     }
+                    fee = required_fee;
                 }
+                None => break candidates,
             }
-            // Calculate change amount
-            let total_input = (amount + fee) as i64 - required; // total input collected
-            let change = total_input as u64 - amount - fee;
+        };
 
-            // If change is zero, return None
-            let change_option = if change > 0 { Some(change) } else { None };
+        let total_input: u64 = selected.iter().map(|utxo| utxo.value()).sum();
 
-            // Return the collected UTXOs and the change amount
-            Some((taken_utxos, change_option))
+        let fee_with_change = fee_estimator.fee(selected.len(), true);
+        if total_input >= amount + fee_with_change + MIN_CHANGE_VALUE {
+            let change = total_input - amount - fee_with_change;
+            return Some((selected, Some(change), fee_with_change));
         }
+
+        let fee_without_change = fee_estimator.fee(selected.len(), false);
+        if total_input >= amount + fee_without_change {
+            return Some((selected, None, fee_without_change));
+        }
+
+        // We have enough to cover the amount, but not the fee
+        // So the caller can reduce the amount by the missing fee
+        if allow_take_fee_from_amount && total_input >= amount && total_input > fee_without_change {
+            return Some((selected, None, fee_without_change));
+        }
+
+        None
     }
 
+    /// Estimated fee of spending `amount` from `account`, or `None` if the account can't
+    /// fund it.
+    pub fn estimate_fee_for(
+        &self,
+        amount: u64,
+        fee_estimator: &FeeEstimator,
+        allow_take_fee_from_amount: bool,
+        account: u32,
+        strategy: CoinSelectionStrategy,
+    ) -> Option<u64> {
+        self.select_unspent_utxos_for(
+            amount,
+            fee_estimator,
+            allow_take_fee_from_amount,
+            account,
+            strategy,
+        )
+        .map(|(_, _, fee)| fee)
+    }
+
+    /// All UTXOs currently held by the wallet that can be spent, in a deterministic order.
+    pub fn spendable_utxos(&self) -> Vec<SpendableUtxo> {
+        let mut utxos: Vec<SpendableUtxo> = self
+            .utxos
+            .iter()
+            .flat_map(|(address, outpoints)| {
+                outpoints
+                    .iter()
+                    .filter(|(outpoint, _)| self.is_utxo_spendable(outpoint))
+                    .map(|(outpoint, tx_out)| SpendableUtxo {
+                        outpoint: *outpoint,
+                        tx_out: tx_out.clone(),
+                        address: address.clone(),
+                    })
+            })
+            .collect();
+        utxos.sort_by_key(|utxo| utxo.outpoint);
+        utxos
+    }
+
+    /// Replace the wallet's UTXOs with those the chain reports for its known addresses,
+    /// updating the database if `save` is set. Reservations of outputs that were spent or
+    /// that lapsed are released.
     pub fn reload_utxos(
         &mut self,
-        core_client: &Client,
+        chain_data: &dyn ChainDataBackend,
         network: Network,
         save: Option<&AppContext>,
     ) -> Result<HashMap<OutPoint, TxOut>, String> {
         // Collect the addresses for which we want to load UTXOs.
-        let addresses: Vec<_> = self.known_addresses.keys().collect();
+        let addresses: Vec<_> = self.known_addresses.keys().cloned().collect();
         if tracing::enabled!(tracing::Level::TRACE) {
             for addr in addresses.iter() {
-                let (net, payload) = (*addr).clone().into_parts();
+                let (net, payload) = addr.clone().into_parts();
                 tracing::trace!(net=net.to_string(),payload=?payload , "Address to load UTXOs for");
             }
         }
 
-        // Calling list_unspent with an empty addresses vector will return all UTXOs,
-        // which is not what we want here. Instead, we handle the empty case explicitly.
-        let utxos: Vec<ListUnspentResultEntry> = if addresses.is_empty() {
-            Vec::new()
-        } else {
-            core_client
-                .list_unspent(None, None, Some(&addresses), Some(false), None)
-                .map_err(|e| e.to_string())?
-        };
-
-        // Use the RPC client to list unspent outputs.
+        let utxos = chain_data.list_unspent(&addresses)?;
 
         // Initialize the HashMap to store the new UTXOs.
         let mut new_utxo_map = HashMap::new();
@@ -122,15 +168,16 @@ impl Wallet {
         let mut new_outpoints = HashSet::new();
 
         // Iterate over the retrieved UTXOs and populate the HashMaps.
-        for utxo in utxos {
-            let outpoint = OutPoint::new(utxo.txid, utxo.vout);
-            let tx_out = TxOut {
-                value: utxo.amount.to_sat(),
-                script_pubkey: utxo.script_pub_key.clone(),
-            };
+        let mut new_statuses = HashMap::new();
+        for (outpoint, tx_out, status) in utxos {
             new_utxo_map.insert(outpoint, tx_out);
             new_outpoints.insert(outpoint);
+            new_statuses.insert(outpoint, status);
         }
+        self.utxo_statuses = new_statuses;
+        // Outputs still reported as unspent keep their reservation, so a pending transaction
+        // the chain doesn't know about yet can't have its inputs spent twice
+        self.prune_utxo_reservations(&new_outpoints, save)?;
 
         // Collect current UTXOs into a set for comparison
         let mut old_outpoints = HashSet::new();
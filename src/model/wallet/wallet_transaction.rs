@@ -0,0 +1,64 @@
+use dash_sdk::dpp::dashcore::Txid;
+use dash_sdk::platform::Identifier;
+use std::fmt;
+
+/// Which way funds moved in a wallet transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransactionDirection {
+    Incoming,
+    Outgoing,
+    /// Funds locked on Core to be credited to a Platform identity.
+    AssetLock,
+}
+
+impl TransactionDirection {
+    pub const ALL: [TransactionDirection; 3] = [
+        TransactionDirection::Incoming,
+        TransactionDirection::Outgoing,
+        TransactionDirection::AssetLock,
+    ];
+
+    pub fn to_int(self) -> u8 {
+        match self {
+            TransactionDirection::Incoming => 0,
+            TransactionDirection::Outgoing => 1,
+            TransactionDirection::AssetLock => 2,
+        }
+    }
+
+    pub fn from_int(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(TransactionDirection::Incoming),
+            1 => Some(TransactionDirection::Outgoing),
+            2 => Some(TransactionDirection::AssetLock),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TransactionDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            TransactionDirection::Incoming => "Received",
+            TransactionDirection::Outgoing => "Sent",
+            TransactionDirection::AssetLock => "Asset Lock",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// A transaction that moved funds in or out of a wallet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletTransaction {
+    pub txid: Txid,
+    pub direction: TransactionDirection,
+    /// Duffs that entered or left the wallet, not counting change and fee.
+    pub amount: u64,
+    /// Only known for transactions built by this wallet.
+    pub fee: Option<u64>,
+    pub confirmations: u32,
+    /// The identity registered or topped up by an asset lock.
+    pub identity_id: Option<Identifier>,
+    /// Unix time in seconds when the transaction was first seen.
+    pub timestamp: u64,
+}
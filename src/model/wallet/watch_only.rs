@@ -0,0 +1,58 @@
+use super::{ClosedKeyItem, Wallet, WalletSeed};
+use crate::model::wallet::accounts::WalletAccount;
+use dash_sdk::dashcore_rpc::dashcore::bip32::ExtendedPubKey;
+use dash_sdk::dpp::dashcore::Network;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+/// Depth of a BIP44 account key, `m/44'/coin_type'/account'`.
+const BIP44_ACCOUNT_DEPTH: u8 = 3;
+
+impl Wallet {
+    /// A watch-only wallet for the BIP44 account behind `extended_public_key`.
+    ///
+    /// The key has to be an account-level key (`m/44'/coin_type'/account'`) for `network`.
+    pub fn new_watch_only(
+        extended_public_key: &str,
+        network: Network,
+        alias: Option<String>,
+    ) -> Result<Self, String> {
+        let extended_public_key = ExtendedPubKey::from_str(extended_public_key.trim())
+            .map_err(|e| format!("Invalid extended public key: {}", e))?;
+
+        // xpubs only distinguish mainnet from the test networks
+        let expected_mainnet = network == Network::Dash;
+        if (extended_public_key.network == Network::Dash) != expected_mainnet {
+            return Err(format!(
+                "The extended public key is not for the {} network",
+                network
+            ));
+        }
+
+        if extended_public_key.depth != BIP44_ACCOUNT_DEPTH {
+            return Err(
+                "Expected a BIP44 account extended public key (m/44'/coin_type'/account')"
+                    .to_string(),
+            );
+        }
+
+        Ok(Wallet {
+            wallet_seed: WalletSeed::WatchOnly(ClosedKeyItem::compute_seed_hash(
+                &extended_public_key.encode(),
+            )),
+            uses_password: false,
+            master_bip44_ecdsa_extended_public_key: extended_public_key,
+            accounts: WalletAccount::default_accounts(extended_public_key),
+            address_total_received: BTreeMap::new(),
+            known_addresses: BTreeMap::new(),
+            watched_addresses: BTreeMap::new(),
+            unused_asset_locks: vec![],
+            alias,
+            identities: HashMap::new(),
+            utxos: HashMap::new(),
+            utxo_statuses: HashMap::new(),
+            utxo_reservations: HashMap::new(),
+            is_main: true,
+        })
+    }
+}
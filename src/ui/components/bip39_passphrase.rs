@@ -0,0 +1,82 @@
+use egui::{Color32, RichText, Ui};
+use zeroize::Zeroize;
+
+/// Optional BIP39 passphrase (the "25th word") for wallet creation and import.
+///
+/// The same seed phrase with a different passphrase is a different wallet, so the passphrase
+/// has to be typed twice. Only the hint is ever stored.
+#[derive(Default)]
+pub struct Bip39PassphraseInput {
+    use_passphrase: bool,
+    passphrase: String,
+    passphrase_confirmation: String,
+    hint: String,
+}
+
+impl Drop for Bip39PassphraseInput {
+    fn drop(&mut self) {
+        self.passphrase.zeroize();
+        self.passphrase_confirmation.zeroize();
+    }
+}
+
+impl Bip39PassphraseInput {
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.checkbox(&mut self.use_passphrase, "Use a BIP39 passphrase");
+        ui.label(
+            RichText::new("A passphrase turns the seed phrase into a different wallet. It is never saved: without it, the funds of this wallet can not be recovered from the seed phrase.")
+                .color(Color32::GRAY)
+                .size(12.0),
+        );
+
+        if !self.use_passphrase {
+            return;
+        }
+
+        ui.add_space(8.0);
+        egui::Grid::new("bip39_passphrase_grid")
+            .num_columns(2)
+            .spacing((10.0, 5.0))
+            .show(ui, |ui| {
+                ui.label("Passphrase:");
+                ui.add(egui::TextEdit::singleline(&mut self.passphrase).password(true));
+                ui.end_row();
+
+                ui.label("Confirm Passphrase:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.passphrase_confirmation).password(true),
+                );
+                ui.end_row();
+
+                ui.label("Optional Hint:");
+                ui.text_edit_singleline(&mut self.hint);
+                ui.end_row();
+            });
+
+        if !self.passphrase_confirmation.is_empty()
+            && self.passphrase != self.passphrase_confirmation
+        {
+            ui.colored_label(Color32::DARK_RED, "The passphrases do not match");
+        }
+    }
+
+    /// The passphrase to derive the seed with, empty when none is used.
+    pub fn passphrase(&self) -> Result<&str, String> {
+        if !self.use_passphrase {
+            return Ok("");
+        }
+        if self.passphrase.is_empty() {
+            return Err("Enter a passphrase or uncheck \"Use a BIP39 passphrase\"".to_string());
+        }
+        if self.passphrase != self.passphrase_confirmation {
+            return Err("The passphrases do not match".to_string());
+        }
+        Ok(&self.passphrase)
+    }
+
+    /// The hint to store with the wallet, if a passphrase is used and a hint was given.
+    pub fn hint(&self) -> Option<String> {
+        let hint = self.hint.trim();
+        (self.use_passphrase && !hint.is_empty()).then(|| hint.to_string())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::app::AppAction;
+use crate::backend_task::BackendTask;
+use crate::backend_task::core::CoreTask;
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
+use crate::model::wallet::fee_estimation::{FeeEstimator, FeeRate};
+use egui::{Color32, Ui};
+
+/// Estimated fee of a wallet transaction that is being entered.
+///
+/// Shown every frame, so the fee rate is fetched from Core by a backend task and coin
+/// selection only runs again once the amount, account, strategy, fee rate or account balance
+/// changed.
+#[derive(Default)]
+pub struct FeeEstimate {
+    estimate: Option<(EstimateInputs, Option<u64>)>,
+    fee_rate_requested: bool,
+}
+
+#[derive(PartialEq)]
+struct EstimateInputs {
+    amount: u64,
+    fee_estimator: FeeEstimator,
+    allow_take_fee_from_amount: bool,
+    account: u32,
+    strategy: CoinSelectionStrategy,
+    balance: u64,
+}
+
+impl FeeEstimate {
+    /// Shows the fee a spend of `amount` from `account` is expected to pay, for the
+    /// transaction `fee_estimator` describes at the current fee rate.
+    #[allow(clippy::too_many_arguments)]
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        app_context: &AppContext,
+        wallet: &Wallet,
+        amount: u64,
+        fee_estimator: impl FnOnce(FeeRate) -> FeeEstimator,
+        allow_take_fee_from_amount: bool,
+        account: u32,
+        strategy: CoinSelectionStrategy,
+    ) -> AppAction {
+        let Some(fee_rate) = app_context.cached_fee_rate() else {
+            ui.horizontal(|ui| {
+                ui.add(egui::widgets::Spinner::default());
+                ui.label("Estimating fee...");
+            });
+            if self.fee_rate_requested {
+                return AppAction::None;
+            }
+            self.fee_rate_requested = true;
+            return AppAction::BackendTask(BackendTask::CoreTask(CoreTask::RefreshFeeRate));
+        };
+        self.fee_rate_requested = false;
+
+        let inputs = EstimateInputs {
+            amount,
+            fee_estimator: fee_estimator(fee_rate),
+            allow_take_fee_from_amount,
+            account,
+            strategy,
+            balance: wallet.account_balance(account),
+        };
+        let fee = match &self.estimate {
+            Some((estimated_inputs, fee)) if *estimated_inputs == inputs => *fee,
+            _ => {
+                let fee = wallet.estimate_fee_for(
+                    amount,
+                    &inputs.fee_estimator,
+                    allow_take_fee_from_amount,
+                    account,
+                    strategy,
+                );
+                self.estimate = Some((inputs, fee));
+                fee
+            }
+        };
+
+        match fee {
+            Some(fee) => {
+                ui.label(format!(
+                    "Estimated fee: {:.8} DASH ({})",
+                    fee as f64 * 1e-8,
+                    fee_rate
+                ));
+            }
+            None => {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    "Insufficient funds to cover this amount and the fee",
+                );
+            }
+        }
+        AppAction::None
+    }
+}
@@ -0,0 +1,153 @@
+use crate::app::AppAction;
+use crate::backend_task::identity::IdentityTask;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
+use crate::model::dpns_name::{DpnsNameResolution, dpns_label};
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use dash_sdk::platform::Identifier;
+use egui::{Color32, Ui};
+
+/// Input for the identity something is sent to. Takes an identity ID in base58 or hex, or a
+/// DPNS username such as `alice.dash`, which is looked up on Platform.
+///
+/// Screens using it have to pass it their backend task results, see
+/// [`RecipientInput::display_task_result`].
+#[derive(Default)]
+pub struct RecipientInput {
+    input: String,
+    resolution: Option<Result<DpnsNameResolution, String>>,
+    resolving: bool,
+}
+
+impl RecipientInput {
+    pub fn show(&mut self, ui: &mut Ui, label: &str) -> AppAction {
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            ui.label(label);
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.input)
+                    .hint_text("Identity ID or name.dash")
+                    .desired_width(380.0),
+            );
+            if response.changed() {
+                self.resolution = None;
+                self.resolving = false;
+            }
+            let name = self.name_to_resolve();
+            let submitted = response.lost_focus()
+                && self.resolution.is_none()
+                && !self.resolving
+                && name.is_some();
+            let look_up_clicked = ui
+                .add_enabled(
+                    name.is_some() && !self.resolving,
+                    egui::Button::new("Look up"),
+                )
+                .clicked();
+            if let Some(name) = name.filter(|_| submitted || look_up_clicked) {
+                self.resolving = true;
+                self.resolution = None;
+                action = AppAction::BackendTask(BackendTask::IdentityTask(
+                    IdentityTask::ResolveDpnsName(name),
+                ));
+            }
+        });
+
+        if self.resolving {
+            ui.horizontal(|ui| {
+                ui.add(egui::widgets::Spinner::default());
+                ui.label("Looking up name...");
+            });
+        }
+        match &self.resolution {
+            Some(Ok(resolution)) => {
+                ui.label(format!(
+                    "{} is identity {}",
+                    resolution.name(),
+                    resolution.identity_id.to_string(Encoding::Base58)
+                ));
+                if !resolution.other_names.is_empty() {
+                    ui.label(format!(
+                        "Also known as: {}",
+                        resolution.other_names.join(", ")
+                    ));
+                }
+                if let Some(warning) = resolution.homograph_warning() {
+                    ui.colored_label(Color32::DARK_RED, format!("Warning: {}", warning));
+                }
+            }
+            Some(Err(e)) => {
+                ui.colored_label(Color32::DARK_RED, e);
+            }
+            None => {}
+        }
+        action
+    }
+
+    /// The identity entered, or the one the entered name was resolved to.
+    pub fn identifier(&self) -> Result<Identifier, String> {
+        let input = self.input.trim();
+        if let Ok(identifier) =
+            Identifier::from_string_try_encodings(input, &[Encoding::Base58, Encoding::Hex])
+        {
+            return Ok(identifier);
+        }
+        if dpns_label(input).is_none() {
+            return Err("Invalid identifier".to_string());
+        }
+        match &self.resolution {
+            Some(Ok(resolution)) if resolution.query == input => Ok(resolution.identity_id),
+            Some(Err(e)) => Err(e.clone()),
+            _ => Err(format!("The name {} has not been looked up yet", input)),
+        }
+    }
+
+    /// The recipient as entered, with the identity ID if a name was entered.
+    pub fn description(&self) -> String {
+        match &self.resolution {
+            Some(Ok(resolution)) if resolution.query == self.input.trim() => format!(
+                "{} ({})",
+                resolution.name(),
+                resolution.identity_id.to_string(Encoding::Base58)
+            ),
+            _ => self.input.trim().to_string(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.input.trim().is_empty()
+    }
+
+    pub fn set_identity_id(&mut self, identity_id: Identifier) {
+        self.input = identity_id.to_string(Encoding::Base58);
+        self.resolution = None;
+        self.resolving = false;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Takes the result of a name lookup, successful or not, for the current input. Returns
+    /// whether the result was used.
+    pub fn display_task_result(&mut self, result: &BackendTaskSuccessResult) -> bool {
+        match result {
+            BackendTaskSuccessResult::ResolvedDpnsName(name, resolution) => {
+                if name == self.input.trim() {
+                    self.resolving = false;
+                    self.resolution = Some(resolution.clone());
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn name_to_resolve(&self) -> Option<String> {
+        let input = self.input.trim();
+        if Identifier::from_string_try_encodings(input, &[Encoding::Base58, Encoding::Hex]).is_ok()
+        {
+            return None;
+        }
+        dpns_label(input).map(|_| input.to_string())
+    }
+}
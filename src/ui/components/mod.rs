@@ -1,7 +1,10 @@
+pub mod bip39_passphrase;
 pub mod clickable_collapsing_header;
 pub mod contract_chooser_panel;
 pub mod dpns_subscreen_chooser_panel;
 pub mod entropy_grid;
+pub mod fee_estimate;
+pub mod identity_recipient_input;
 pub mod left_panel;
 pub mod left_wallet_panel;
 pub mod styled;
@@ -12,6 +12,7 @@ pub enum ToolsSubscreen {
     ProofViewer,
     ContractViewer,
     PlatformInfo,
+    MessageVerifier,
 }
 
 impl ToolsSubscreen {
@@ -23,6 +24,7 @@ impl ToolsSubscreen {
             Self::DocumentViewer => "Document deserializer",
             Self::ContractViewer => "Contract deserializer",
             Self::PlatformInfo => "Platform info",
+            Self::MessageVerifier => "Verify message",
         }
     }
 }
@@ -38,6 +40,7 @@ pub fn add_tools_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext
         ToolsSubscreen::DocumentViewer,
         ToolsSubscreen::ContractViewer,
         ToolsSubscreen::PlatformInfo,
+        ToolsSubscreen::MessageVerifier,
     ];
 
     let active_screen = match app_context.get_settings() {
@@ -54,6 +57,9 @@ pub fn add_tools_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext
                 ToolsSubscreen::ContractViewer
             }
             ui::RootScreenType::RootScreenToolsPlatformInfoScreen => ToolsSubscreen::PlatformInfo,
+            ui::RootScreenType::RootScreenToolsMessageVerifierScreen => {
+                ToolsSubscreen::MessageVerifier
+            }
             _ => ToolsSubscreen::ProofLog,
         },
         _ => ToolsSubscreen::ProofLog, // Fallback to Active screen if settings unavailable
@@ -148,6 +154,11 @@ pub fn add_tools_subscreen_chooser_panel(ctx: &Context, app_context: &AppContext
                                     RootScreenType::RootScreenToolsPlatformInfoScreen,
                                 )
                             }
+                            ToolsSubscreen::MessageVerifier => {
+                                action = AppAction::SetMainScreen(
+                                    RootScreenType::RootScreenToolsMessageVerifierScreen,
+                                )
+                            }
                         }
                             }
 
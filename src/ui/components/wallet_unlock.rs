@@ -21,7 +21,10 @@ pub trait ScreenWithWalletUnlock {
     fn should_ask_for_password(&mut self) -> bool {
         if let Some(wallet_guard) = self.selected_wallet_ref().clone() {
             let mut wallet = wallet_guard.write().unwrap();
-            if !wallet.uses_password {
+            if wallet.is_watch_only() {
+                // There is nothing to unlock, signing is never possible
+                true
+            } else if !wallet.uses_password {
                 if let Err(e) = wallet.wallet_seed.open_no_password() {
                     self.set_error_message(Some(e));
                 }
@@ -46,6 +49,14 @@ pub trait ScreenWithWalletUnlock {
         if let Some(wallet_guard) = self.selected_wallet_ref().clone() {
             let mut wallet = wallet_guard.write().unwrap();
 
+            if wallet.is_watch_only() {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    "This is a watch-only wallet, it can not sign transactions.",
+                );
+                return false;
+            }
+
             // Only render the unlock prompt if the wallet requires a password and is locked
             if wallet.uses_password && !wallet.is_open() {
                 if let Some(alias) = &wallet.alias {
@@ -6,6 +6,7 @@ use crate::model::qualified_contract::QualifiedContract;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
 use crate::ui::ScreenLike;
+use crate::ui::components::identity_recipient_input::RecipientInput;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::{island_central_panel, styled_text_edit_singleline};
 use crate::ui::components::top_panel::add_top_panel;
@@ -114,7 +115,7 @@ pub struct DocumentActionScreen {
 
     // Transfer-specific
     pub identities_map: HashMap<Identifier, QualifiedIdentity>,
-    pub recipient_id_input: String,
+    pub recipient_input: RecipientInput,
 
     // Delete-specific
     pub fetched_documents: IndexMap<Identifier, Option<Document>>,
@@ -162,7 +163,7 @@ impl DocumentActionScreen {
             original_doc: None,
             price_input: String::new(),
             identities_map,
-            recipient_id_input: String::new(),
+            recipient_input: RecipientInput::default(),
             fetched_documents: IndexMap::new(),
         }
     }
@@ -184,7 +185,7 @@ impl DocumentActionScreen {
         self.fetched_price = None;
         self.original_doc = None;
         self.price_input.clear();
-        self.recipient_id_input.clear();
+        self.recipient_input.clear();
         self.fetched_documents.clear();
     }
 
@@ -593,20 +594,14 @@ impl DocumentActionScreen {
             ));
         });
 
-        ui.horizontal(|ui| {
-            ui.label("Recipient Identity:");
-            let dark_mode = ui.ctx().style().visuals.dark_mode;
-            ui.add(styled_text_edit_singleline(
-                &mut self.recipient_id_input,
-                dark_mode,
-            ));
-        });
+        let mut action = self.recipient_input.show(ui, "Recipient Identity:");
 
         ui.add_space(10.0);
         if let Some(doc_type) = &self.selected_document_type {
             self.render_token_cost_info(ui, &doc_type.clone());
         }
-        self.render_broadcast_button(ui)
+        action |= self.render_broadcast_button(ui);
+        action
     }
 
     fn ui_field_inputs(
@@ -1045,8 +1040,7 @@ impl DocumentActionScreen {
     fn create_transfer_task(&self) -> BackendTask {
         let document_id =
             Identifier::from_string(&self.document_id_input, Encoding::Base58).unwrap_or_default();
-        let recipient_id =
-            Identifier::from_string(&self.recipient_id_input, Encoding::Base58).unwrap_or_default();
+        let recipient_id = self.recipient_input.identifier().unwrap_or_default();
 
         let doc_type = self.selected_document_type.as_ref().unwrap();
 
@@ -1444,7 +1438,7 @@ impl DocumentActionScreen {
                 !self.document_id_input.is_empty() && !self.price_input.is_empty()
             }
             DocumentActionType::Transfer => {
-                !self.document_id_input.is_empty() && !self.recipient_id_input.is_empty()
+                !self.document_id_input.is_empty() && self.recipient_input.identifier().is_ok()
             }
         }
     }
@@ -1496,7 +1490,7 @@ impl ScreenLike for DocumentActionScreen {
         // Backend messages are handled via display_message
     }
 
-    fn display_message(&mut self, message: &str, _message_type: crate::ui::MessageType) {
+    fn display_message(&mut self, message: &str, message_type: crate::ui::MessageType) {
         if message.contains("Document deleted successfully")
             || message.contains("Document replaced successfully")
             || message.contains("Document transferred successfully")
@@ -1511,6 +1505,9 @@ impl ScreenLike for DocumentActionScreen {
     }
 
     fn display_task_result(&mut self, result: crate::ui::BackendTaskSuccessResult) {
+        if self.recipient_input.display_task_result(&result) {
+            return;
+        }
         match result {
             BackendTaskSuccessResult::BroadcastedDocument(_) => {
                 self.broadcast_status = BroadcastStatus::Broadcasted;
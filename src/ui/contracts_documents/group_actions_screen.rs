@@ -368,7 +368,7 @@ impl GroupActionsScreen {
                 let mut freeze_screen =
                     FreezeTokensScreen::new(identity_token_info, &self.app_context);
                 freeze_screen.group_action_id = Some(action_id);
-                freeze_screen.freeze_identity_id = identifier.to_string(Encoding::Base58);
+                freeze_screen.freeze_identity.set_identity_id(*identifier);
                 freeze_screen.public_note = note_opt.clone();
                 *action |= AppAction::AddScreen(Screen::FreezeTokensScreen(freeze_screen));
             }
@@ -3,7 +3,12 @@ use std::sync::Arc;
 use crate::{
     app::AppAction,
     context::AppContext,
-    model::{qualified_contract::QualifiedContract, qualified_identity::QualifiedIdentity},
+    model::{
+        payment_uri::find_payment_uri,
+        qualified_contract::QualifiedContract,
+        qualified_identity::QualifiedIdentity,
+        wallet::{Wallet, coin_selection::CoinSelectionStrategy},
+    },
 };
 use dash_sdk::{
     dpp::{
@@ -20,8 +25,9 @@ use dash_sdk::{
     },
     platform::{Identifier, IdentityPublicKey},
 };
-use egui::{Color32, ComboBox, Response, Ui};
+use egui::{Color32, ComboBox, Context, Response, Ui};
 
+use super::identities::funding_common::{copy_to_clipboard, generate_qr_code_image};
 use super::tokens::tokens_screen::IdentityTokenInfo;
 
 /// Helper function to create a styled info icon button
@@ -54,6 +60,143 @@ pub fn info_icon_button(ui: &mut egui::Ui, hover_text: &str) -> Response {
     response.on_hover_text(hover_text)
 }
 
+/// Combo box for picking how the inputs of a wallet transaction are selected.
+pub fn render_coin_selection_chooser(
+    ui: &mut Ui,
+    id_salt: &str,
+    strategy: &mut CoinSelectionStrategy,
+) -> Response {
+    ui.horizontal(|ui| {
+        ui.label("Coin selection:");
+        ComboBox::from_id_salt(id_salt)
+            .selected_text(strategy.to_string())
+            .show_ui(ui, |ui| {
+                for option in CoinSelectionStrategy::ALL {
+                    ui.selectable_value(strategy, option, option.to_string())
+                        .on_hover_text(option.description());
+                }
+            });
+        info_icon_button(ui, strategy.description());
+    })
+    .response
+}
+
+/// Combo box for picking the BIP44 account that funds a wallet transaction. Hidden
+/// accounts are not offered, and an account the wallet doesn't have falls back to account 0.
+pub fn render_account_chooser(
+    ui: &mut Ui,
+    id_salt: &str,
+    wallet: &Wallet,
+    account: &mut u32,
+) -> Response {
+    if !wallet.accounts.contains_key(account) {
+        *account = 0;
+    }
+    let selected_text = wallet
+        .accounts
+        .get(account)
+        .map(|wallet_account| wallet_account.display_name())
+        .unwrap_or_else(|| format!("Account {}", account));
+    ui.horizontal(|ui| {
+        ui.label("Account:");
+        ComboBox::from_id_salt(id_salt)
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                for wallet_account in wallet.visible_accounts() {
+                    let balance = wallet.account_balance(wallet_account.index);
+                    ui.selectable_value(
+                        account,
+                        wallet_account.index,
+                        format!(
+                            "{} ({:.8} DASH)",
+                            wallet_account.display_name(),
+                            balance as f64 * 1e-8
+                        ),
+                    );
+                }
+            });
+    })
+    .response
+}
+
+/// Shows a transaction exported for another DET instance, with buttons to copy it, save it
+/// to a file or show it as a QR code.
+///
+/// Returns an error message if copying or saving failed.
+pub fn render_transaction_export(
+    ui: &mut Ui,
+    text: &str,
+    file_name: &str,
+    show_qr_code: &mut bool,
+) -> Option<String> {
+    let mut error = None;
+
+    egui::ScrollArea::vertical()
+        .id_salt(file_name)
+        .max_height(120.0)
+        .show(ui, |ui| {
+            ui.add(
+                egui::TextEdit::multiline(&mut text.to_string())
+                    .desired_width(f32::INFINITY)
+                    .interactive(false),
+            );
+        });
+
+    ui.horizontal(|ui| {
+        if ui.button("Copy").clicked() {
+            error = copy_to_clipboard(text).err();
+        }
+        if ui.button("Save to File").clicked() {
+            if let Some(path) = rfd::FileDialog::new().set_file_name(file_name).save_file() {
+                error = std::fs::write(path, text)
+                    .map_err(|e| format!("Failed to save file: {}", e))
+                    .err();
+            }
+        }
+        let qr_label = if *show_qr_code {
+            "Hide QR Code"
+        } else {
+            "Show QR Code"
+        };
+        if ui.button(qr_label).clicked() {
+            *show_qr_code = !*show_qr_code;
+        }
+    });
+
+    if *show_qr_code {
+        match generate_qr_code_image(text) {
+            Ok(qr_image) => {
+                let texture =
+                    ui.ctx()
+                        .load_texture(file_name, qr_image, egui::TextureOptions::NEAREST);
+                ui.image(&texture);
+            }
+            Err(_) => {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    "The transaction is too large for a QR code, please use a file instead.",
+                );
+            }
+        }
+    }
+
+    error
+}
+
+/// A `dash:` payment request in a file dropped on the window, such as a text file or a link
+/// dragged out of a browser.
+pub fn dropped_payment_uri(ctx: &Context) -> Option<String> {
+    let dropped_files = ctx.input(|i| i.raw.dropped_files.clone());
+    dropped_files.iter().find_map(|file| {
+        let contents = match (&file.bytes, &file.path) {
+            (Some(bytes), _) => String::from_utf8_lossy(bytes).into_owned(),
+            (None, Some(path)) => std::fs::read_to_string(path).ok()?,
+            (None, None) => return None,
+        };
+        find_payment_uri(&contents).map(str::to_string)
+    })
+}
+
 /// Returns the newly selected identity (if changed), otherwise the existing one.
 pub fn render_identity_selector(
     ui: &mut Ui,
@@ -1,7 +1,9 @@
 use crate::app::AppAction;
-use crate::backend_task::BackendTask;
-use crate::backend_task::identity::{IdentityInputToLoad, IdentityTask};
+use crate::backend_task::core::{CoreItem, CoreTask};
+use crate::backend_task::identity::{IdentityInputToLoad, IdentityTask, verify_key_input};
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
+use crate::model::masternode_core_info::MasternodeCoreInfo;
 use crate::model::qualified_identity::IdentityType;
 use crate::model::wallet::Wallet;
 use crate::ui::components::left_panel::add_left_panel;
@@ -19,6 +21,7 @@ use std::fs;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
+use zeroize::Zeroize;
 
 #[derive(Debug, Clone, Deserialize)]
 struct MasternodeInfo {
@@ -54,6 +57,13 @@ fn load_testnet_nodes_from_yml(file_path: &str) -> Option<TestnetNodes> {
     serde_yaml::from_str(&file_content).expect("expected proper yaml")
 }
 
+const IDENTITY_LOOKUP_INFO: &str = "The identity ID or ProTx hash can be entered in hex or base58. \
+     A DPNS name such as alice.dash works as well. \
+     If left empty, the identity is found by the public key of the private keys entered below, \
+     which are then attached with the purpose and security level they have on the identity. \
+     Masternode and evonode identities are found through the ProTx their keys are registered \
+     with on Dash Core.";
+
 #[derive(PartialEq)]
 pub enum AddIdentityStatus {
     NotStarted,
@@ -79,6 +89,17 @@ pub struct AddExistingIdentityScreen {
     pub identity_index_input: String,
     pub app_context: Arc<AppContext>,
     show_pop_up_info: Option<String>,
+    /// The masternode last looked up on Core
+    masternode_info: Option<MasternodeCoreInfo>,
+    looking_up_masternode: bool,
+    importing_masternodes: bool,
+    success_message: Option<String>,
+}
+
+impl Drop for AddExistingIdentityScreen {
+    fn drop(&mut self) {
+        self.zeroize_key_inputs();
+    }
 }
 
 impl AddExistingIdentityScreen {
@@ -106,6 +127,10 @@ impl AddExistingIdentityScreen {
             identity_index_input: String::new(),
             app_context: app_context.clone(),
             show_pop_up_info: None,
+            masternode_info: None,
+            looking_up_masternode: false,
+            importing_masternodes: false,
+            success_message: None,
         }
     }
 
@@ -127,8 +152,17 @@ impl AddExistingIdentityScreen {
             .spacing([10.0, 10.0])
             .striped(false)
             .show(ui, |ui| {
-                ui.label("Identity ID / ProTxHash (Hex or Base58):");
-                ui.text_edit_singleline(&mut self.identity_id_input);
+                ui.horizontal(|ui| {
+                    ui.label("Identity ID / ProTxHash / DPNS name:");
+                    let response = crate::ui::helpers::info_icon_button(ui, IDENTITY_LOOKUP_INFO);
+                    if response.clicked() {
+                        self.show_pop_up_info = Some(IDENTITY_LOOKUP_INFO.to_string());
+                    }
+                });
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.identity_id_input)
+                        .hint_text("Leave empty to find it by a private key"),
+                );
                 ui.label("");
                 ui.end_row();
 
@@ -228,9 +262,13 @@ impl AddExistingIdentityScreen {
             });
         ui.add_space(10.0);
 
-        // Add button to add more keys
-        if ui.button("+ Add Key").clicked() {
-            self.keys_input.push(String::new());
+        if self.identity_type == IdentityType::User {
+            // Add button to add more keys
+            if ui.button("+ Add Key").clicked() {
+                self.keys_input.push(String::new());
+            }
+        } else {
+            action |= self.render_masternode_core_lookup(ui);
         }
         ui.add_space(10.0);
 
@@ -353,6 +391,110 @@ impl AddExistingIdentityScreen {
         action
     }
 
+    /// Lookup of the masternode on Core, filling in its type and the keys the Core wallet has,
+    /// and bulk import of the masternodes of the Core wallet.
+    fn render_masternode_core_lookup(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            let look_up_button = ui
+                .add_enabled(
+                    !self.looking_up_masternode && !self.identity_id_input.trim().is_empty(),
+                    egui::Button::new("Look up ProTx on Core"),
+                )
+                .on_hover_text(
+                    "Fill in the masternode from the connected Dash Core, including the keys its wallet holds.",
+                );
+            if look_up_button.clicked() {
+                self.looking_up_masternode = true;
+                action = AppAction::BackendTask(BackendTask::CoreTask(
+                    CoreTask::GetMasternodeInfo(self.identity_id_input.trim().to_string()),
+                ));
+            }
+
+            let import_button = ui
+                .add_enabled(
+                    !self.importing_masternodes,
+                    egui::Button::new("Import all masternodes of the Core wallet"),
+                )
+                .on_hover_text(
+                    "Load the identity of every masternode whose keys or collateral are in the Dash Core wallet.",
+                );
+            if import_button.clicked() {
+                self.importing_masternodes = true;
+                action = AppAction::BackendTask(BackendTask::IdentityTask(
+                    IdentityTask::ImportWalletMasternodes,
+                ));
+            }
+
+            if self.looking_up_masternode || self.importing_masternodes {
+                ui.add(egui::widgets::Spinner::default());
+            }
+        });
+
+        let Some(info) = self
+            .masternode_info
+            .as_ref()
+            .filter(|info| info.pro_tx_hash == self.identity_id_input.trim())
+        else {
+            return action;
+        };
+        ui.add_space(10.0);
+        egui::Grid::new("masternode_core_info_grid")
+            .num_columns(2)
+            .spacing([10.0, 5.0])
+            .show(ui, |ui| {
+                ui.label("Voting address:");
+                ui.label(info.voting_address.to_string());
+                ui.end_row();
+                ui.label("Voting key hash:");
+                ui.label(info.voting_key_hash().unwrap_or_default());
+                ui.end_row();
+                ui.label("Owner address:");
+                ui.label(info.owner_address.to_string());
+                ui.end_row();
+                ui.label("Payout address:");
+                ui.label(info.payout_address.to_string());
+                ui.end_row();
+                ui.label("Operator BLS key:");
+                ui.label(&info.operator_public_key);
+                ui.end_row();
+            });
+
+        // Check the entered keys against the registration, invalid keys are reported on load
+        let mismatches = info.key_mismatches(
+            verify_key_input(self.voting_private_key_input.clone(), "Voting")
+                .ok()
+                .flatten(),
+            verify_key_input(self.owner_private_key_input.clone(), "Owner")
+                .ok()
+                .flatten(),
+            verify_key_input(self.payout_address_private_key_input.clone(), "Payout")
+                .ok()
+                .flatten(),
+            self.app_context.network,
+        );
+        for mismatch in mismatches {
+            ui.colored_label(Color32::DARK_RED, mismatch);
+        }
+        action
+    }
+
+    fn apply_masternode_info(&mut self, info: MasternodeCoreInfo) {
+        self.identity_id_input = info.pro_tx_hash.clone();
+        self.identity_type = info.identity_type;
+        let keys = &info.wallet_keys;
+        for (input, wallet_key) in [
+            (&mut self.voting_private_key_input, &keys.voting),
+            (&mut self.owner_private_key_input, &keys.owner),
+            (&mut self.payout_address_private_key_input, &keys.payout),
+        ] {
+            if let Some(wallet_key) = wallet_key.as_ref().filter(|_| input.trim().is_empty()) {
+                *input = wallet_key.clone();
+            }
+        }
+        self.masternode_info = Some(info);
+    }
+
     fn load_identity_clicked(&mut self) -> AppAction {
         let identity_input = IdentityInputToLoad {
             identity_id_input: self.identity_id_input.trim().to_string(),
@@ -368,6 +510,14 @@ impl AddExistingIdentityScreen {
             identity_input,
         )))
     }
+
+    fn zeroize_key_inputs(&mut self) {
+        self.voting_private_key_input.zeroize();
+        self.owner_private_key_input.zeroize();
+        self.payout_address_private_key_input.zeroize();
+        self.keys_input.iter_mut().for_each(Zeroize::zeroize);
+    }
+
     fn fill_random_hpmn(&mut self) {
         if let Some((name, hpmn)) = self
             .testnet_loaded_nodes
@@ -411,20 +561,24 @@ impl AddExistingIdentityScreen {
             ui.add_space(50.0);
 
             ui.heading("🎉");
-            ui.heading("Successfully loaded identity.");
+            ui.heading(
+                self.success_message
+                    .as_deref()
+                    .unwrap_or("Successfully loaded identity."),
+            );
 
             ui.add_space(20.0);
 
             if ui.button("Load Another").clicked() {
                 self.identity_id_input.clear();
                 self.alias_input.clear();
-                self.voting_private_key_input.clear();
-                self.owner_private_key_input.clear();
-                self.payout_address_private_key_input.clear();
+                self.zeroize_key_inputs();
                 self.keys_input = vec![String::new(), String::new(), String::new()];
                 self.identity_index_input.clear();
                 self.error_message = None;
                 self.show_pop_up_info = None;
+                self.masternode_info = None;
+                self.success_message = None;
                 self.add_identity_status = AddIdentityStatus::NotStarted;
             }
             ui.add_space(5.0);
@@ -475,16 +629,35 @@ impl ScreenLike for AddExistingIdentityScreen {
             MessageType::Success => {
                 if message == "Successfully loaded identity" {
                     self.add_identity_status = AddIdentityStatus::Complete;
+                } else if message.starts_with("Imported ") {
+                    self.importing_masternodes = false;
+                    self.success_message = Some(message.to_string());
+                    self.add_identity_status = AddIdentityStatus::Complete;
+                } else if message == "The Core wallet has no masternodes" {
+                    self.importing_masternodes = false;
+                    self.add_identity_status = AddIdentityStatus::ErrorMessage(message.to_string());
                 }
             }
             MessageType::Info => {}
             MessageType::Error => {
+                self.looking_up_masternode = false;
+                self.importing_masternodes = false;
                 // It's not great because the error message can be coming from somewhere else if there are other processes happening
                 self.add_identity_status = AddIdentityStatus::ErrorMessage(message.to_string());
             }
         }
     }
 
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::CoreItem(CoreItem::MasternodeInfo(info)) => {
+                self.looking_up_masternode = false;
+                self.apply_masternode_info(info);
+            }
+            _ => self.display_message("Success", MessageType::Success),
+        }
+    }
+
     fn pop_on_success(&mut self) {
         self.add_identity_status = AddIdentityStatus::Complete;
     }
@@ -1,4 +1,6 @@
 use crate::app::AppAction;
+use crate::model::wallet::fee_estimation::FeeEstimator;
+use crate::ui::helpers::{render_account_chooser, render_coin_selection_chooser};
 use crate::ui::identities::add_new_identity_screen::{
     AddNewIdentityScreen, FundingMethod, WalletFundedScreenStep,
 };
@@ -9,18 +11,41 @@ impl AddNewIdentityScreen {
         if let Some(selected_wallet) = &self.selected_wallet {
             let wallet = selected_wallet.read().unwrap(); // Read lock on the wallet
 
-            let total_balance: u64 = wallet.max_balance(); // Sum up all the balances
+            let total_balance: u64 = wallet.account_balance(self.funding_account);
 
             let dash_balance = total_balance as f64 * 1e-8; // Convert to DASH units
 
             ui.horizontal(|ui| {
-                ui.label(format!("Wallet Balance: {:.8} DASH", dash_balance));
+                ui.label(format!("Account Balance: {:.8} DASH", dash_balance));
             });
         } else {
             ui.label("No wallet selected");
         }
     }
 
+    fn show_estimated_fee(&mut self, ui: &mut egui::Ui) -> AppAction {
+        let Some(selected_wallet) = &self.selected_wallet else {
+            return AppAction::None;
+        };
+        let amount = self
+            .funding_amount_exact
+            .unwrap_or_else(|| (self.funding_amount.parse::<f64>().unwrap_or(0.0) * 1e8) as u64);
+        if amount == 0 {
+            return AppAction::None;
+        }
+
+        self.fee_estimate.show(
+            ui,
+            &self.app_context,
+            &selected_wallet.read().unwrap(),
+            amount,
+            FeeEstimator::for_asset_lock,
+            true,
+            self.funding_account,
+            self.coin_selection,
+        )
+    }
+
     pub fn render_ui_by_using_unused_balance(
         &mut self,
         ui: &mut Ui,
@@ -35,10 +60,22 @@ impl AddNewIdentityScreen {
         ));
 
         ui.add_space(10.0);
+        if let Some(selected_wallet) = &self.selected_wallet {
+            render_account_chooser(
+                ui,
+                "identity_funding_account",
+                &selected_wallet.read().unwrap(),
+                &mut self.funding_account,
+            );
+            ui.add_space(5.0);
+        }
         self.show_wallet_balance(ui);
         ui.add_space(5.0);
 
         self.render_funding_amount_input(ui);
+        ui.add_space(5.0);
+        render_coin_selection_chooser(ui, "identity_coin_selection", &mut self.coin_selection);
+        action |= self.show_estimated_fee(ui);
 
         // Extract the step from the RwLock to minimize borrow scope
         let step = *self.step.read().unwrap();
@@ -3,6 +3,7 @@ use crate::backend_task::BackendTask;
 use crate::backend_task::identity::{
     IdentityRegistrationInfo, IdentityTask, RegisterIdentityFundingMethod,
 };
+use crate::model::payment_uri::PaymentUri;
 use crate::ui::identities::add_new_identity_screen::{
     AddNewIdentityScreen, WalletFundedScreenStep,
 };
@@ -23,6 +24,7 @@ impl AddNewIdentityScreen {
                     let mut wallet = wallet_guard.write().unwrap();
                     let receive_address = wallet.receive_address(
                         self.app_context.network,
+                        self.funding_account,
                         false,
                         Some(&self.app_context),
                     )?;
@@ -81,7 +83,11 @@ impl AddNewIdentityScreen {
         //     self.start_balance_check(&address, ui.ctx());
         // }
 
-        let pay_uri = format!("{}?amount={:.4}", address.to_qr_uri(), amount);
+        let pay_uri = PaymentUri {
+            amount: Some((amount * 1e8).round() as u64),
+            ..PaymentUri::new(address.clone())
+        }
+        .to_uri();
 
         // Generate the QR code image
         if let Ok(qr_image) = generate_qr_code_image(&pay_uri) {
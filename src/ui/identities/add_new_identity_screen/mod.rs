@@ -11,11 +11,15 @@ use crate::backend_task::identity::{
 use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
+use crate::ui::components::fee_estimate::FeeEstimate;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
-use crate::ui::identities::funding_common::WalletFundedScreenStep;
+use crate::ui::identities::funding_common::{
+    WalletFundedScreenStep, funding_amount_from_payment_uri,
+};
 use crate::ui::{MessageType, ScreenLike};
 use dash_sdk::dashcore_rpc::dashcore::Address;
 use dash_sdk::dashcore_rpc::dashcore::transaction::special_transaction::TransactionPayload;
@@ -64,6 +68,9 @@ pub struct AddNewIdentityScreen {
     funding_method: Arc<RwLock<FundingMethod>>,
     funding_amount: String,
     funding_amount_exact: Option<Duffs>,
+    funding_account: u32,
+    coin_selection: CoinSelectionStrategy,
+    fee_estimate: FeeEstimate,
     funding_utxo: Option<(OutPoint, TxOut, Address)>,
     alias_input: String,
     copied_to_clipboard: Option<Option<String>>,
@@ -83,7 +90,11 @@ impl AddNewIdentityScreen {
 
         if app_context.has_wallet.load(Ordering::Relaxed) {
             let wallets = &app_context.wallets.read().unwrap();
-            if let Some(wallet) = wallets.values().next() {
+            // Watch-only wallets can not derive identity keys
+            if let Some(wallet) = wallets
+                .values()
+                .find(|wallet| !wallet.read().unwrap().is_watch_only())
+            {
                 // Automatically select the only available wallet
                 selected_wallet = Some(wallet.clone());
             }
@@ -99,6 +110,9 @@ impl AddNewIdentityScreen {
             funding_method: Arc::new(RwLock::new(FundingMethod::NoSelection)),
             funding_amount: "0.5".to_string(),
             funding_amount_exact: None,
+            funding_account: 0,
+            coin_selection: CoinSelectionStrategy::default(),
+            fee_estimate: FeeEstimate::default(),
             funding_utxo: None,
             alias_input: String::new(),
             copied_to_clipboard: None,
@@ -359,25 +373,35 @@ impl AddNewIdentityScreen {
                     .selected_text(selected_wallet_alias)
                     .show_ui(ui, |ui| {
                         for wallet in wallets.values() {
-                            let wallet_alias = wallet
-                                .read()
-                                .ok()
-                                .and_then(|w| w.alias.clone())
-                                .unwrap_or_else(|| "Unnamed Wallet".to_string());
+                            let (wallet_alias, is_watch_only) = {
+                                let wallet_read = wallet.read().unwrap();
+                                (
+                                    wallet_read
+                                        .alias
+                                        .clone()
+                                        .unwrap_or_else(|| "Unnamed Wallet".to_string()),
+                                    wallet_read.is_watch_only(),
+                                )
+                            };
 
                             let is_selected = self
                                 .selected_wallet
                                 .as_ref()
                                 .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
 
-                            if ui.selectable_label(is_selected, wallet_alias).clicked() {
-                                // Update the selected wallet
-                                selected_wallet = Some(wallet.clone());
-                            }
+                            ui.add_enabled_ui(!is_watch_only, |ui| {
+                                if ui.selectable_label(is_selected, wallet_alias).clicked() {
+                                    // Update the selected wallet
+                                    selected_wallet = Some(wallet.clone());
+                                }
+                            });
                         }
                     });
                 true
-            } else if let Some(wallet) = wallets.values().next() {
+            } else if let Some(wallet) = wallets
+                .values()
+                .find(|wallet| !wallet.read().unwrap().is_watch_only())
+            {
                 if self.selected_wallet.is_none() {
                     // Automatically select the only available wallet
                     selected_wallet = Some(wallet.clone());
@@ -681,6 +705,8 @@ impl AddNewIdentityScreen {
                     identity_funding_method: RegisterIdentityFundingMethod::FundWithWallet(
                         amount,
                         self.identity_id_number,
+                        self.funding_account,
+                        self.coin_selection,
                     ),
                 };
 
@@ -711,6 +737,16 @@ impl AddNewIdentityScreen {
                 )
                 .lost_focus();
 
+            match funding_amount_from_payment_uri(
+                ui.ctx(),
+                &mut self.funding_amount,
+                self.app_context.network,
+            ) {
+                Ok(Some(amount)) => self.funding_amount_exact = Some(amount),
+                Ok(None) => {}
+                Err(e) => self.error_message = Some(e),
+            }
+
             let enter_pressed = ui.input(|i| i.key_pressed(egui::Key::Enter));
 
             if amount_input && enter_pressed {
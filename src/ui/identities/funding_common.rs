@@ -1,4 +1,9 @@
+use crate::model::payment_uri::{PaymentUri, find_payment_uri, format_dash_amount};
+use crate::ui::helpers::dropped_payment_uri;
 use arboard::Clipboard;
+use dash_sdk::dpp::balances::credits::Duffs;
+use dash_sdk::dpp::dashcore::Network;
+use eframe::egui::Context;
 use eframe::epaint::{Color32, ColorImage};
 use image::Luma;
 use qrcode::QrCode;
@@ -42,3 +47,25 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
         .set_text(text.to_string())
         .map_err(|e| e.to_string())
 }
+
+/// Takes the amount of a `dash:` payment request pasted into a funding amount input, or dropped
+/// on the window, and puts it in the input instead.
+pub fn funding_amount_from_payment_uri(
+    ctx: &Context,
+    input: &mut String,
+    network: Network,
+) -> Result<Option<Duffs>, String> {
+    let Some(uri) = find_payment_uri(input)
+        .map(str::to_string)
+        .or_else(|| dropped_payment_uri(ctx))
+    else {
+        return Ok(None);
+    };
+    input.clear();
+
+    let amount = PaymentUri::parse(&uri, network)?
+        .amount
+        .ok_or_else(|| "The payment request has no amount".to_string())?;
+    *input = format_dash_amount(amount);
+    Ok(Some(amount))
+}
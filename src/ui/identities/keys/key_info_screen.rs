@@ -1,17 +1,21 @@
 use crate::app::AppAction;
+use crate::backend_task::BackendTask;
+use crate::backend_task::identity::IdentityTask;
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::qualified_identity::encrypted_key_storage::{
     PrivateKeyData, WalletDerivationPath,
 };
+use crate::model::qualified_identity::qualified_identity_public_key::QualifiedIdentityPublicKey;
 use crate::model::wallet::Wallet;
-use crate::ui::ScreenLike;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::{MessageType, ScreenLike};
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
+use bip39::rand::{SeedableRng, rngs::StdRng};
 use dash_sdk::dashcore_rpc::dashcore::PrivateKey as RPCPrivateKey;
 use dash_sdk::dpp::dashcore::address::Payload;
 use dash_sdk::dpp::dashcore::hashes::Hash;
@@ -20,15 +24,24 @@ use dash_sdk::dpp::dashcore::sign_message::signed_msg_hash;
 use dash_sdk::dpp::dashcore::{Address, PrivateKey, PubkeyHash, ScriptHash};
 use dash_sdk::dpp::identity::KeyType;
 use dash_sdk::dpp::identity::KeyType::BIP13_SCRIPT_HASH;
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::hash::IdentityPublicKeyHashMethodsV0;
 use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use dash_sdk::dpp::identity::identity_public_key::contract_bounds::ContractBounds;
+use dash_sdk::dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::platform::IdentityPublicKey;
 use eframe::egui::{self, Context};
 use egui::{Color32, RichText, ScrollArea, TextEdit};
 use std::sync::{Arc, RwLock};
 
+#[derive(Clone, Copy, PartialEq)]
+enum KeyUpdate {
+    Disable,
+    /// Replace the key by a new random one with the same purpose and security level
+    Rotate,
+}
+
 pub struct KeyInfoScreen {
     pub identity: QualifiedIdentity,
     pub key: IdentityPublicKey,
@@ -48,23 +61,25 @@ pub struct KeyInfoScreen {
     view_private_key_even_if_encrypted_or_in_wallet: bool,
     show_pop_up_info: Option<String>,
     show_confirm_remove_private_key: bool,
+    confirm_key_update: Option<KeyUpdate>,
+    key_update_in_progress: bool,
+    key_update_message: Option<(String, MessageType)>,
 }
 
-// /// The prefix for signed messages using Dash's message signing protocol.
-// pub const DASH_SIGNED_MSG_PREFIX: &[u8] = b"\x19Dash Signed Message:\n";
-//
-// pub fn signed_msg_hash(msg: &str) -> sha256d::Hash {
-//     let mut engine = sha256d::Hash::engine();
-//     engine.input(DASH_SIGNED_MSG_PREFIX);
-//     let msg_len = encode::VarInt(msg.len() as u64);
-//     msg_len.consensus_encode(&mut engine).expect("engines don't error");
-//     engine.input(msg.as_bytes());
-//     sha256d::Hash::from_engine(engine)
-// }
-
 impl ScreenLike for KeyInfoScreen {
     fn refresh(&mut self) {}
 
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if !self.key_update_in_progress {
+            return;
+        }
+        self.key_update_in_progress = false;
+        if message_type == MessageType::Success {
+            self.reload_identity();
+        }
+        self.key_update_message = Some((message.to_string(), message_type));
+    }
+
     fn ui(&mut self, ctx: &Context) -> AppAction {
         let mut action = add_top_panel(
             ctx,
@@ -83,7 +98,7 @@ impl ScreenLike for KeyInfoScreen {
         );
 
         action |= island_central_panel(ctx, |ui| {
-            let inner_action = AppAction::None;
+            let mut inner_action = AppAction::None;
 
             ScrollArea::vertical().show(ui, |ui| {
                 ui.heading(RichText::new("Key Information").color(Color32::BLACK));
@@ -412,6 +427,8 @@ impl ScreenLike for KeyInfoScreen {
                     }
                 }
 
+                inner_action |= self.render_key_update(ui);
+
                 if self.view_wallet_unlock {
                     let (needed_unlock, just_unlocked) = self.render_wallet_unlock_if_needed(ui);
                     if !needed_unlock || just_unlocked {
@@ -440,6 +457,10 @@ impl ScreenLike for KeyInfoScreen {
                     self.render_remove_private_key_confirm(ui);
                 }
 
+                if let Some(key_update) = self.confirm_key_update {
+                    inner_action |= self.render_key_update_confirm(ui, key_update);
+                }
+
                 ui.add_space(10.0);
             });
 
@@ -484,7 +505,165 @@ impl KeyInfoScreen {
             view_private_key_even_if_encrypted_or_in_wallet: false,
             show_pop_up_info: None,
             show_confirm_remove_private_key: false,
+            confirm_key_update: None,
+            key_update_in_progress: false,
+            key_update_message: None,
+        }
+    }
+
+    /// Picks up the identity saved by a key update, so the key shows as disabled.
+    fn reload_identity(&mut self) {
+        let Ok(Some(identity)) = self
+            .app_context
+            .get_identity_by_id(&self.identity.identity.id())
+        else {
+            return;
+        };
+        if let Some(key) = identity.identity.get_public_key_by_id(self.key.id()) {
+            self.key = key.clone();
+        }
+        self.identity = identity;
+    }
+
+    fn render_key_update(&mut self, ui: &mut egui::Ui) -> AppAction {
+        // Key updates are signed by the master key of the main identity
+        if self.identity.can_sign_with_master_key().is_none() {
+            return AppAction::None;
+        }
+
+        ui.add_space(10.0);
+        ui.separator();
+        ui.add_space(10.0);
+        ui.heading(RichText::new("Disable or Rotate").color(Color32::BLACK));
+        ui.add_space(5.0);
+
+        if let Some((message, message_type)) = &self.key_update_message {
+            let color = if *message_type == MessageType::Error {
+                Color32::DARK_RED
+            } else {
+                Color32::DARK_GREEN
+            };
+            ui.colored_label(color, message);
+            ui.add_space(5.0);
+        }
+
+        if self.key.is_disabled() {
+            ui.label(RichText::new("This key is disabled.").color(Color32::BLACK));
+            return AppAction::None;
         }
+        // Read-only keys and the master key signing the update can't be replaced
+        if let Some(reason) = self.identity.key_update_blocker(self.key.id()) {
+            ui.label(RichText::new(format!("{}.", reason)).color(Color32::BLACK));
+            return AppAction::None;
+        }
+        if self.key_update_in_progress {
+            ui.horizontal(|ui| {
+                ui.add(egui::Spinner::new());
+                ui.label("Updating identity...");
+            });
+            return AppAction::None;
+        }
+
+        ui.label(
+            RichText::new(
+                "If this key may have leaked, rotate it to replace it by a new key with the same purpose and security level, or disable it.",
+            )
+            .color(Color32::BLACK),
+        );
+        ui.add_space(5.0);
+        ui.horizontal(|ui| {
+            if ui.button("Rotate Key").clicked() {
+                self.confirm_key_update = Some(KeyUpdate::Rotate);
+            }
+            if ui.button("Disable Key").clicked() {
+                self.confirm_key_update = Some(KeyUpdate::Disable);
+            }
+        });
+        AppAction::None
+    }
+
+    fn render_key_update_confirm(&mut self, ui: &mut egui::Ui, key_update: KeyUpdate) -> AppAction {
+        let mut action = AppAction::None;
+        let (title, text) = match key_update {
+            KeyUpdate::Disable => (
+                "Disable Key",
+                format!(
+                    "Disable key {}? Disabled keys can't be enabled again.",
+                    self.key.id()
+                ),
+            ),
+            KeyUpdate::Rotate => (
+                "Rotate Key",
+                format!(
+                    "Add a new {:?} {:?} key and disable key {}? The new private key is stored in DET.",
+                    self.key.purpose(),
+                    self.key.security_level(),
+                    self.key.id()
+                ),
+            ),
+        };
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                ui.label(RichText::new(text).color(Color32::BLACK));
+                ui.add_space(10.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("Cancel").clicked() {
+                        self.confirm_key_update = None;
+                    }
+                    ui.add_space(3.0);
+                    if ui.button("Confirm").clicked() {
+                        self.confirm_key_update = None;
+                        action = self.key_update_action(key_update);
+                    }
+                });
+            });
+        action
+    }
+
+    fn key_update_action(&mut self, key_update: KeyUpdate) -> AppAction {
+        let task = match key_update {
+            KeyUpdate::Disable => {
+                IdentityTask::DisableKeys(self.identity.clone(), vec![self.key.id()])
+            }
+            KeyUpdate::Rotate => {
+                let mut rng = StdRng::from_entropy();
+                let Ok((public_key_data, private_key)) =
+                    self.key.key_type().random_public_and_private_key_data(
+                        &mut rng,
+                        self.app_context.platform_version(),
+                    )
+                else {
+                    self.key_update_message = Some((
+                        "Failed to generate a replacement key".to_string(),
+                        MessageType::Error,
+                    ));
+                    return AppAction::None;
+                };
+                // The backend task gives the key the next free id
+                let replacement_key = IdentityPublicKeyV0 {
+                    id: self.identity.identity.get_public_key_max_id() + 1,
+                    key_type: self.key.key_type(),
+                    purpose: self.key.purpose(),
+                    security_level: self.key.security_level(),
+                    data: public_key_data.into(),
+                    read_only: self.key.read_only(),
+                    disabled_at: None,
+                    contract_bounds: self.key.contract_bounds().cloned(),
+                };
+                IdentityTask::RotateKey(
+                    self.identity.clone(),
+                    self.key.id(),
+                    QualifiedIdentityPublicKey::from(IdentityPublicKey::from(replacement_key)),
+                    private_key,
+                )
+            }
+        };
+        self.key_update_in_progress = true;
+        self.key_update_message = None;
+        AppAction::BackendTask(BackendTask::IdentityTask(task))
     }
 
     fn validate_and_store_private_key(&mut self) {
@@ -604,7 +783,9 @@ impl KeyInfoScreen {
             // Use the key type to determine how to sign
             match self.key.key_type() {
                 KeyType::ECDSA_SECP256K1 | KeyType::ECDSA_HASH160 => {
-                    // Sign the message using ECDSA
+                    // Identity keys keep their own non-recoverable format with a fixed header
+                    // byte, wallet addresses sign like Dash Core's signmessage instead, see
+                    // crate::model::signed_message
                     let secp = Secp256k1::new();
 
                     let message_hash = signed_msg_hash(self.message_input.as_str());
@@ -20,7 +20,7 @@ use crate::{
 
 pub mod add_existing_identity_screen;
 pub mod add_new_identity_screen;
-mod funding_common;
+pub(crate) mod funding_common;
 pub mod identities_screen;
 pub mod keys;
 pub mod register_dpns_name_screen;
@@ -1,6 +1,7 @@
 use crate::app::AppAction;
 use crate::backend_task::BackendTask;
 use crate::backend_task::identity::{IdentityTask, IdentityTopUpInfo, TopUpIdentityFundingMethod};
+use crate::model::payment_uri::PaymentUri;
 use crate::ui::identities::funding_common::{copy_to_clipboard, generate_qr_code_image};
 use crate::ui::identities::top_up_identity_screen::{TopUpIdentityScreen, WalletFundedScreenStep};
 use dash_sdk::dashcore_rpc::RpcApi;
@@ -19,6 +20,7 @@ impl TopUpIdentityScreen {
                     let mut wallet = wallet_guard.write().unwrap();
                     let receive_address = wallet.receive_address(
                         self.app_context.network,
+                        self.funding_account,
                         false,
                         Some(&self.app_context),
                     )?;
@@ -72,7 +74,11 @@ impl TopUpIdentityScreen {
             }
         };
 
-        let pay_uri = format!("{}?amount={:.4}", address.to_qr_uri(), amount);
+        let pay_uri = PaymentUri {
+            amount: Some((amount * 1e8).round() as u64),
+            ..PaymentUri::new(address.clone())
+        }
+        .to_uri();
 
         // Generate the QR code image
         if let Ok(qr_image) = generate_qr_code_image(&pay_uri) {
@@ -10,12 +10,16 @@ use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
+use crate::ui::components::fee_estimate::FeeEstimate;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
 use crate::ui::identities::add_new_identity_screen::FundingMethod;
-use crate::ui::identities::funding_common::WalletFundedScreenStep;
+use crate::ui::identities::funding_common::{
+    WalletFundedScreenStep, funding_amount_from_payment_uri,
+};
 use crate::ui::{MessageType, ScreenLike};
 use dash_sdk::dashcore_rpc::dashcore::Address;
 use dash_sdk::dashcore_rpc::dashcore::transaction::special_transaction::TransactionPayload;
@@ -39,6 +43,9 @@ pub struct TopUpIdentityScreen {
     funding_method: Arc<RwLock<FundingMethod>>,
     funding_amount: String,
     funding_amount_exact: Option<Duffs>,
+    funding_account: u32,
+    coin_selection: CoinSelectionStrategy,
+    fee_estimate: FeeEstimate,
     funding_utxo: Option<(OutPoint, TxOut, Address)>,
     copied_to_clipboard: Option<Option<String>>,
     error_message: Option<String>,
@@ -60,6 +67,9 @@ impl TopUpIdentityScreen {
             funding_method: Arc::new(RwLock::new(FundingMethod::NoSelection)),
             funding_amount: "".to_string(),
             funding_amount_exact: None,
+            funding_account: 0,
+            coin_selection: CoinSelectionStrategy::default(),
+            fee_estimate: FeeEstimate::default(),
             funding_utxo: None,
             copied_to_clipboard: None,
             error_message: None,
@@ -96,13 +106,16 @@ impl TopUpIdentityScreen {
                                     .clone()
                                     .unwrap_or_else(|| "Unnamed Wallet".to_string());
 
-                                let has_resources = match funding_method {
-                                    FundingMethod::UseWalletBalance => wallet_read.has_balance(),
-                                    FundingMethod::UseUnusedAssetLock => {
-                                        wallet_read.has_unused_asset_lock()
-                                    }
-                                    _ => true,
-                                };
+                                let has_resources = !wallet_read.is_watch_only()
+                                    && match funding_method {
+                                        FundingMethod::UseWalletBalance => {
+                                            wallet_read.has_balance()
+                                        }
+                                        FundingMethod::UseUnusedAssetLock => {
+                                            wallet_read.has_unused_asset_lock()
+                                        }
+                                        _ => true,
+                                    };
 
                                 (alias, has_resources)
                             };
@@ -129,13 +142,14 @@ impl TopUpIdentityScreen {
                     // Check if the wallet has the required resources
                     let has_required_resources = {
                         let wallet_read = wallet.read().unwrap();
-                        match funding_method {
-                            FundingMethod::UseWalletBalance => wallet_read.has_balance(),
-                            FundingMethod::UseUnusedAssetLock => {
-                                wallet_read.has_unused_asset_lock()
+                        !wallet_read.is_watch_only()
+                            && match funding_method {
+                                FundingMethod::UseWalletBalance => wallet_read.has_balance(),
+                                FundingMethod::UseUnusedAssetLock => {
+                                    wallet_read.has_unused_asset_lock()
+                                }
+                                _ => true,
                             }
-                            _ => true,
-                        }
                     };
 
                     if has_required_resources {
@@ -278,6 +292,8 @@ impl TopUpIdentityScreen {
                             .cloned()
                             .map(|i| i + 1)
                             .unwrap_or_default(),
+                        self.funding_account,
+                        self.coin_selection,
                     ),
                 };
 
@@ -302,6 +318,14 @@ impl TopUpIdentityScreen {
                 .add(egui::TextEdit::singleline(&mut self.funding_amount).desired_width(100.0))
                 .lost_focus();
 
+            if let Err(e) = funding_amount_from_payment_uri(
+                ui.ctx(),
+                &mut self.funding_amount,
+                self.app_context.network,
+            ) {
+                self.error_message = Some(e);
+            }
+
             self.funding_amount_exact = self.funding_amount.parse::<f64>().ok().map(|f| {
                 (f * 1e8) as u64 // Convert the amount to Duffs
             });
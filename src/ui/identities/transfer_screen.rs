@@ -1,9 +1,10 @@
 use crate::app::AppAction;
-use crate::backend_task::BackendTask;
 use crate::backend_task::identity::IdentityTask;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
+use crate::ui::components::identity_recipient_input::RecipientInput;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
@@ -15,7 +16,7 @@ use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicK
 use dash_sdk::dpp::identity::{KeyType, Purpose, SecurityLevel};
 use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::dpp::prelude::TimestampMillis;
-use dash_sdk::platform::{Identifier, IdentityPublicKey};
+use dash_sdk::platform::IdentityPublicKey;
 use eframe::egui::{self, Context, Ui};
 use egui::{Color32, RichText};
 use std::sync::{Arc, RwLock};
@@ -38,7 +39,7 @@ pub enum TransferCreditsStatus {
 pub struct TransferScreen {
     pub identity: QualifiedIdentity,
     selected_key: Option<IdentityPublicKey>,
-    receiver_identity_id: String,
+    receiver: RecipientInput,
     amount: String,
     transfer_credits_status: TransferCreditsStatus,
     error_message: Option<String>,
@@ -66,7 +67,7 @@ impl TransferScreen {
         Self {
             identity,
             selected_key: selected_key.cloned(),
-            receiver_identity_id: String::new(),
+            receiver: RecipientInput::default(),
             amount: String::new(),
             transfer_credits_status: TransferCreditsStatus::NotStarted,
             error_message: None,
@@ -104,12 +105,8 @@ impl TransferScreen {
         });
     }
 
-    fn render_to_identity_input(&mut self, ui: &mut Ui) {
-        ui.horizontal(|ui| {
-            ui.label("Receiver Identity Id:");
-
-            ui.text_edit_singleline(&mut self.receiver_identity_id);
-        });
+    fn render_to_identity_input(&mut self, ui: &mut Ui) -> AppAction {
+        self.receiver.show(ui, "Receiver:")
     }
 
     fn show_confirmation_popup(&mut self, ui: &mut Ui) -> AppAction {
@@ -119,26 +116,13 @@ impl TransferScreen {
             .collapsible(false)
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
-                let identifier = if self.receiver_identity_id.is_empty() {
-                    self.error_message = Some("Invalid identifier".to_string());
-                    self.transfer_credits_status =
-                        TransferCreditsStatus::ErrorMessage("Invalid identifier".to_string());
-                    self.confirmation_popup = false;
-                    return;
-                } else {
-                    match Identifier::from_string_try_encodings(
-                        &self.receiver_identity_id,
-                        &[Encoding::Base58, Encoding::Hex],
-                    ) {
-                        Ok(identifier) => identifier,
-                        Err(_) => {
-                            self.error_message = Some("Invalid identifier".to_string());
-                            self.transfer_credits_status = TransferCreditsStatus::ErrorMessage(
-                                "Invalid identifier".to_string(),
-                            );
-                            self.confirmation_popup = false;
-                            return;
-                        }
+                let identifier = match self.receiver.identifier() {
+                    Ok(identifier) => identifier,
+                    Err(e) => {
+                        self.error_message = Some(e.clone());
+                        self.transfer_credits_status = TransferCreditsStatus::ErrorMessage(e);
+                        self.confirmation_popup = false;
+                        return;
                     }
                 };
 
@@ -152,7 +136,8 @@ impl TransferScreen {
 
                 ui.label(format!(
                     "Are you sure you want to transfer {} Dash to {}",
-                    self.amount, self.receiver_identity_id
+                    self.amount,
+                    self.receiver.description()
                 ));
                 let parts: Vec<&str> = self.amount.split('.').collect();
                 let mut credits: u128 = 0;
@@ -239,6 +224,15 @@ impl ScreenLike for TransferScreen {
         }
     }
 
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if !self
+            .receiver
+            .display_task_result(&backend_task_success_result)
+        {
+            self.display_message("Success", MessageType::Success);
+        }
+    }
+
     fn refresh(&mut self) {
         // Refresh the identity because there might be new keys
         self.identity = self
@@ -361,10 +355,10 @@ impl ScreenLike for TransferScreen {
                 ui.separator();
                 ui.add_space(10.0);
 
-                // Input the ID of the identity to transfer to
-                ui.heading("3. ID of the identity to transfer to");
+                // Input the ID or name of the identity to transfer to
+                ui.heading("3. ID or name of the identity to transfer to");
                 ui.add_space(5.0);
-                self.render_to_identity_input(ui);
+                inner_action |= self.render_to_identity_input(ui);
 
                 ui.add_space(10.0);
 
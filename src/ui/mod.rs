@@ -23,6 +23,7 @@ use crate::ui::tokens::transfer_tokens_screen::TransferTokensScreen;
 use crate::ui::tokens::view_token_claims_screen::ViewTokenClaimsScreen;
 use crate::ui::tools::contract_visualizer_screen::ContractVisualizerScreen;
 use crate::ui::tools::document_visualizer_screen::DocumentVisualizerScreen;
+use crate::ui::tools::message_verifier_screen::MessageVerifierScreen;
 use crate::ui::tools::platform_info_screen::PlatformInfoScreen;
 use crate::ui::tools::proof_log_screen::ProofLogScreen;
 use crate::ui::tools::proof_visualizer_screen::ProofVisualizerScreen;
@@ -57,6 +58,9 @@ use tokens::unfreeze_tokens_screen::UnfreezeTokensScreen;
 use tokens::update_token_config::UpdateTokenConfigScreen;
 use tools::transition_visualizer_screen::TransitionVisualizerScreen;
 use wallets::add_new_wallet_screen::AddNewWalletScreen;
+use wallets::sign_transaction_screen::SignTransactionScreen;
+use wallets::slip39_export_screen::Slip39ExportScreen;
+use wallets::sweep_key_screen::SweepKeyScreen;
 
 pub mod components;
 pub mod contracts_documents;
@@ -89,6 +93,7 @@ pub enum RootScreenType {
     RootScreenTokenCreator,
     RootScreenToolsContractVisualizerScreen,
     RootScreenToolsPlatformInfoScreen,
+    RootScreenToolsMessageVerifierScreen,
 }
 
 impl RootScreenType {
@@ -113,6 +118,7 @@ impl RootScreenType {
             RootScreenType::RootScreenToolsDocumentVisualizerScreen => 15,
             RootScreenType::RootScreenToolsContractVisualizerScreen => 16,
             RootScreenType::RootScreenToolsPlatformInfoScreen => 17,
+            RootScreenType::RootScreenToolsMessageVerifierScreen => 18,
         }
     }
 
@@ -137,6 +143,7 @@ impl RootScreenType {
             15 => Some(RootScreenType::RootScreenToolsDocumentVisualizerScreen),
             16 => Some(RootScreenType::RootScreenToolsContractVisualizerScreen),
             17 => Some(RootScreenType::RootScreenToolsPlatformInfoScreen),
+            18 => Some(RootScreenType::RootScreenToolsMessageVerifierScreen),
             _ => None,
         }
     }
@@ -168,6 +175,7 @@ impl From<RootScreenType> for ScreenType {
                 ScreenType::ContractsVisualizer
             }
             RootScreenType::RootScreenToolsPlatformInfoScreen => ScreenType::PlatformInfo,
+            RootScreenType::RootScreenToolsMessageVerifierScreen => ScreenType::MessageVerifier,
         }
     }
 }
@@ -183,6 +191,9 @@ pub enum ScreenType {
     WalletsBalances,
     ImportWallet,
     AddNewWallet,
+    SignTransaction,
+    ExportSlip39Shares,
+    SweepPrivateKey,
     AddExistingIdentity,
     TransitionVisualizer,
     WithdrawalScreen(QualifiedIdentity),
@@ -207,6 +218,7 @@ pub enum ScreenType {
     DocumentsVisualizer,
     ContractsVisualizer,
     PlatformInfo,
+    MessageVerifier,
     CreateDocument,
     DeleteDocument,
     ReplaceDocument,
@@ -298,6 +310,13 @@ impl ScreenType {
             ScreenType::AddNewWallet => {
                 Screen::AddNewWalletScreen(AddNewWalletScreen::new(app_context))
             }
+            ScreenType::SignTransaction => {
+                Screen::SignTransactionScreen(SignTransactionScreen::new(app_context))
+            }
+            ScreenType::ExportSlip39Shares => {
+                Screen::Slip39ExportScreen(Slip39ExportScreen::new(app_context))
+            }
+            ScreenType::SweepPrivateKey => Screen::SweepKeyScreen(SweepKeyScreen::new(app_context)),
             ScreenType::WalletsBalances => {
                 Screen::WalletsBalancesScreen(WalletsBalancesScreen::new(app_context))
             }
@@ -323,6 +342,9 @@ impl ScreenType {
             ScreenType::PlatformInfo => {
                 Screen::PlatformInfoScreen(PlatformInfoScreen::new(app_context))
             }
+            ScreenType::MessageVerifier => {
+                Screen::MessageVerifierScreen(MessageVerifierScreen::new(app_context))
+            }
             ScreenType::CreateDocument => Screen::DocumentActionScreen(DocumentActionScreen::new(
                 app_context.clone(),
                 None,
@@ -423,6 +445,9 @@ pub enum Screen {
     DPNSScreen(DPNSScreen),
     DocumentQueryScreen(DocumentQueryScreen),
     AddNewWalletScreen(AddNewWalletScreen),
+    SignTransactionScreen(SignTransactionScreen),
+    Slip39ExportScreen(Slip39ExportScreen),
+    SweepKeyScreen(SweepKeyScreen),
     ImportWalletScreen(ImportWalletScreen),
     AddNewIdentityScreen(AddNewIdentityScreen),
     AddExistingIdentityScreen(AddExistingIdentityScreen),
@@ -446,6 +471,7 @@ pub enum Screen {
     AddContractsScreen(AddContractsScreen),
     ProofVisualizerScreen(ProofVisualizerScreen),
     PlatformInfoScreen(PlatformInfoScreen),
+    MessageVerifierScreen(MessageVerifierScreen),
 
     // Token Screens
     TokensScreen(Box<TokensScreen>),
@@ -486,6 +512,9 @@ impl Screen {
             Screen::DocumentActionScreen(screen) => screen.app_context = app_context,
             Screen::GroupActionsScreen(screen) => screen.app_context = app_context,
             Screen::AddNewWalletScreen(screen) => screen.app_context = app_context,
+            Screen::SignTransactionScreen(screen) => screen.app_context = app_context,
+            Screen::Slip39ExportScreen(screen) => screen.app_context = app_context,
+            Screen::SweepKeyScreen(screen) => screen.app_context = app_context,
             Screen::TransferScreen(screen) => screen.app_context = app_context,
             Screen::TopUpIdentityScreen(screen) => screen.app_context = app_context,
             Screen::WalletsBalancesScreen(screen) => screen.app_context = app_context,
@@ -495,6 +524,7 @@ impl Screen {
             Screen::ProofVisualizerScreen(screen) => screen.app_context = app_context,
             Screen::DocumentVisualizerScreen(screen) => screen.app_context = app_context,
             Screen::PlatformInfoScreen(screen) => screen.app_context = app_context,
+            Screen::MessageVerifierScreen(screen) => screen.app_context = app_context,
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.app_context = app_context,
@@ -603,6 +633,9 @@ impl Screen {
             },
             Screen::GroupActionsScreen(_) => ScreenType::GroupActions,
             Screen::AddNewWalletScreen(_) => ScreenType::AddNewWallet,
+            Screen::SignTransactionScreen(_) => ScreenType::SignTransaction,
+            Screen::Slip39ExportScreen(_) => ScreenType::ExportSlip39Shares,
+            Screen::SweepKeyScreen(_) => ScreenType::SweepPrivateKey,
             Screen::WalletsBalancesScreen(_) => ScreenType::WalletsBalances,
             Screen::ImportWalletScreen(_) => ScreenType::ImportWallet,
             Screen::ProofLogScreen(_) => ScreenType::ProofLog,
@@ -610,6 +643,7 @@ impl Screen {
             Screen::ProofVisualizerScreen(_) => ScreenType::ProofVisualizer,
             Screen::DocumentVisualizerScreen(_) => ScreenType::DocumentsVisualizer,
             Screen::PlatformInfoScreen(_) => ScreenType::PlatformInfo,
+            Screen::MessageVerifierScreen(_) => ScreenType::MessageVerifier,
 
             // Token Screens
             Screen::TokensScreen(screen)
@@ -683,6 +717,9 @@ impl ScreenLike for Screen {
             Screen::DPNSScreen(screen) => screen.refresh(),
             Screen::DocumentQueryScreen(screen) => screen.refresh(),
             Screen::AddNewWalletScreen(screen) => screen.refresh(),
+            Screen::SignTransactionScreen(screen) => screen.refresh(),
+            Screen::Slip39ExportScreen(screen) => screen.refresh(),
+            Screen::SweepKeyScreen(screen) => screen.refresh(),
             Screen::ImportWalletScreen(screen) => screen.refresh(),
             Screen::AddNewIdentityScreen(screen) => screen.refresh(),
             Screen::TopUpIdentityScreen(screen) => screen.refresh(),
@@ -706,6 +743,7 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.refresh(),
             Screen::ContractVisualizerScreen(screen) => screen.refresh(),
             Screen::PlatformInfoScreen(screen) => screen.refresh(),
+            Screen::MessageVerifierScreen(screen) => screen.refresh(),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.refresh(),
@@ -732,6 +770,9 @@ impl ScreenLike for Screen {
             Screen::DPNSScreen(screen) => screen.refresh_on_arrival(),
             Screen::DocumentQueryScreen(screen) => screen.refresh_on_arrival(),
             Screen::AddNewWalletScreen(screen) => screen.refresh_on_arrival(),
+            Screen::SignTransactionScreen(screen) => screen.refresh_on_arrival(),
+            Screen::Slip39ExportScreen(screen) => screen.refresh_on_arrival(),
+            Screen::SweepKeyScreen(screen) => screen.refresh_on_arrival(),
             Screen::ImportWalletScreen(screen) => screen.refresh_on_arrival(),
             Screen::AddNewIdentityScreen(screen) => screen.refresh_on_arrival(),
             Screen::TopUpIdentityScreen(screen) => screen.refresh_on_arrival(),
@@ -755,6 +796,7 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.refresh_on_arrival(),
             Screen::ContractVisualizerScreen(screen) => screen.refresh_on_arrival(),
             Screen::PlatformInfoScreen(screen) => screen.refresh_on_arrival(),
+            Screen::MessageVerifierScreen(screen) => screen.refresh_on_arrival(),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.refresh_on_arrival(),
@@ -781,6 +823,9 @@ impl ScreenLike for Screen {
             Screen::DPNSScreen(screen) => screen.ui(ctx),
             Screen::DocumentQueryScreen(screen) => screen.ui(ctx),
             Screen::AddNewWalletScreen(screen) => screen.ui(ctx),
+            Screen::SignTransactionScreen(screen) => screen.ui(ctx),
+            Screen::Slip39ExportScreen(screen) => screen.ui(ctx),
+            Screen::SweepKeyScreen(screen) => screen.ui(ctx),
             Screen::ImportWalletScreen(screen) => screen.ui(ctx),
             Screen::AddNewIdentityScreen(screen) => screen.ui(ctx),
             Screen::TopUpIdentityScreen(screen) => screen.ui(ctx),
@@ -804,6 +849,7 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.ui(ctx),
             Screen::ContractVisualizerScreen(screen) => screen.ui(ctx),
             Screen::PlatformInfoScreen(screen) => screen.ui(ctx),
+            Screen::MessageVerifierScreen(screen) => screen.ui(ctx),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.ui(ctx),
@@ -830,6 +876,9 @@ impl ScreenLike for Screen {
             Screen::DPNSScreen(screen) => screen.display_message(message, message_type),
             Screen::DocumentQueryScreen(screen) => screen.display_message(message, message_type),
             Screen::AddNewWalletScreen(screen) => screen.display_message(message, message_type),
+            Screen::SignTransactionScreen(screen) => screen.display_message(message, message_type),
+            Screen::Slip39ExportScreen(screen) => screen.display_message(message, message_type),
+            Screen::SweepKeyScreen(screen) => screen.display_message(message, message_type),
             Screen::ImportWalletScreen(screen) => screen.display_message(message, message_type),
             Screen::AddNewIdentityScreen(screen) => screen.display_message(message, message_type),
             Screen::TopUpIdentityScreen(screen) => screen.display_message(message, message_type),
@@ -865,6 +914,7 @@ impl ScreenLike for Screen {
                 screen.display_message(message, message_type)
             }
             Screen::PlatformInfoScreen(screen) => screen.display_message(message, message_type),
+            Screen::MessageVerifierScreen(screen) => screen.display_message(message, message_type),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.display_message(message, message_type),
@@ -901,6 +951,15 @@ impl ScreenLike for Screen {
             Screen::AddNewWalletScreen(screen) => {
                 screen.display_task_result(backend_task_success_result)
             }
+            Screen::SignTransactionScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
+            Screen::Slip39ExportScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
+            Screen::SweepKeyScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
             Screen::ImportWalletScreen(screen) => {
                 screen.display_task_result(backend_task_success_result)
             }
@@ -966,6 +1025,9 @@ impl ScreenLike for Screen {
             Screen::PlatformInfoScreen(screen) => {
                 screen.display_task_result(backend_task_success_result)
             }
+            Screen::MessageVerifierScreen(screen) => {
+                screen.display_task_result(backend_task_success_result)
+            }
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.display_task_result(backend_task_success_result),
@@ -1018,6 +1080,9 @@ impl ScreenLike for Screen {
             Screen::DPNSScreen(screen) => screen.pop_on_success(),
             Screen::DocumentQueryScreen(screen) => screen.pop_on_success(),
             Screen::AddNewWalletScreen(screen) => screen.pop_on_success(),
+            Screen::SignTransactionScreen(screen) => screen.pop_on_success(),
+            Screen::Slip39ExportScreen(screen) => screen.pop_on_success(),
+            Screen::SweepKeyScreen(screen) => screen.pop_on_success(),
             Screen::ImportWalletScreen(screen) => screen.pop_on_success(),
             Screen::AddNewIdentityScreen(screen) => screen.pop_on_success(),
             Screen::TopUpIdentityScreen(screen) => screen.pop_on_success(),
@@ -1041,6 +1106,7 @@ impl ScreenLike for Screen {
             Screen::DocumentVisualizerScreen(screen) => screen.pop_on_success(),
             Screen::ContractVisualizerScreen(screen) => screen.pop_on_success(),
             Screen::PlatformInfoScreen(screen) => screen.pop_on_success(),
+            Screen::MessageVerifierScreen(screen) => screen.pop_on_success(),
 
             // Token Screens
             Screen::TokensScreen(screen) => screen.pop_on_success(),
@@ -1,23 +1,26 @@
 use crate::app::AppAction;
 use crate::backend_task::core::{CoreItem, CoreTask};
-use crate::backend_task::system_task::SystemTask;
+use crate::backend_task::system_task::{BackupFile, SystemTask};
 use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
-use crate::config::Config;
+use crate::config::{ChainDataSource, Config};
 use crate::context::AppContext;
+use crate::model::wallet::fee_estimation::MIN_RELAY_FEE_RATE;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::{
     ClickableCollapsingHeader, StyledCard, StyledCheckbox, island_central_panel,
 };
 use crate::ui::components::top_panel::add_top_panel;
 use crate::ui::theme::{DashColors, ThemeMode};
-use crate::ui::{RootScreenType, ScreenLike};
+use crate::ui::{MessageType, RootScreenType, ScreenLike};
 use crate::utils::path::format_path_for_display;
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::dpp::identity::TimestampMillis;
 use eframe::egui::{self, Context, Ui};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use zeroize::Zeroize;
 
 pub struct NetworkChooserScreen {
     pub mainnet_app_context: Arc<AppContext>,
@@ -36,6 +39,12 @@ pub struct NetworkChooserScreen {
     overwrite_dash_conf: bool,
     developer_mode: bool,
     theme_preference: ThemeMode,
+    fallback_fee_rate_input: String,
+    fallback_fee_rate_error_message: Option<String>,
+    backup_password_input: String,
+    backup_password_confirmation_input: String,
+    backup_message: Option<(String, MessageType)>,
+    backup_in_progress: bool,
     should_reset_collapsing_states: bool,
 }
 
@@ -76,6 +85,14 @@ impl NetworkChooserScreen {
             .map(|(_, _, _, _, _, theme)| theme)
             .unwrap_or(ThemeMode::System);
 
+        let fallback_fee_rate_input = current_context
+            .db
+            .get_fallback_fee_rate()
+            .ok()
+            .flatten()
+            .map(|fee_rate| fee_rate.to_string())
+            .unwrap_or_default();
+
         Self {
             mainnet_app_context: mainnet_app_context.clone(),
             testnet_app_context: testnet_app_context.cloned(),
@@ -93,6 +110,12 @@ impl NetworkChooserScreen {
             overwrite_dash_conf,
             developer_mode,
             theme_preference,
+            fallback_fee_rate_input,
+            fallback_fee_rate_error_message: None,
+            backup_password_input: String::new(),
+            backup_password_confirmation_input: String::new(),
+            backup_message: None,
+            backup_in_progress: false,
             should_reset_collapsing_states: true, // Start with collapsed state
         }
     }
@@ -129,6 +152,178 @@ impl NetworkChooserScreen {
             )
             .map_err(|e| e.to_string())
     }
+    /// Save the fallback fee rate and re-estimate the fee rate of the current network
+    fn save_fallback_fee_rate(&mut self) {
+        let input = self.fallback_fee_rate_input.trim();
+        let fallback_fee_rate = if input.is_empty() {
+            None
+        } else {
+            match input.parse::<u64>() {
+                Ok(fee_rate) => Some(fee_rate),
+                Err(_) => {
+                    self.fallback_fee_rate_error_message =
+                        Some("Fee rate must be a whole number of duffs per kB".to_string());
+                    return;
+                }
+            }
+        };
+
+        let app_context = self.current_app_context().clone();
+        match app_context.db.update_fallback_fee_rate(fallback_fee_rate) {
+            Ok(()) => {
+                app_context.clear_cached_fee_rate();
+                self.fallback_fee_rate_error_message = None;
+            }
+            Err(e) => self.fallback_fee_rate_error_message = Some(e.to_string()),
+        }
+    }
+
+    fn export_backup(&self) -> Result<Option<SystemTask>, String> {
+        if self.backup_password_input.is_empty() {
+            return Err("Enter a password to encrypt the backup with".to_string());
+        }
+        if self.backup_password_input != self.backup_password_confirmation_input {
+            return Err("The passwords do not match".to_string());
+        }
+
+        let file_name = format!(
+            "dash-evo-tool-backup-{}.detbackup",
+            chrono::Utc::now().format("%Y%m%d_%H%M%S")
+        );
+        let Some(path) = rfd::FileDialog::new().set_file_name(file_name).save_file() else {
+            return Ok(None);
+        };
+
+        Ok(Some(SystemTask::ExportBackup(BackupFile {
+            path,
+            password: self.backup_password_input.clone(),
+        })))
+    }
+
+    fn restore_backup(&self) -> Result<Option<SystemTask>, String> {
+        if self.backup_password_input.is_empty() {
+            return Err("Enter the password the backup was encrypted with".to_string());
+        }
+        let Some(path) = rfd::FileDialog::new().pick_file() else {
+            return Ok(None);
+        };
+
+        Ok(Some(SystemTask::RestoreBackup(BackupFile {
+            path,
+            password: self.backup_password_input.clone(),
+        })))
+    }
+
+    fn backup_finished(&mut self, message: String) {
+        self.backup_in_progress = false;
+        self.backup_message = Some((message, MessageType::Success));
+        self.backup_password_input.zeroize();
+        self.backup_password_confirmation_input.zeroize();
+    }
+
+    fn backup_restored(&mut self, restored: BTreeMap<String, usize>) {
+        // All networks share the database, so each of them may have new wallets
+        for app_context in [
+            Some(&self.mainnet_app_context),
+            self.testnet_app_context.as_ref(),
+            self.devnet_app_context.as_ref(),
+            self.local_app_context.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if let Err(e) = app_context.load_new_wallets() {
+                self.backup_in_progress = false;
+                self.backup_message = Some((e.to_string(), MessageType::Error));
+                return;
+            }
+        }
+
+        let count: usize = restored.values().sum();
+        self.backup_finished(if count == 0 {
+            "Everything in the backup was already present".to_string()
+        } else {
+            let details: Vec<String> = restored
+                .iter()
+                .filter(|(_, count)| **count > 0)
+                .map(|(table, count)| format!("{} {}", count, table.replace('_', " ")))
+                .collect();
+            format!("Restored {}", details.join(", "))
+        });
+    }
+
+    fn render_backup_section(&mut self, ui: &mut Ui, dark_mode: bool) -> AppAction {
+        let mut action = AppAction::None;
+        ui.label(
+            egui::RichText::new("Backup")
+                .strong()
+                .color(DashColors::text_primary(dark_mode)),
+        );
+        ui.label(
+            egui::RichText::new(
+                "Wallets, local identities with their keys, saved contracts, tokens and scheduled votes of all networks, in one password protected file",
+            )
+            .color(DashColors::TEXT_SECONDARY),
+        );
+        ui.add_space(8.0);
+
+        egui::Grid::new("backup_password_grid")
+            .num_columns(2)
+            .spacing([10.0, 5.0])
+            .show(ui, |ui| {
+                ui.label("Backup Password:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.backup_password_input)
+                        .password(true)
+                        .desired_width(200.0),
+                );
+                ui.end_row();
+
+                ui.label("Confirm (export only):");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.backup_password_confirmation_input)
+                        .password(true)
+                        .desired_width(200.0),
+                );
+                ui.end_row();
+            });
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(!self.backup_in_progress, |ui| {
+                let mut result = None;
+                if ui.button("Export Backup").clicked() {
+                    result = Some(self.export_backup());
+                }
+                if ui.button("Restore Backup").clicked() {
+                    result = Some(self.restore_backup());
+                }
+                match result {
+                    Some(Ok(Some(task))) => {
+                        self.backup_in_progress = true;
+                        self.backup_message = None;
+                        action = AppAction::BackendTask(BackendTask::SystemTask(task));
+                    }
+                    Some(Err(e)) => self.backup_message = Some((e, MessageType::Error)),
+                    Some(Ok(None)) | None => {}
+                }
+            });
+            if self.backup_in_progress {
+                ui.spinner();
+            }
+        });
+
+        if let Some((message, message_type)) = &self.backup_message {
+            let color = match message_type {
+                MessageType::Error => DashColors::ERROR,
+                _ => DashColors::SUCCESS,
+            };
+            ui.colored_label(color, message);
+        }
+
+        action
+    }
+
     /// Render the network selection table
     fn render_network_table(&mut self, ui: &mut Ui) -> AppAction {
         let mut app_action = AppAction::None;
@@ -171,6 +366,12 @@ impl NetworkChooserScreen {
                         .underline()
                         .color(DashColors::text_primary(dark_mode)),
                 );
+                ui.label(
+                    egui::RichText::new("Wallet Data")
+                        .strong()
+                        .underline()
+                        .color(DashColors::text_primary(dark_mode)),
+                );
                 ui.label(
                     egui::RichText::new("Actions")
                         .strong()
@@ -446,6 +647,45 @@ impl NetworkChooserScreen {
                             });
                         });
 
+                        // Fee Rate Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.horizontal(|ui| {
+                                    ui.label(
+                                        egui::RichText::new("Fallback fee rate (duffs/kB):")
+                                            .strong()
+                                            .color(DashColors::text_primary(dark_mode)),
+                                    );
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.fallback_fee_rate_input)
+                                            .hint_text(MIN_RELAY_FEE_RATE.to_string())
+                                            .desired_width(100.0),
+                                    );
+                                    if ui.button("Save").clicked() {
+                                        self.save_fallback_fee_rate();
+                                    }
+                                });
+                                ui.label(
+                                    egui::RichText::new(
+                                        "Used for wallet transactions when Dash Core can't estimate a fee rate",
+                                    )
+                                    .color(DashColors::TEXT_SECONDARY),
+                                );
+                                if let Some(error) = &self.fallback_fee_rate_error_message {
+                                    ui.colored_label(DashColors::ERROR, error);
+                                }
+                            });
+                        });
+
+                        // Backup Section
+                        ui.add_space(16.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                app_action |= self.render_backup_section(ui, dark_mode);
+                            });
+                        });
+
                         // Configuration Requirements Section (only show if not overwriting dash.conf)
                         if !self.overwrite_dash_conf {
                             ui.add_space(16.0);
@@ -634,6 +874,8 @@ impl NetworkChooserScreen {
             ui.label("");
         }
 
+        self.render_chain_data_source(ui, network);
+
         if network == Network::Devnet {
             if ui.button("Clear local Platform data").clicked() {
                 app_action =
@@ -647,6 +889,45 @@ impl NetworkChooserScreen {
         app_action
     }
 
+    /// Lets the user pick where wallet data for `network` comes from and saves the choice
+    fn render_chain_data_source(&self, ui: &mut Ui, network: Network) {
+        let app_context = self.context_for_network(network);
+        let current = app_context.config.read().unwrap().chain_data_source;
+        let mut selected = current;
+        egui::ComboBox::from_id_salt(format!("chain_data_source_{}", network))
+            .selected_text(selected.label())
+            .show_ui(ui, |ui| {
+                for source in [
+                    ChainDataSource::CoreRpc,
+                    ChainDataSource::CoreUtxoScan,
+                    ChainDataSource::Insight,
+                ] {
+                    ui.selectable_value(&mut selected, source, source.label());
+                }
+            })
+            .response
+            .on_hover_text("Where wallet balances and UTXOs are loaded from and transactions are broadcast to. Dash Core imports wallet addresses into its own wallet and rescans. The UTXO set scan needs no Core wallet, but only finds unspent funds. Use the Insight API when no Dash Core is running.");
+
+        if selected == current {
+            return;
+        }
+
+        let updated_config = {
+            let mut config = app_context.config.write().unwrap();
+            config.chain_data_source = selected;
+            config.clone()
+        };
+        match Config::load() {
+            Ok(mut config) => {
+                config.update_config_for_network(network, updated_config);
+                if let Err(e) = config.save() {
+                    eprintln!("Failed to save config to .env: {e}");
+                }
+            }
+            Err(e) => eprintln!("Failed to load config: {e}"),
+        }
+    }
+
     /// Check if the network is working
     fn check_network_status(&self, network: Network) -> bool {
         match network {
@@ -675,17 +956,31 @@ impl ScreenLike for NetworkChooserScreen {
         }
     }
 
-    fn display_message(&mut self, message: &str, _message_type: super::MessageType) {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
         if message.contains("Failed to get best chain lock for mainnet, testnet, devnet, and local")
         {
             self.mainnet_core_status_online = false;
             self.testnet_core_status_online = false;
             self.devnet_core_status_online = false;
             self.local_core_status_online = false;
+        } else if self.backup_in_progress && message_type == MessageType::Error {
+            self.backup_in_progress = false;
+            self.backup_message = Some((message.to_string(), MessageType::Error));
         }
     }
 
     fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        match backend_task_success_result {
+            BackendTaskSuccessResult::ExportedBackup(path) => {
+                self.backup_finished(format!("Backup saved to {}", path.display()));
+                return;
+            }
+            BackendTaskSuccessResult::RestoredBackup(restored) => {
+                self.backup_restored(restored);
+                return;
+            }
+            _ => {}
+        }
         if let BackendTaskSuccessResult::CoreItem(CoreItem::ChainLocks(
             mainnet_chainlock,
             testnet_chainlock,
@@ -1,10 +1,11 @@
 use super::tokens_screen::IdentityTokenInfo;
 use crate::app::AppAction;
-use crate::backend_task::BackendTask;
 use crate::backend_task::tokens::TokenTask;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
+use crate::ui::components::identity_recipient_input::RecipientInput;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
@@ -54,7 +55,7 @@ pub struct FreezeTokensScreen {
     pub group_action_id: Option<Identifier>,
 
     /// The identity we want to freeze
-    pub freeze_identity_id: String,
+    pub freeze_identity: RecipientInput,
 
     status: FreezeTokensStatus,
     error_message: Option<String>,
@@ -182,7 +183,7 @@ impl FreezeTokensScreen {
             is_unilateral_group_member,
             group_action_id: None,
             public_note: None,
-            freeze_identity_id: String::new(),
+            freeze_identity: RecipientInput::default(),
             status: FreezeTokensStatus::NotStarted,
             error_message,
             app_context: app_context.clone(),
@@ -194,11 +195,8 @@ impl FreezeTokensScreen {
     }
 
     /// Renders text input for the identity to freeze
-    fn render_freeze_identity_input(&mut self, ui: &mut Ui) {
-        ui.horizontal(|ui| {
-            ui.label("Freeze Identity ID:");
-            ui.text_edit_singleline(&mut self.freeze_identity_id);
-        });
+    fn render_freeze_identity_input(&mut self, ui: &mut Ui) -> AppAction {
+        self.freeze_identity.show(ui, "Identity to freeze:")
     }
 
     /// Confirmation popup
@@ -210,24 +208,21 @@ impl FreezeTokensScreen {
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
                 // Validate user input
-                let parsed = Identifier::from_string_try_encodings(
-                    &self.freeze_identity_id,
-                    &[
-                        dash_sdk::dpp::platform_value::string_encoding::Encoding::Base58,
-                        dash_sdk::dpp::platform_value::string_encoding::Encoding::Hex,
-                    ],
-                );
-                if parsed.is_err() {
-                    self.error_message = Some("Please enter a valid identity ID.".into());
-                    self.status = FreezeTokensStatus::ErrorMessage("Invalid identity".into());
-                    self.show_confirmation_popup = false;
-                    return;
-                }
-                let freeze_id = parsed.unwrap();
+                let freeze_id = match self.freeze_identity.identifier() {
+                    Ok(freeze_id) => freeze_id,
+                    Err(e) => {
+                        self.error_message =
+                            Some("Please enter a valid identity ID or name.".into());
+                        self.status =
+                            FreezeTokensStatus::ErrorMessage(format!("Invalid identity: {}", e));
+                        self.show_confirmation_popup = false;
+                        return;
+                    }
+                };
 
                 ui.label(format!(
                     "Are you sure you want to freeze identity {}?",
-                    self.freeze_identity_id
+                    self.freeze_identity.description()
                 ));
 
                 ui.add_space(10.0);
@@ -354,6 +349,15 @@ impl ScreenLike for FreezeTokensScreen {
         }
     }
 
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if !self
+            .freeze_identity
+            .display_task_result(&backend_task_success_result)
+        {
+            self.display_message("Success", MessageType::Success);
+        }
+    }
+
     fn refresh(&mut self) {
         // Reload identity if needed
         if let Ok(all_identities) = self.app_context.load_local_user_identities() {
@@ -487,16 +491,16 @@ impl ScreenLike for FreezeTokensScreen {
                 ui.add_space(10.0);
 
                 // 2) Identity to freeze
-                ui.heading("2. Enter the identity ID to freeze");
+                ui.heading("2. Enter the ID or name of the identity to freeze");
                 ui.add_space(5.0);
                 if self.group_action_id.is_some() {
                     ui.label(
                         "You are signing an existing group Freeze so you are not allowed to choose the identity.",
                     );
                     ui.add_space(5.0);
-                    ui.label(format!("Identity: {}", self.freeze_identity_id));
+                    ui.label(format!("Identity: {}", self.freeze_identity.description()));
                 } else {
-                    self.render_freeze_identity_input(ui);
+                    action |= self.render_freeze_identity_input(ui);
                 }
 
                 ui.add_space(10.0);
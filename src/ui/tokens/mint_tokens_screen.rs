@@ -1,9 +1,10 @@
 use super::tokens_screen::IdentityTokenInfo;
 use crate::app::AppAction;
-use crate::backend_task::BackendTask;
 use crate::backend_task::tokens::TokenTask;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::wallet::Wallet;
+use crate::ui::components::identity_recipient_input::RecipientInput;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
@@ -27,7 +28,6 @@ use dash_sdk::dpp::data_contract::group::accessors::v0::GroupV0Getters;
 use dash_sdk::dpp::group::{GroupStateTransitionInfo, GroupStateTransitionInfoStatus};
 use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
 use dash_sdk::dpp::identity::{KeyType, Purpose, SecurityLevel};
-use dash_sdk::dpp::platform_value::string_encoding::Encoding;
 use dash_sdk::platform::{Identifier, IdentityPublicKey};
 use eframe::egui::{self, Color32, Context, Ui};
 use egui::RichText;
@@ -53,7 +53,7 @@ pub struct MintTokensScreen {
     is_unilateral_group_member: bool,
     pub group_action_id: Option<Identifier>,
 
-    pub recipient_identity_id: String,
+    recipient: RecipientInput,
 
     pub amount_to_mint: String,
     status: MintTokensStatus,
@@ -181,7 +181,7 @@ impl MintTokensScreen {
             group,
             is_unilateral_group_member,
             group_action_id: None,
-            recipient_identity_id: "".to_string(),
+            recipient: RecipientInput::default(),
             amount_to_mint: "".to_string(),
             status: MintTokensStatus::NotStarted,
             error_message,
@@ -205,13 +205,9 @@ impl MintTokensScreen {
     }
 
     /// Renders an optional text input for the user to specify a "Recipient Identity"
-    fn render_recipient_input(&mut self, ui: &mut Ui) {
-        ui.horizontal(|ui| {
-            ui.label("Recipient:");
-            ui.text_edit_singleline(&mut self.recipient_identity_id);
-        });
-
+    fn render_recipient_input(&mut self, ui: &mut Ui) -> AppAction {
         // If empty, minted tokens go to the 'issuer' identity (self.identity).
+        self.recipient.show(ui, "Recipient:")
     }
 
     /// Renders a confirm popup with the final "Are you sure?" step
@@ -231,19 +227,18 @@ impl MintTokensScreen {
                     return;
                 }
 
-                let maybe_identifier = if self.recipient_identity_id.trim().is_empty() {
+                let maybe_identifier = if self.recipient.is_empty() {
                     None
                 } else {
-                    // Attempt to parse from base58 or hex
-                    match Identifier::from_string_try_encodings(
-                        &self.recipient_identity_id,
-                        &[Encoding::Base58, Encoding::Hex],
-                    ) {
+                    // Attempt to parse from base58 or hex, or look up the entered name
+                    match self.recipient.identifier() {
                         Ok(id) => Some(id),
-                        Err(_) => {
-                            self.error_message = Some("Invalid recipient identity format.".into());
-                            self.status =
-                                MintTokensStatus::ErrorMessage("Invalid recipient identity".into());
+                        Err(e) => {
+                            self.error_message = Some(e.clone());
+                            self.status = MintTokensStatus::ErrorMessage(format!(
+                                "Invalid recipient identity: {}",
+                                e
+                            ));
                             self.show_confirmation_popup = false;
                             return;
                         }
@@ -256,11 +251,8 @@ impl MintTokensScreen {
                 ));
 
                 // If user provided a recipient:
-                if let Some(ref recipient_id) = maybe_identifier {
-                    ui.label(format!(
-                        "Recipient: {}",
-                        recipient_id.to_string(Encoding::Base58)
-                    ));
+                if maybe_identifier.is_some() {
+                    ui.label(format!("Recipient: {}", self.recipient.description()));
                 } else {
                     ui.label("No recipient specified; tokens will be minted to default identity.");
                 }
@@ -389,6 +381,15 @@ impl ScreenLike for MintTokensScreen {
         }
     }
 
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if !self
+            .recipient
+            .display_task_result(&backend_task_success_result)
+        {
+            self.display_message("Success", MessageType::Success);
+        }
+    }
+
     fn refresh(&mut self) {
         // If you need to reload local identity data or re-check keys:
         if let Ok(all_identities) = self.app_context.load_local_user_identities() {
@@ -571,7 +572,7 @@ impl ScreenLike for MintTokensScreen {
                         ui.heading("3. Recipient identity (required)");
                     }
                     ui.add_space(5.0);
-                    self.render_recipient_input(ui);
+                    action |= self.render_recipient_input(ui);
                 }
 
                 ui.add_space(10.0);
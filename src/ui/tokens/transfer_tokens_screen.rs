@@ -1,9 +1,10 @@
 use crate::app::{AppAction, BackendTasksExecutionMode};
-use crate::backend_task::BackendTask;
 use crate::backend_task::tokens::TokenTask;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
 use crate::model::qualified_identity::QualifiedIdentity;
 use crate::model::wallet::Wallet;
+use crate::ui::components::identity_recipient_input::RecipientInput;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::tokens_subscreen_chooser_panel::add_tokens_subscreen_chooser_panel;
@@ -121,7 +122,7 @@ pub struct TransferTokensScreen {
     selected_friend_index: Option<usize>,
     selected_key: Option<IdentityPublicKey>,
     pub public_note: Option<String>,
-    pub receiver_identity_id: String,
+    receiver: RecipientInput,
     pub amount: String,
     transfer_tokens_status: TransferTokensStatus,
     max_amount: u64,
@@ -170,12 +171,13 @@ impl TransferTokensScreen {
         let selected_wallet =
             get_selected_wallet(&identity, None, selected_key, &mut error_message);
 
-        let (selected_friend_index, receiver_identity_id) =
-            if let Some((_first, identifier)) = friend_identities.first() {
-                (Some(0), identifier.to_string(Encoding::Base58))
-            } else {
-                (None, String::new())
-            };
+        let mut receiver = RecipientInput::default();
+        let selected_friend_index = if let Some((_first, identifier)) = friend_identities.first() {
+            receiver.set_identity_id(*identifier);
+            Some(0)
+        } else {
+            None
+        };
         Self {
             identity,
             identity_token_balance,
@@ -183,7 +185,7 @@ impl TransferTokensScreen {
             selected_friend_index,
             selected_key: selected_key.cloned(),
             public_note: None,
-            receiver_identity_id,
+            receiver,
             amount: String::new(),
             transfer_tokens_status: TransferTokensStatus::NotStarted,
             max_amount,
@@ -212,7 +214,7 @@ impl TransferTokensScreen {
         });
     }
 
-    fn render_to_identity_input(&mut self, ui: &mut Ui) {
+    fn render_to_identity_input(&mut self, ui: &mut Ui) -> AppAction {
         ui.horizontal(|ui| {
             // Dropdown
             egui::ComboBox::from_id_salt("friend_selector")
@@ -227,8 +229,7 @@ impl TransferTokensScreen {
                             .selectable_value(&mut self.selected_friend_index, Some(i), alias)
                             .clicked()
                         {
-                            self.receiver_identity_id =
-                                self.friend_identities[i].1.to_string(Encoding::Base58);
+                            self.receiver.set_identity_id(self.friend_identities[i].1);
                         }
                     }
 
@@ -237,17 +238,18 @@ impl TransferTokensScreen {
                         .clicked()
                     {
                         // Clear the text box to avoid confusion
-                        self.receiver_identity_id.clear();
+                        self.receiver.clear();
                     }
                 });
-
-            // Text box
-            let prev_text = self.receiver_identity_id.clone();
-            ui.text_edit_singleline(&mut self.receiver_identity_id);
-            if self.receiver_identity_id != prev_text {
-                self.selected_friend_index = None;
-            }
         });
+
+        // Text box, taking an identity ID or a DPNS name
+        let prev_receiver = self.receiver.description();
+        let action = self.receiver.show(ui, "Recipient:");
+        if self.receiver.description() != prev_receiver {
+            self.selected_friend_index = None;
+        }
+        action
     }
 
     fn show_confirmation_popup(&mut self, ui: &mut Ui) -> AppAction {
@@ -257,24 +259,12 @@ impl TransferTokensScreen {
             .collapsible(false)
             .open(&mut is_open)
             .show(ui.ctx(), |ui| {
-                let identifier = if self.receiver_identity_id.is_empty() {
-                    self.transfer_tokens_status =
-                        TransferTokensStatus::ErrorMessage("Invalid identifier".to_string());
-                    self.confirmation_popup = false;
-                    return;
-                } else {
-                    match Identifier::from_string_try_encodings(
-                        &self.receiver_identity_id,
-                        &[Encoding::Base58, Encoding::Hex],
-                    ) {
-                        Ok(identifier) => identifier,
-                        Err(_) => {
-                            self.transfer_tokens_status = TransferTokensStatus::ErrorMessage(
-                                "Invalid identifier".to_string(),
-                            );
-                            self.confirmation_popup = false;
-                            return;
-                        }
+                let identifier = match self.receiver.identifier() {
+                    Ok(identifier) => identifier,
+                    Err(e) => {
+                        self.transfer_tokens_status = TransferTokensStatus::ErrorMessage(e);
+                        self.confirmation_popup = false;
+                        return;
                     }
                 };
 
@@ -287,7 +277,9 @@ impl TransferTokensScreen {
 
                 ui.label(format!(
                     "Are you sure you want to transfer {} {} to {}?",
-                    self.amount, self.identity_token_balance.token_alias, self.receiver_identity_id
+                    self.amount,
+                    self.identity_token_balance.token_alias,
+                    self.receiver.description()
                 ));
 
                 if ui.button("Confirm").clicked() {
@@ -379,6 +371,15 @@ impl ScreenLike for TransferTokensScreen {
         }
     }
 
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if !self
+            .receiver
+            .display_task_result(&backend_task_success_result)
+        {
+            self.display_message("Success", MessageType::Success);
+        }
+    }
+
     fn refresh(&mut self) {
         // Refresh the identity because there might be new keys
         self.identity = self
@@ -428,6 +429,7 @@ impl ScreenLike for TransferTokensScreen {
 
         let central_panel_action = island_central_panel(ctx, |ui| {
             let dark_mode = ui.ctx().style().visuals.dark_mode;
+            let mut inner_action = AppAction::None;
 
             // Show the success screen if the transfer was successful
             if self.transfer_tokens_status == TransferTokensStatus::Complete {
@@ -534,10 +536,10 @@ impl ScreenLike for TransferTokensScreen {
                 ui.separator();
                 ui.add_space(10.0);
 
-                // Input the ID of the identity to transfer to
-                ui.heading("3. ID of the identity to transfer to");
+                // Input the ID or name of the identity to transfer to
+                ui.heading("3. ID or name of the identity to transfer to");
                 ui.add_space(5.0);
-                self.render_to_identity_input(ui);
+                inner_action |= self.render_to_identity_input(ui);
 
                 ui.add_space(10.0);
                 ui.separator();
@@ -597,7 +599,8 @@ impl ScreenLike for TransferTokensScreen {
                 }
 
                 if self.confirmation_popup {
-                    return self.show_confirmation_popup(ui);
+                    inner_action |= self.show_confirmation_popup(ui);
+                    return inner_action;
                 }
 
                 // Handle transfer status messages
@@ -648,7 +651,7 @@ impl ScreenLike for TransferTokensScreen {
                 }
             }
 
-            AppAction::None
+            inner_action
         });
         action |= central_panel_action;
         action
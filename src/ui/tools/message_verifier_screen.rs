@@ -0,0 +1,152 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::signed_message::verify_message;
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::tools_subscreen_chooser_panel::add_tools_subscreen_chooser_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{RootScreenType, ScreenLike};
+use dash_sdk::dpp::dashcore::Address;
+use eframe::egui::{self, Context, ScrollArea, TextEdit, Ui};
+use egui::Color32;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Checks Dash signed messages, e.g. proofs of ownership of an address.
+pub struct MessageVerifierScreen {
+    pub app_context: Arc<AppContext>,
+    address_input: String,
+    message_input: String,
+    signature_input: String,
+    /// Outcome of the last check: whether the signature is valid, or why it couldn't be checked
+    result: Option<Result<bool, String>>,
+}
+
+impl MessageVerifierScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            address_input: String::new(),
+            message_input: String::new(),
+            signature_input: String::new(),
+            result: None,
+        }
+    }
+
+    fn verify(&self) -> Result<bool, String> {
+        let network = self.app_context.network;
+        let address = Address::from_str(self.address_input.trim())
+            .map_err(|e| format!("Invalid address: {}", e))?
+            .require_network(network)
+            .map_err(|_| format!("Address is not valid for network {}", network))?;
+        verify_message(&address, &self.message_input, &self.signature_input)
+    }
+
+    fn show_inputs(&mut self, ui: &mut Ui) {
+        let mut changed = false;
+
+        ui.label("Address:");
+        changed |= ui
+            .add(
+                TextEdit::singleline(&mut self.address_input)
+                    .hint_text("Address that signed the message")
+                    .desired_width(ui.available_width()),
+            )
+            .changed();
+        ui.add_space(10.0);
+
+        ui.label("Message:");
+        changed |= ui
+            .add(
+                TextEdit::multiline(&mut self.message_input)
+                    .desired_rows(4)
+                    .desired_width(ui.available_width()),
+            )
+            .changed();
+        ui.add_space(10.0);
+
+        ui.label("Signature (base64):");
+        changed |= ui
+            .add(
+                TextEdit::singleline(&mut self.signature_input)
+                    .font(egui::TextStyle::Monospace)
+                    .desired_width(ui.available_width()),
+            )
+            .changed();
+        ui.add_space(10.0);
+
+        // An outcome shown for other inputs would be misleading
+        if changed {
+            self.result = None;
+        }
+
+        let can_verify =
+            !self.address_input.trim().is_empty() && !self.signature_input.trim().is_empty();
+        if ui
+            .add_enabled(can_verify, egui::Button::new("Verify"))
+            .clicked()
+        {
+            self.result = Some(self.verify());
+        }
+    }
+
+    fn show_result(&self, ui: &mut Ui) {
+        let Some(result) = &self.result else {
+            return;
+        };
+        ui.add_space(10.0);
+        match result {
+            Ok(true) => {
+                ui.colored_label(
+                    Color32::DARK_GREEN,
+                    "Signature is valid: the message was signed by this address.",
+                );
+            }
+            Ok(false) => {
+                ui.colored_label(
+                    Color32::DARK_RED,
+                    "Signature is not valid for this address and message.",
+                );
+            }
+            Err(e) => {
+                ui.colored_label(Color32::DARK_RED, e);
+            }
+        }
+    }
+}
+
+impl ScreenLike for MessageVerifierScreen {
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![("Tools", AppAction::None)],
+            vec![],
+        );
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            RootScreenType::RootScreenToolsMessageVerifierScreen,
+        );
+
+        action |= add_tools_subscreen_chooser_panel(ctx, self.app_context.as_ref());
+
+        action |= island_central_panel(ctx, |ui| {
+            ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Verify Signed Message");
+                ui.label(
+                    "Check a message signed with a Dash address, e.g. by this app or by Dash Core's signmessage.",
+                );
+                ui.separator();
+                ui.add_space(10.0);
+
+                self.show_inputs(ui);
+                self.show_result(ui);
+            });
+            AppAction::None
+        });
+
+        action
+    }
+}
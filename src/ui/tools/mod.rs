@@ -1,5 +1,6 @@
 pub mod contract_visualizer_screen;
 pub mod document_visualizer_screen;
+pub mod message_verifier_screen;
 pub mod platform_info_screen;
 pub mod proof_log_screen;
 pub mod proof_visualizer_screen;
@@ -1,11 +1,13 @@
 use crate::app::AppAction;
 use crate::context::AppContext;
 use crate::ui::ScreenLike;
+use crate::ui::components::bip39_passphrase::Bip39PassphraseInput;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
 use eframe::egui::Context;
 
+use crate::model::wallet::accounts::WalletAccount;
 use crate::model::wallet::encryption::{DASH_SECRET_MESSAGE, encrypt_message};
 use crate::model::wallet::{ClosedKeyItem, OpenWalletSeed, Wallet, WalletSeed};
 use crate::ui::components::entropy_grid::U256EntropyGrid;
@@ -56,6 +58,7 @@ pub struct AddNewWalletScreen {
     error: Option<String>,
     pub app_context: Arc<AppContext>,
     use_password_for_app: bool,
+    passphrase_input: Bip39PassphraseInput,
 }
 
 impl AddNewWalletScreen {
@@ -72,6 +75,7 @@ impl AddNewWalletScreen {
             error: None,
             app_context: app_context.clone(),
             use_password_for_app: true,
+            passphrase_input: Bip39PassphraseInput::default(),
         }
     }
 
@@ -87,7 +91,7 @@ impl AddNewWalletScreen {
 
     fn save_wallet(&mut self) -> Result<AppAction, String> {
         if let Some(mnemonic) = &self.seed_phrase {
-            let seed = mnemonic.to_seed("");
+            let seed = mnemonic.to_seed(self.passphrase_input.passphrase()?);
 
             let (encrypted_seed, salt, nonce, uses_password) = if self.password.is_empty() {
                 (seed.to_vec(), vec![], vec![], false)
@@ -134,17 +138,21 @@ impl AddNewWalletScreen {
                         salt,
                         nonce,
                         password_hint: None, // Set a password hint if needed
+                        passphrase_hint: self.passphrase_input.hint(),
                     },
                 }),
                 uses_password,
                 master_bip44_ecdsa_extended_public_key,
-                address_balances: Default::default(),
+                accounts: WalletAccount::default_accounts(master_bip44_ecdsa_extended_public_key),
+                address_total_received: Default::default(),
                 known_addresses: Default::default(),
                 watched_addresses: Default::default(),
                 unused_asset_locks: Default::default(),
                 alias: Some(self.alias_input.clone()),
                 identities: Default::default(),
                 utxos: Default::default(),
+                utxo_statuses: Default::default(),
+                utxo_reservations: Default::default(),
                 is_main: true,
             };
 
@@ -428,7 +436,13 @@ impl ScreenLike for AddNewWalletScreen {
 
                     ui.add_space(20.0);
 
-                    ui.heading("6. Save the wallet.");
+                    ui.heading("6. Add a BIP39 passphrase to the seed phrase. (Optional, for advanced users)");
+                    ui.add_space(8.0);
+                    self.passphrase_input.ui(ui);
+
+                    ui.add_space(20.0);
+
+                    ui.heading("7. Save the wallet.");
                     ui.add_space(5.0);
 
                     // Centered "Save Wallet" button at the bottom
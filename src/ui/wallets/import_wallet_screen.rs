@@ -1,12 +1,18 @@
 use crate::app::AppAction;
+use crate::backend_task::core::{CoreItem, CoreTask};
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
-use crate::ui::ScreenLike;
+use crate::model::wallet::address_discovery::{AddressDiscoveryProgress, DEFAULT_GAP_LIMIT};
+use crate::ui::components::bip39_passphrase::Bip39PassphraseInput;
 use crate::ui::components::left_panel::add_left_panel;
 use crate::ui::components::styled::island_central_panel;
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
 use eframe::egui::Context;
 
+use crate::model::wallet::accounts::WalletAccount;
 use crate::model::wallet::encryption::{DASH_SECRET_MESSAGE, encrypt_message};
+use crate::model::wallet::slip39::{Share, combine_shares};
 use crate::model::wallet::{ClosedKeyItem, OpenWalletSeed, Wallet, WalletSeed};
 use crate::ui::wallets::add_new_wallet_screen::{
     DASH_BIP44_ACCOUNT_0_PATH_MAINNET, DASH_BIP44_ACCOUNT_0_PATH_TESTNET,
@@ -17,11 +23,24 @@ use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
 use dash_sdk::dpp::dashcore::Network;
 use dash_sdk::dpp::dashcore::bip32::{ExtendedPrivKey, ExtendedPubKey};
 use egui::{Color32, ComboBox, Direction, Grid, Layout, RichText, Stroke, Ui, Vec2};
+use std::collections::BTreeMap;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, RwLock};
+use zeroize::Zeroize;
 use zxcvbn::zxcvbn;
 
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum ImportSource {
+    SeedPhrase,
+    /// SLIP-39 shares of a wallet seed exported by Dash Evo Tool
+    Slip39Shares,
+    /// An extended public key, for a watch-only wallet
+    ExtendedPublicKey,
+}
+
 pub struct ImportWalletScreen {
+    import_source: ImportSource,
+    extended_public_key_input: String,
     seed_phrase_words: Vec<String>,
     selected_seed_phrase_length: usize,
     seed_phrase: Option<Mnemonic>,
@@ -32,11 +51,29 @@ pub struct ImportWalletScreen {
     error: Option<String>,
     pub app_context: Arc<AppContext>,
     use_password_for_app: bool,
+    gap_limit_input: String,
+    discovering_addresses: bool,
+    discovery_progress: AddressDiscoveryProgress,
+    discovery_result: Option<(String, MessageType)>,
+    passphrase_input: Bip39PassphraseInput,
+    share_input: String,
+    slip39_shares: Vec<Share>,
+    slip39_passphrase: String,
+    share_error: Option<String>,
+}
+
+impl Drop for ImportWalletScreen {
+    fn drop(&mut self) {
+        self.share_input.zeroize();
+        self.slip39_passphrase.zeroize();
+    }
 }
 
 impl ImportWalletScreen {
     pub fn new(app_context: &Arc<AppContext>) -> Self {
         Self {
+            import_source: ImportSource::SeedPhrase,
+            extended_public_key_input: String::new(),
             seed_phrase_words: vec!["".to_string(); 24],
             selected_seed_phrase_length: 12,
             seed_phrase: None,
@@ -47,94 +84,373 @@ impl ImportWalletScreen {
             error: None,
             app_context: app_context.clone(),
             use_password_for_app: true,
+            gap_limit_input: DEFAULT_GAP_LIMIT.to_string(),
+            discovering_addresses: false,
+            discovery_progress: AddressDiscoveryProgress::default(),
+            discovery_result: None,
+            passphrase_input: Bip39PassphraseInput::default(),
+            share_input: String::new(),
+            slip39_shares: vec![],
+            slip39_passphrase: String::new(),
+            share_error: None,
         }
     }
+    fn gap_limit(&self) -> Result<u32, String> {
+        self.gap_limit_input
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|gap_limit| *gap_limit > 0)
+            .ok_or_else(|| "The gap limit must be a whole number greater than 0".to_string())
+    }
+
     fn save_wallet(&mut self) -> Result<AppAction, String> {
-        if let Some(mnemonic) = &self.seed_phrase {
-            let seed = mnemonic.to_seed("");
-
-            let (encrypted_seed, salt, nonce, uses_password) = if self.password.is_empty() {
-                (seed.to_vec(), vec![], vec![], false)
-            } else {
-                // Encrypt the seed to obtain encrypted_seed, salt, and nonce
-                let (encrypted_seed, salt, nonce) =
-                    ClosedKeyItem::encrypt_seed(&seed, self.password.as_str())?;
-                if self.use_password_for_app {
-                    let (encrypted_message, salt, nonce) =
-                        encrypt_message(DASH_SECRET_MESSAGE, self.password.as_str())?;
-                    self.app_context
-                        .db
-                        .update_main_password(&salt, &nonce, &encrypted_message)
-                        .map_err(|e| e.to_string())?;
-                }
-                (encrypted_seed, salt, nonce, true)
-            };
-
-            // Generate master ECDSA extended private key
-            let master_ecdsa_extended_private_key =
-                ExtendedPrivKey::new_master(self.app_context.network, &seed)
-                    .expect("Failed to create master ECDSA extended private key");
-            let bip44_root_derivation_path: DerivationPath = match self.app_context.network {
-                Network::Dash => DerivationPath::from(DASH_BIP44_ACCOUNT_0_PATH_MAINNET.as_slice()),
-                _ => DerivationPath::from(DASH_BIP44_ACCOUNT_0_PATH_TESTNET.as_slice()),
-            };
-            let secp = Secp256k1::new();
-            let master_bip44_ecdsa_extended_public_key = master_ecdsa_extended_private_key
-                .derive_priv(&secp, &bip44_root_derivation_path)
-                .map_err(|e| e.to_string())?;
-
-            let master_bip44_ecdsa_extended_public_key =
-                ExtendedPubKey::from_priv(&secp, &master_bip44_ecdsa_extended_public_key);
-
-            // Compute the seed hash
-            let seed_hash = ClosedKeyItem::compute_seed_hash(&seed);
-
-            let wallet = Wallet {
-                wallet_seed: WalletSeed::Open(OpenWalletSeed {
-                    seed,
-                    wallet_info: ClosedKeyItem {
-                        seed_hash,
-                        encrypted_seed,
-                        salt,
-                        nonce,
-                        password_hint: None, // Set a password hint if needed
-                    },
-                }),
-                uses_password,
-                master_bip44_ecdsa_extended_public_key,
-                address_balances: Default::default(),
-                known_addresses: Default::default(),
-                watched_addresses: Default::default(),
-                unused_asset_locks: Default::default(),
-                alias: Some(self.alias_input.clone()),
-                identities: Default::default(),
-                utxos: Default::default(),
-                is_main: true,
-            };
-
-            self.app_context
-                .db
-                .store_wallet(&wallet, &self.app_context.network)
-                .map_err(|e| {
-                    if e.to_string().contains("UNIQUE constraint failed: wallet.seed_hash") {
-                        "This wallet has already been imported for another network. Each wallet can only be imported once per network. If you want to use this wallet on a different network, please switch networks first.".to_string()
-                    } else {
-                        e.to_string()
-                    }
-                })?;
-
-            // Acquire a write lock and add the new wallet
-            if let Ok(mut wallets) = self.app_context.wallets.write() {
-                wallets.insert(wallet.seed_hash(), Arc::new(RwLock::new(wallet)));
-                self.app_context.has_wallet.store(true, Ordering::Relaxed);
-            } else {
-                eprintln!("Failed to acquire write lock on wallets");
+        let gap_limit = self.gap_limit()?;
+        let (seed, passphrase_hint) = match self.import_source {
+            ImportSource::Slip39Shares => (self.slip39_seed()?, None),
+            _ => match &self.seed_phrase {
+                Some(mnemonic) => (
+                    mnemonic.to_seed(self.passphrase_input.passphrase()?),
+                    self.passphrase_input.hint(),
+                ),
+                None => return Ok(AppAction::None), // No action if no seed phrase exists
+            },
+        };
+
+        let (encrypted_seed, salt, nonce, uses_password) = if self.password.is_empty() {
+            (seed.to_vec(), vec![], vec![], false)
+        } else {
+            // Encrypt the seed to obtain encrypted_seed, salt, and nonce
+            let (encrypted_seed, salt, nonce) =
+                ClosedKeyItem::encrypt_seed(&seed, self.password.as_str())?;
+            if self.use_password_for_app {
+                let (encrypted_message, salt, nonce) =
+                    encrypt_message(DASH_SECRET_MESSAGE, self.password.as_str())?;
+                self.app_context
+                    .db
+                    .update_main_password(&salt, &nonce, &encrypted_message)
+                    .map_err(|e| e.to_string())?;
             }
+            (encrypted_seed, salt, nonce, true)
+        };
+
+        // Generate master ECDSA extended private key
+        let master_ecdsa_extended_private_key =
+            ExtendedPrivKey::new_master(self.app_context.network, &seed)
+                .expect("Failed to create master ECDSA extended private key");
+        let bip44_root_derivation_path: DerivationPath = match self.app_context.network {
+            Network::Dash => DerivationPath::from(DASH_BIP44_ACCOUNT_0_PATH_MAINNET.as_slice()),
+            _ => DerivationPath::from(DASH_BIP44_ACCOUNT_0_PATH_TESTNET.as_slice()),
+        };
+        let secp = Secp256k1::new();
+        let master_bip44_ecdsa_extended_public_key = master_ecdsa_extended_private_key
+            .derive_priv(&secp, &bip44_root_derivation_path)
+            .map_err(|e| e.to_string())?;
+
+        let master_bip44_ecdsa_extended_public_key =
+            ExtendedPubKey::from_priv(&secp, &master_bip44_ecdsa_extended_public_key);
+
+        // Compute the seed hash
+        let seed_hash = ClosedKeyItem::compute_seed_hash(&seed);
+
+        let wallet = Wallet {
+            wallet_seed: WalletSeed::Open(OpenWalletSeed {
+                seed,
+                wallet_info: ClosedKeyItem {
+                    seed_hash,
+                    encrypted_seed,
+                    salt,
+                    nonce,
+                    password_hint: None, // Set a password hint if needed
+                    passphrase_hint,
+                },
+            }),
+            uses_password,
+            master_bip44_ecdsa_extended_public_key,
+            accounts: WalletAccount::default_accounts(master_bip44_ecdsa_extended_public_key),
+            address_total_received: Default::default(),
+            known_addresses: Default::default(),
+            watched_addresses: Default::default(),
+            unused_asset_locks: Default::default(),
+            alias: Some(self.alias_input.clone()),
+            identities: Default::default(),
+            utxos: Default::default(),
+            utxo_statuses: Default::default(),
+            utxo_reservations: Default::default(),
+            is_main: true,
+        };
+
+        self.add_wallet(wallet, gap_limit)
+    }
+
+    /// Recovers the wallet seed from the entered SLIP-39 shares.
+    fn slip39_seed(&self) -> Result<[u8; 64], String> {
+        let mut master_secret = combine_shares(&self.slip39_shares, &self.slip39_passphrase)?;
+        let seed = <[u8; 64]>::try_from(master_secret.as_slice()).map_err(|_| {
+            format!(
+                "The shares hold a {}-bit master secret. Only 512-bit wallet seeds, as exported by Dash Evo Tool, can be imported.",
+                master_secret.len() * 8
+            )
+        });
+        master_secret.zeroize();
+        seed
+    }
+
+    fn save_watch_only_wallet(&mut self) -> Result<AppAction, String> {
+        let gap_limit = self.gap_limit()?;
+        let wallet = Wallet::new_watch_only(
+            &self.extended_public_key_input,
+            self.app_context.network,
+            Some(self.alias_input.clone()),
+        )?;
+
+        self.add_wallet(wallet, gap_limit)
+    }
+
+    /// Stores the wallet, makes it available to the app and starts looking for its used
+    /// addresses.
+    fn add_wallet(&mut self, wallet: Wallet, gap_limit: u32) -> Result<AppAction, String> {
+        self.app_context
+            .db
+            .store_wallet(&wallet, &self.app_context.network)
+            .map_err(|e| {
+                if e.to_string().contains("UNIQUE constraint failed: wallet.seed_hash") {
+                    "This wallet has already been imported for another network. Each wallet can only be imported once per network. If you want to use this wallet on a different network, please switch networks first.".to_string()
+                } else {
+                    e.to_string()
+                }
+            })?;
+
+        let wallet = Arc::new(RwLock::new(wallet));
 
-            Ok(AppAction::GoToMainScreen) // Navigate back to the main screen after saving
+        // Acquire a write lock and add the new wallet
+        if let Ok(mut wallets) = self.app_context.wallets.write() {
+            wallets.insert(wallet.read().unwrap().seed_hash(), wallet.clone());
+            self.app_context.has_wallet.store(true, Ordering::Relaxed);
         } else {
-            Ok(AppAction::None) // No action if no seed phrase exists
+            eprintln!("Failed to acquire write lock on wallets");
+        }
+
+        // Look for funds on addresses beyond the ones the app derives on demand
+        self.discovering_addresses = true;
+        Ok(AppAction::BackendTask(BackendTask::CoreTask(
+            CoreTask::DiscoverWalletAddresses(wallet, gap_limit),
+        )))
+    }
+
+    fn render_gap_limit_input(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Gap Limit:");
+            ui.add(egui::TextEdit::singleline(&mut self.gap_limit_input).desired_width(60.0));
+        });
+        ui.label(
+            RichText::new("Address discovery stops after this many unused addresses in a row on both the receiving and change chain.")
+                .color(Color32::GRAY)
+                .size(12.0),
+        );
+    }
+
+    fn render_save_button(ui: &mut Ui, enabled: bool) -> bool {
+        let mut clicked = false;
+        ui.with_layout(Layout::centered_and_justified(Direction::TopDown), |ui| {
+            let save_button = egui::Button::new(RichText::new("Save Wallet").strong().size(30.0))
+                .min_size(Vec2::new(300.0, 60.0))
+                .corner_radius(10.0)
+                .stroke(Stroke::new(1.5, Color32::WHITE))
+                .sense(if enabled {
+                    egui::Sense::click()
+                } else {
+                    egui::Sense::hover()
+                });
+            clicked = ui.add(save_button).clicked();
+        });
+        clicked
+    }
+
+    fn render_watch_only_import(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+
+        ui.heading("1. Enter the extended public key of the account to watch.");
+        ui.add_space(8.0);
+        ui.add(
+            egui::TextEdit::multiline(&mut self.extended_public_key_input)
+                .hint_text("xpub... or tpub...")
+                .desired_rows(2)
+                .desired_width(600.0),
+        );
+        ui.label(
+            RichText::new("This is the key of a BIP44 account (m/44'/coin_type'/account'). A watch-only wallet shows balances and history but can never sign transactions.")
+                .color(Color32::GRAY)
+                .size(12.0),
+        );
+
+        ui.add_space(20.0);
+
+        ui.heading("2. Select a wallet name to remember it. (This will not go to the blockchain)");
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label("Wallet Name:");
+            ui.text_edit_singleline(&mut self.alias_input);
+        });
+
+        ui.add_space(20.0);
+
+        ui.heading("3. Choose how far to look for used addresses.");
+        ui.add_space(8.0);
+        self.render_gap_limit_input(ui);
+
+        ui.add_space(20.0);
+
+        ui.heading("4. Save the wallet.");
+        ui.add_space(5.0);
+
+        let has_key = !self.extended_public_key_input.trim().is_empty();
+        if Self::render_save_button(ui, has_key) {
+            match self.save_watch_only_wallet() {
+                Ok(save_wallet_action) => action = save_wallet_action,
+                Err(e) => self.error = Some(e),
+            }
+        }
+
+        if let Some(error_msg) = &self.error {
+            ui.add_space(10.0);
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error_msg);
+        }
+
+        action
+    }
+
+    fn render_address_discovery(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.add_space(10.0);
+        ui.heading("Discovering wallet addresses");
+        ui.add_space(10.0);
+
+        let progress = self.discovery_progress;
+        match &self.discovery_result {
+            None => {
+                ui.horizontal(|ui| {
+                    ui.add(egui::Spinner::new());
+                    ui.label(format!(
+                        "Checked {} addresses, {} of them used. Core rescans the blockchain for each batch, this can take a while.",
+                        progress.checked_addresses, progress.used_addresses
+                    ));
+                });
+            }
+            Some((message, message_type)) => {
+                let color = match message_type {
+                    MessageType::Error => Color32::DARK_RED,
+                    _ => Color32::DARK_GREEN,
+                };
+                ui.colored_label(color, message);
+                ui.add_space(10.0);
+                if ui.button("Go to Wallets").clicked() {
+                    action = AppAction::GoToMainScreen;
+                }
+            }
+        }
+        action
+    }
+
+    fn add_slip39_share(&mut self) -> Result<(), String> {
+        let share = Share::from_mnemonic(&self.share_input)?;
+        if let Some(first) = self.slip39_shares.first() {
+            if !first.is_compatible_with(&share) {
+                return Err(
+                    "This share belongs to a different backup than the shares entered so far"
+                        .to_string(),
+                );
+            }
+        }
+        if self.slip39_shares.iter().any(|entered| {
+            entered.group_index == share.group_index && entered.member_index == share.member_index
+        }) {
+            return Err("This share was already entered".to_string());
+        }
+        self.slip39_shares.push(share);
+        self.share_input.zeroize();
+        Ok(())
+    }
+
+    /// Renders the share entry and recovery progress. Returns whether enough groups are
+    /// complete to recover the wallet.
+    fn render_slip39_shares_input(&mut self, ui: &mut Ui) -> bool {
+        ui.add_space(8.0);
+        ui.add(
+            egui::TextEdit::multiline(&mut self.share_input)
+                .hint_text("Enter the words of one share, separated by spaces")
+                .desired_rows(3)
+                .desired_width(600.0),
+        );
+        if ui.button("Add Share").clicked() {
+            self.share_error = self.add_slip39_share().err();
+        }
+        if let Some(error) = &self.share_error {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+
+        let Some(first) = self.slip39_shares.first() else {
+            return false;
+        };
+        let group_threshold = first.group_threshold;
+        let group_count = first.group_count;
+
+        // Entered shares and member threshold of each group
+        let mut groups: BTreeMap<u8, (usize, u8)> = BTreeMap::new();
+        for share in &self.slip39_shares {
+            let entry = groups
+                .entry(share.group_index)
+                .or_insert((0, share.member_threshold));
+            entry.0 += 1;
+        }
+        let complete_groups = groups
+            .values()
+            .filter(|(entered, threshold)| *entered >= *threshold as usize)
+            .count();
+
+        ui.add_space(10.0);
+        ui.label(
+            RichText::new(format!(
+                "{} of the {} groups needed are complete ({} groups in this backup)",
+                complete_groups.min(group_threshold as usize),
+                group_threshold,
+                group_count
+            ))
+            .strong(),
+        );
+
+        let mut remove = None;
+        Grid::new("slip39_shares_grid")
+            .num_columns(3)
+            .spacing((15.0, 5.0))
+            .show(ui, |ui| {
+                for (group_index, (entered, threshold)) in &groups {
+                    ui.label(format!("Group {}", group_index + 1));
+                    ui.label(format!(
+                        "{} of {} shares",
+                        (*entered).min(*threshold as usize),
+                        threshold
+                    ));
+                    ui.horizontal(|ui| {
+                        for (index, share) in self.slip39_shares.iter().enumerate() {
+                            if share.group_index == *group_index
+                                && ui
+                                    .button(format!("Remove share {}", share.member_index + 1))
+                                    .clicked()
+                            {
+                                remove = Some(index);
+                            }
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if let Some(index) = remove {
+            self.slip39_shares.remove(index);
+            self.share_error = None;
         }
+
+        complete_groups >= group_threshold as usize
     }
 
     fn render_seed_phrase_input(&mut self, ui: &mut Ui) {
@@ -250,56 +566,110 @@ impl ScreenLike for ImportWalletScreen {
             egui::ScrollArea::both()
                 .auto_shrink([false; 2]) // Prevent shrinking when content is less than the available area
                 .show(ui, |ui| {
+                    if self.discovering_addresses || self.discovery_result.is_some() {
+                        inner_action = self.render_address_discovery(ui);
+                        return;
+                    }
+
                     ui.add_space(10.0);
                     ui.heading("Follow these steps to import your wallet.");
 
                     ui.add_space(5.0);
 
-                    ui.heading("1. Select the seed phrase length and enter all words.");
-                    self.render_seed_phrase_input(ui);
+                    ui.horizontal(|ui| {
+                        ui.label("Import from:");
+                        let previous = self.import_source;
+                        ui.selectable_value(&mut self.import_source, ImportSource::SeedPhrase, "Seed Phrase");
+                        ui.selectable_value(&mut self.import_source, ImportSource::Slip39Shares, "SLIP-39 Shares");
+                        ui.selectable_value(&mut self.import_source, ImportSource::ExtendedPublicKey, "Extended Public Key (watch-only)");
+                        if self.import_source != previous {
+                            self.error = None;
+                        }
+                    });
+
+                    ui.add_space(10.0);
+
+                    let ready_to_save = match self.import_source {
+                        ImportSource::ExtendedPublicKey => {
+                            inner_action = self.render_watch_only_import(ui);
+                            return;
+                        }
+                        ImportSource::Slip39Shares => {
+                            ui.heading("1. Enter the shares one at a time until enough groups are complete.");
+                            if !self.render_slip39_shares_input(ui) {
+                                return;
+                            }
+
+                            ui.add_space(20.0);
 
-                    // Check seed phrase validity whenever all words are filled
-                    if self.seed_phrase_words.iter().all(|string| !string.is_empty()) {
-                        match Mnemonic::parse_normalized(self.seed_phrase_words.join(" ").as_str()) {
-                            Ok(mnemonic) => {
-                                self.seed_phrase = Some(mnemonic);
-                                // Clear any existing seed phrase error
+                            ui.heading("2. Enter the SLIP-39 passphrase, if the shares were created with one.");
+                            ui.add_space(8.0);
+                            ui.horizontal(|ui| {
+                                ui.label("Passphrase:");
+                                ui.add(egui::TextEdit::singleline(&mut self.slip39_passphrase).password(true));
+                            });
+                            ui.label(
+                                RichText::new("A wrong passphrase recovers a different wallet, check its balance before relying on it.")
+                                    .color(Color32::GRAY)
+                                    .size(12.0),
+                            );
+                            true
+                        }
+                        ImportSource::SeedPhrase => {
+                            ui.heading("1. Select the seed phrase length and enter all words.");
+                            self.render_seed_phrase_input(ui);
+
+                            // Check seed phrase validity whenever all words are filled
+                            if self.seed_phrase_words.iter().all(|string| !string.is_empty()) {
+                                match Mnemonic::parse_normalized(self.seed_phrase_words.join(" ").as_str()) {
+                                    Ok(mnemonic) => {
+                                        self.seed_phrase = Some(mnemonic);
+                                        // Clear any existing seed phrase error
+                                        if let Some(ref mut error) = self.error {
+                                            if error.contains("Invalid seed phrase") {
+                                                self.error = None;
+                                            }
+                                        }
+                                    }
+                                    Err(_) => {
+                                        self.seed_phrase = None;
+                                        self.error = Some("Invalid seed phrase. Please check that all words are spelled correctly and are valid BIP39 words.".to_string());
+                                    }
+                                }
+                            } else {
+                                // Clear seed phrase and error if not all words are filled
+                                self.seed_phrase = None;
                                 if let Some(ref mut error) = self.error {
                                     if error.contains("Invalid seed phrase") {
                                         self.error = None;
                                     }
                                 }
                             }
-                            Err(_) => {
-                                self.seed_phrase = None;
-                                self.error = Some("Invalid seed phrase. Please check that all words are spelled correctly and are valid BIP39 words.".to_string());
+
+                            // Display error message if seed phrase is invalid
+                            if let Some(ref error_msg) = self.error {
+                                if error_msg.contains("Invalid seed phrase") {
+                                    ui.add_space(10.0);
+                                    ui.colored_label(Color32::from_rgb(255, 100, 100), error_msg);
+                                }
                             }
-                        }
-                    } else {
-                        // Clear seed phrase and error if not all words are filled
-                        self.seed_phrase = None;
-                        if let Some(ref mut error) = self.error {
-                            if error.contains("Invalid seed phrase") {
-                                self.error = None;
+
+                            if self.seed_phrase.is_none() {
+                                return;
                             }
-                        }
-                    }
 
-                    // Display error message if seed phrase is invalid
-                    if let Some(ref error_msg) = self.error {
-                        if error_msg.contains("Invalid seed phrase") {
-                            ui.add_space(10.0);
-                            ui.colored_label(Color32::from_rgb(255, 100, 100), error_msg);
-                        }
-                    }
+                            ui.add_space(20.0);
 
-                    if self.seed_phrase.is_none() {
-                        return;
-                    }
+                            ui.heading("2. Enter the BIP39 passphrase, if the wallet was created with one.");
+                            ui.add_space(8.0);
+                            self.passphrase_input.ui(ui);
+                            true
+                        }
+                    };
 
                     ui.add_space(20.0);
 
-                    ui.heading("2. Select a wallet name to remember it. (This will not go to the blockchain)");
+                    ui.heading("3. Select a wallet name to remember it. (This will not go to the blockchain)");
 
                     ui.add_space(8.0);
 
@@ -310,7 +680,7 @@ impl ScreenLike for ImportWalletScreen {
 
                     ui.add_space(20.0);
 
-                    ui.heading("3. Add a password that must be used to unlock the wallet. (Optional but recommended)");
+                    ui.heading("4. Add a password that must be used to unlock the wallet. (Optional but recommended)");
 
                     ui.add_space(8.0);
 
@@ -378,34 +748,34 @@ impl ScreenLike for ImportWalletScreen {
 
                     ui.add_space(20.0);
 
-                    ui.heading("4. Save the wallet.");
+                    ui.heading("5. Choose how far to look for used addresses.");
+                    ui.add_space(8.0);
+
+                    self.render_gap_limit_input(ui);
+
+                    ui.add_space(20.0);
+
+                    ui.heading("6. Save the wallet.");
                     ui.add_space(5.0);
 
                     // Centered "Save Wallet" button at the bottom
-                    ui.with_layout(Layout::centered_and_justified(Direction::TopDown), |ui| {
-                        let save_button = egui::Button::new(
-                            RichText::new("Save Wallet").strong().size(30.0),
-                        )
-                            .min_size(Vec2::new(300.0, 60.0))
-                            .corner_radius(10.0)
-                            .stroke(Stroke::new(1.5, Color32::WHITE))
-                            .sense(if self.seed_phrase.is_some() {
-                                egui::Sense::click()
-                            } else {
-                                egui::Sense::hover()
-                            });
-
-                        if ui.add(save_button).clicked() {
-                            match self.save_wallet() {
-                                Ok(save_wallet_action) => {
-                                    inner_action = save_wallet_action;
-                                }
-                                Err(e) => {
-                                    self.error = Some(e)
-                                }
+                    if Self::render_save_button(ui, ready_to_save) {
+                        match self.save_wallet() {
+                            Ok(save_wallet_action) => {
+                                inner_action = save_wallet_action;
+                            }
+                            Err(e) => {
+                                self.error = Some(e)
                             }
                         }
-                    });
+                    }
+
+                    if let Some(ref error_msg) = self.error {
+                        if !error_msg.contains("Invalid seed phrase") {
+                            ui.add_space(10.0);
+                            ui.colored_label(Color32::from_rgb(255, 100, 100), error_msg);
+                        }
+                    }
                 });
 
             inner_action
@@ -413,4 +783,19 @@ impl ScreenLike for ImportWalletScreen {
 
         action
     }
+
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if self.discovering_addresses {
+            self.discovering_addresses = false;
+            self.discovery_result = Some((message.to_string(), message_type));
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::CoreItem(CoreItem::AddressDiscoveryProgress(progress)) =
+            backend_task_success_result
+        {
+            self.discovery_progress = progress;
+        }
+    }
 }
@@ -1,3 +1,6 @@
 pub mod add_new_wallet_screen;
 pub mod import_wallet_screen;
+pub mod sign_transaction_screen;
+pub mod slip39_export_screen;
+pub mod sweep_key_screen;
 pub mod wallets_screen;
@@ -0,0 +1,460 @@
+use crate::app::AppAction;
+use crate::backend_task::BackendTask;
+use crate::backend_task::core::CoreTask;
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::unsigned_transaction::UnsignedTransaction;
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::render_transaction_export;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::dashcore::consensus::{deserialize, serialize};
+use dash_sdk::dpp::dashcore::transaction::special_transaction::TransactionPayload::AssetLockPayloadType;
+use dash_sdk::dpp::dashcore::{Address, Network, Transaction};
+use eframe::egui::Context;
+use egui::{Color32, ComboBox, Grid, RichText, Ui};
+use std::sync::{Arc, RwLock};
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum SignTransactionMode {
+    /// Sign an unsigned transaction exported by an online wallet, usually on an offline machine
+    Sign,
+    /// Broadcast a transaction that was signed elsewhere
+    Broadcast,
+}
+
+pub struct SignTransactionScreen {
+    pub app_context: Arc<AppContext>,
+    mode: SignTransactionMode,
+    selected_wallet: Option<Arc<RwLock<Wallet>>>,
+    wallet_password: String,
+    show_password: bool,
+    transaction_input: String,
+    unsigned_transaction: Option<UnsignedTransaction>,
+    signed_transaction: Option<Transaction>,
+    show_qr_code: bool,
+    broadcasting: bool,
+    success_message: Option<String>,
+    error_message: Option<String>,
+}
+
+impl SignTransactionScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            mode: SignTransactionMode::Sign,
+            selected_wallet: None,
+            wallet_password: String::new(),
+            show_password: false,
+            transaction_input: String::new(),
+            unsigned_transaction: None,
+            signed_transaction: None,
+            show_qr_code: false,
+            broadcasting: false,
+            success_message: None,
+            error_message: None,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.transaction_input.clear();
+        self.unsigned_transaction = None;
+        self.signed_transaction = None;
+        self.show_qr_code = false;
+        self.broadcasting = false;
+        self.success_message = None;
+        self.error_message = None;
+    }
+
+    fn render_wallet_selection(&mut self, ui: &mut Ui) {
+        let wallets = self.app_context.wallets.read().unwrap();
+        let wallets: Vec<_> = wallets
+            .values()
+            .filter(|wallet| {
+                // Watch-only wallets can broadcast but never sign
+                self.mode == SignTransactionMode::Broadcast
+                    || !wallet.read().unwrap().is_watch_only()
+            })
+            .cloned()
+            .collect();
+
+        if self
+            .selected_wallet
+            .as_ref()
+            .is_some_and(|selected| !wallets.iter().any(|w| Arc::ptr_eq(w, selected)))
+        {
+            self.selected_wallet = None;
+        }
+        if self.selected_wallet.is_none() && wallets.len() == 1 {
+            self.selected_wallet = wallets.first().cloned();
+        }
+
+        let wallet_name = |wallet: &Arc<RwLock<Wallet>>| {
+            wallet
+                .read()
+                .unwrap()
+                .alias
+                .clone()
+                .unwrap_or_else(|| "Unnamed Wallet".to_string())
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("Wallet:");
+            ComboBox::from_id_salt("sign_transaction_wallet")
+                .selected_text(
+                    self.selected_wallet
+                        .as_ref()
+                        .map(wallet_name)
+                        .unwrap_or_else(|| "Select a wallet".to_string()),
+                )
+                .show_ui(ui, |ui| {
+                    for wallet in &wallets {
+                        let selected = self
+                            .selected_wallet
+                            .as_ref()
+                            .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
+                        if ui.selectable_label(selected, wallet_name(wallet)).clicked() {
+                            self.selected_wallet = Some(wallet.clone());
+                        }
+                    }
+                });
+        });
+    }
+
+    fn render_transaction_input(&mut self, ui: &mut Ui, hint: &str) {
+        ui.add(
+            egui::TextEdit::multiline(&mut self.transaction_input)
+                .hint_text(hint)
+                .desired_rows(4)
+                .desired_width(f32::INFINITY),
+        );
+        if ui.button("Load from File").clicked() {
+            if let Some(path) = rfd::FileDialog::new().pick_file() {
+                match std::fs::read_to_string(path) {
+                    Ok(contents) => self.transaction_input = contents,
+                    Err(e) => self.error_message = Some(format!("Failed to read file: {}", e)),
+                }
+            }
+        }
+    }
+
+    fn load_unsigned_transaction(&mut self) {
+        match UnsignedTransaction::from_json(&self.transaction_input) {
+            Ok(unsigned) if unsigned.network != self.app_context.network => {
+                self.error_message = Some(format!(
+                    "The transaction is for {} but the app is on {}",
+                    unsigned.network, self.app_context.network
+                ));
+            }
+            Ok(unsigned) => {
+                self.error_message = None;
+                self.unsigned_transaction = Some(unsigned);
+            }
+            Err(e) => self.error_message = Some(e),
+        }
+    }
+
+    fn load_signed_transaction(&mut self) {
+        let transaction = hex::decode(self.transaction_input.trim())
+            .map_err(|e| format!("Expected a hex encoded transaction: {}", e))
+            .and_then(|bytes| {
+                deserialize::<Transaction>(&bytes)
+                    .map_err(|e| format!("Invalid transaction: {}", e))
+            });
+        match transaction {
+            Ok(transaction) => {
+                self.error_message = None;
+                self.signed_transaction = Some(transaction);
+            }
+            Err(e) => self.error_message = Some(e),
+        }
+    }
+
+    fn render_outputs(ui: &mut Ui, transaction: &Transaction, network: Network) {
+        ui.label(RichText::new("Outputs").strong());
+        Grid::new("sign_transaction_outputs")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for tx_out in &transaction.output {
+                    let recipient = if tx_out.script_pubkey.is_op_return() {
+                        "Burn (asset lock)".to_string()
+                    } else {
+                        Address::from_script(&tx_out.script_pubkey, network)
+                            .map(|address| address.to_string())
+                            .unwrap_or_else(|_| "Unknown script".to_string())
+                    };
+                    ui.label(recipient);
+                    ui.label(format!("{:.8} DASH", tx_out.value as f64 * 1e-8));
+                    ui.end_row();
+                }
+                if let Some(AssetLockPayloadType(payload)) =
+                    &transaction.special_transaction_payload
+                {
+                    for credit_output in &payload.credit_outputs {
+                        let recipient = Address::from_script(&credit_output.script_pubkey, network)
+                            .map(|address| format!("Platform credits ({})", address))
+                            .unwrap_or_else(|_| "Platform credits".to_string());
+                        ui.label(recipient);
+                        ui.label(format!("{:.8} DASH", credit_output.value as f64 * 1e-8));
+                        ui.end_row();
+                    }
+                }
+            });
+    }
+
+    fn render_sign(&mut self, ui: &mut Ui) {
+        ui.label("Sign a transaction exported by an online or watch-only copy of this wallet.");
+        ui.add_space(10.0);
+
+        self.render_wallet_selection(ui);
+        ui.add_space(10.0);
+
+        if let Some(signed) = &self.signed_transaction {
+            ui.colored_label(
+                Color32::DARK_GREEN,
+                "Transaction signed. Broadcast it from the online instance (Sign Transaction > Broadcast).",
+            );
+            ui.add_space(5.0);
+            let signed_hex = hex::encode(serialize(signed));
+            if let Some(error) = render_transaction_export(
+                ui,
+                &signed_hex,
+                "signed_transaction.txt",
+                &mut self.show_qr_code,
+            ) {
+                self.error_message = Some(error);
+            }
+            ui.add_space(10.0);
+            if ui.button("Sign Another").clicked() {
+                self.reset();
+            }
+            return;
+        }
+
+        let Some(unsigned) = self.unsigned_transaction.clone() else {
+            self.render_transaction_input(ui, "Paste the unsigned transaction here");
+            ui.add_space(5.0);
+            if ui.button("Review").clicked() {
+                self.load_unsigned_transaction();
+            }
+            return;
+        };
+
+        ui.label(RichText::new("Inputs").strong());
+        Grid::new("sign_transaction_inputs")
+            .num_columns(3)
+            .striped(true)
+            .show(ui, |ui| {
+                for input in unsigned.inputs.values() {
+                    ui.label(input.address.to_string());
+                    ui.label(input.derivation_path.to_string());
+                    ui.label(format!(
+                        "{:.8} DASH",
+                        input.previous_output().value as f64 * 1e-8
+                    ));
+                    ui.end_row();
+                }
+            });
+        ui.add_space(10.0);
+        Self::render_outputs(ui, &unsigned.transaction, unsigned.network);
+        ui.add_space(10.0);
+        ui.label(format!("Fee: {:.8} DASH", unsigned.fee() as f64 * 1e-8));
+        ui.label(
+            RichText::new(
+                "Input values were checked against the transactions that created them. Compare the outputs and fee with what you expect before signing.",
+            )
+            .color(Color32::GRAY),
+        );
+        ui.add_space(10.0);
+
+        if self.selected_wallet.is_none() {
+            ui.label("Select the wallet to sign with.");
+            return;
+        }
+
+        let (needed_unlock, just_unlocked) = self.render_wallet_unlock_if_needed(ui);
+        if needed_unlock && !just_unlocked {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Sign").clicked() {
+                if let Some(wallet) = &self.selected_wallet {
+                    match wallet.read().unwrap().sign_unsigned_transaction(&unsigned) {
+                        Ok(signed) => {
+                            self.error_message = None;
+                            self.signed_transaction = Some(signed);
+                        }
+                        Err(e) => self.error_message = Some(e),
+                    }
+                }
+            }
+            if ui.button("Cancel").clicked() {
+                self.reset();
+            }
+        });
+    }
+
+    fn render_broadcast(&mut self, ui: &mut Ui) -> AppAction {
+        ui.label("Broadcast a transaction that was signed by an offline copy of this wallet.");
+        ui.add_space(10.0);
+
+        self.render_wallet_selection(ui);
+        ui.add_space(10.0);
+
+        if let Some(success_message) = &self.success_message {
+            ui.colored_label(Color32::DARK_GREEN, success_message);
+            ui.add_space(10.0);
+            if ui.button("Broadcast Another").clicked() {
+                self.reset();
+            }
+            return AppAction::None;
+        }
+
+        let Some(signed) = self.signed_transaction.clone() else {
+            self.render_transaction_input(ui, "Paste the signed transaction hex here");
+            ui.add_space(5.0);
+            if ui.button("Review").clicked() {
+                self.load_signed_transaction();
+            }
+            return AppAction::None;
+        };
+
+        ui.label(format!("Transaction {}", signed.txid()));
+        ui.add_space(5.0);
+        Self::render_outputs(ui, &signed, self.app_context.network);
+        ui.add_space(10.0);
+
+        if self.broadcasting {
+            ui.horizontal(|ui| {
+                ui.add(egui::Spinner::new());
+                ui.label("Broadcasting...");
+            });
+            return AppAction::None;
+        }
+
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            if ui.button("Broadcast").clicked() {
+                match &self.selected_wallet {
+                    Some(wallet) => {
+                        self.error_message = None;
+                        self.broadcasting = true;
+                        action = AppAction::BackendTask(BackendTask::CoreTask(
+                            CoreTask::BroadcastSignedTransaction(wallet.clone(), signed.clone()),
+                        ));
+                    }
+                    None => {
+                        self.error_message =
+                            Some("Select the wallet the transaction spends from".to_string())
+                    }
+                }
+            }
+            if ui.button("Cancel").clicked() {
+                self.reset();
+            }
+        });
+        action
+    }
+}
+
+impl ScreenWithWalletUnlock for SignTransactionScreen {
+    fn selected_wallet_ref(&self) -> &Option<Arc<RwLock<Wallet>>> {
+        &self.selected_wallet
+    }
+
+    fn wallet_password_ref(&self) -> &String {
+        &self.wallet_password
+    }
+
+    fn wallet_password_mut(&mut self) -> &mut String {
+        &mut self.wallet_password
+    }
+
+    fn show_password(&self) -> bool {
+        self.show_password
+    }
+
+    fn show_password_mut(&mut self) -> &mut bool {
+        &mut self.show_password
+    }
+
+    fn set_error_message(&mut self, error_message: Option<String>) {
+        self.error_message = error_message;
+    }
+
+    fn error_message(&self) -> Option<&String> {
+        self.error_message.as_ref()
+    }
+}
+
+impl ScreenLike for SignTransactionScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if !self.broadcasting {
+            return;
+        }
+        self.broadcasting = false;
+        match message_type {
+            MessageType::Error => self.error_message = Some(message.to_string()),
+            _ => self.success_message = Some(message.to_string()),
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Wallets", AppAction::GoToMainScreen),
+                ("Sign Transaction", AppAction::None),
+            ],
+            vec![],
+        );
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            crate::ui::RootScreenType::RootScreenWalletsBalances,
+        );
+
+        action |= island_central_panel(ctx, |ui| {
+            let mut inner_action = AppAction::None;
+            egui::ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        let previous = self.mode;
+                        ui.selectable_value(&mut self.mode, SignTransactionMode::Sign, "Sign");
+                        ui.selectable_value(
+                            &mut self.mode,
+                            SignTransactionMode::Broadcast,
+                            "Broadcast",
+                        );
+                        if self.mode != previous {
+                            self.reset();
+                        }
+                    });
+                    ui.separator();
+
+                    inner_action = match self.mode {
+                        SignTransactionMode::Sign => {
+                            self.render_sign(ui);
+                            AppAction::None
+                        }
+                        SignTransactionMode::Broadcast => self.render_broadcast(ui),
+                    };
+
+                    if let Some(error_message) = &self.error_message {
+                        ui.add_space(10.0);
+                        ui.colored_label(Color32::DARK_RED, error_message);
+                    }
+                });
+            inner_action
+        });
+
+        action
+    }
+}
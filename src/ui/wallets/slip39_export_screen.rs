@@ -0,0 +1,466 @@
+use crate::app::AppAction;
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::slip39::{GroupConfig, MAX_SHARE_COUNT, Share};
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::{MessageType, ScreenLike};
+use eframe::egui::Context;
+use egui::{Align, Color32, ComboBox, Frame, Grid, Layout, Margin, RichText, Stroke, Ui};
+use std::collections::BTreeSet;
+use std::sync::{Arc, RwLock};
+use zeroize::Zeroize;
+
+pub struct Slip39ExportScreen {
+    pub app_context: Arc<AppContext>,
+    selected_wallet: Option<Arc<RwLock<Wallet>>>,
+    wallet_password: String,
+    show_password: bool,
+    group_threshold: u8,
+    groups: Vec<GroupConfig>,
+    passphrase: String,
+    passphrase_confirmation: String,
+    /// Mnemonics of the generated shares of each group
+    shares: Vec<Vec<String>>,
+    /// Group and member index of the shares the user entered back correctly
+    verified_shares: BTreeSet<(u8, u8)>,
+    verify_input: String,
+    verify_message: Option<(String, MessageType)>,
+    error_message: Option<String>,
+}
+
+impl Drop for Slip39ExportScreen {
+    fn drop(&mut self) {
+        self.wallet_password.zeroize();
+        self.passphrase.zeroize();
+        self.passphrase_confirmation.zeroize();
+        self.verify_input.zeroize();
+        self.clear_shares();
+    }
+}
+
+impl Slip39ExportScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            selected_wallet: None,
+            wallet_password: String::new(),
+            show_password: false,
+            group_threshold: 1,
+            groups: vec![GroupConfig {
+                member_threshold: 2,
+                member_count: 3,
+            }],
+            passphrase: String::new(),
+            passphrase_confirmation: String::new(),
+            shares: vec![],
+            verified_shares: BTreeSet::new(),
+            verify_input: String::new(),
+            verify_message: None,
+            error_message: None,
+        }
+    }
+
+    fn clear_shares(&mut self) {
+        self.shares
+            .iter_mut()
+            .flatten()
+            .for_each(|mnemonic| mnemonic.zeroize());
+        self.shares.clear();
+        self.verified_shares.clear();
+        self.verify_message = None;
+    }
+
+    fn render_wallet_selection(&mut self, ui: &mut Ui) {
+        let wallets = self.app_context.wallets.read().unwrap();
+        let wallets: Vec<_> = wallets
+            .values()
+            .filter(|wallet| !wallet.read().unwrap().is_watch_only())
+            .cloned()
+            .collect();
+
+        if self
+            .selected_wallet
+            .as_ref()
+            .is_some_and(|selected| !wallets.iter().any(|w| Arc::ptr_eq(w, selected)))
+        {
+            self.selected_wallet = None;
+        }
+        if self.selected_wallet.is_none() && wallets.len() == 1 {
+            self.selected_wallet = wallets.first().cloned();
+        }
+
+        let wallet_name = |wallet: &Arc<RwLock<Wallet>>| {
+            wallet
+                .read()
+                .unwrap()
+                .alias
+                .clone()
+                .unwrap_or_else(|| "Unnamed Wallet".to_string())
+        };
+
+        let mut wallet_changed = false;
+        ui.horizontal(|ui| {
+            ui.label("Wallet:");
+            ComboBox::from_id_salt("slip39_export_wallet")
+                .selected_text(
+                    self.selected_wallet
+                        .as_ref()
+                        .map(wallet_name)
+                        .unwrap_or_else(|| "Select a wallet".to_string()),
+                )
+                .show_ui(ui, |ui| {
+                    for wallet in &wallets {
+                        let selected = self
+                            .selected_wallet
+                            .as_ref()
+                            .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
+                        if ui.selectable_label(selected, wallet_name(wallet)).clicked() {
+                            self.selected_wallet = Some(wallet.clone());
+                            wallet_changed = true;
+                        }
+                    }
+                });
+        });
+        if wallet_changed {
+            self.clear_shares();
+        }
+    }
+
+    fn render_group_configuration(&mut self, ui: &mut Ui) {
+        let mut remove = None;
+        Grid::new("slip39_groups_grid")
+            .num_columns(4)
+            .spacing((15.0, 5.0))
+            .show(ui, |ui| {
+                ui.label(RichText::new("Group").strong());
+                ui.label(RichText::new("Shares Needed").strong());
+                ui.label(RichText::new("Shares").strong());
+                ui.label("");
+                ui.end_row();
+
+                let group_count = self.groups.len();
+                for (index, group) in self.groups.iter_mut().enumerate() {
+                    ui.label(format!("Group {}", index + 1));
+                    ui.add(
+                        egui::DragValue::new(&mut group.member_threshold)
+                            .range(1..=group.member_count),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut group.member_count).range(1..=MAX_SHARE_COUNT),
+                    );
+                    group.member_threshold = group.member_threshold.min(group.member_count);
+                    if group_count > 1 && ui.button("Remove").clicked() {
+                        remove = Some(index);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(index) = remove {
+            self.groups.remove(index);
+        }
+
+        if self.groups.len() < MAX_SHARE_COUNT as usize && ui.button("Add Group").clicked() {
+            self.groups.push(GroupConfig {
+                member_threshold: 2,
+                member_count: 3,
+            });
+        }
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label("Groups needed to recover the wallet:");
+            ui.add(
+                egui::DragValue::new(&mut self.group_threshold).range(1..=self.groups.len() as u8),
+            );
+        });
+        self.group_threshold = self.group_threshold.clamp(1, self.groups.len() as u8);
+
+        ui.label(
+            RichText::new("A group that needs a single share can only have one share, more of them would all be identical.")
+                .color(Color32::GRAY)
+                .size(12.0),
+        );
+    }
+
+    fn generate_shares(&mut self) -> Result<(), String> {
+        if self.passphrase != self.passphrase_confirmation {
+            return Err("The passphrases do not match".to_string());
+        }
+        let wallet = self
+            .selected_wallet
+            .as_ref()
+            .ok_or_else(|| "Select the wallet to back up".to_string())?;
+        let shares = wallet.read().unwrap().slip39_shares(
+            &self.passphrase,
+            self.group_threshold,
+            &self.groups,
+        )?;
+
+        self.clear_shares();
+        self.shares = shares
+            .iter()
+            .map(|group_shares| group_shares.iter().map(Share::to_mnemonic).collect())
+            .collect();
+        Ok(())
+    }
+
+    fn render_share(ui: &mut Ui, id: String, mnemonic: &str) {
+        let columns = 6;
+        Frame::new()
+            .fill(Color32::WHITE)
+            .stroke(Stroke::new(1.0, Color32::BLACK))
+            .corner_radius(5.0)
+            .inner_margin(Margin::same(10))
+            .show(ui, |ui| {
+                Grid::new(id)
+                    .num_columns(columns)
+                    .spacing((20.0, 4.0))
+                    .show(ui, |ui| {
+                        for (i, word) in mnemonic.split(' ').enumerate() {
+                            ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
+                                ui.label(
+                                    RichText::new(format!("{} ", i + 1))
+                                        .size(10.0)
+                                        .color(Color32::GRAY),
+                                );
+                                ui.label(RichText::new(word).size(16.0).color(Color32::BLACK));
+                            });
+
+                            if (i + 1) % columns == 0 {
+                                ui.end_row();
+                            }
+                        }
+                    });
+            });
+    }
+
+    fn render_shares(&self, ui: &mut Ui) {
+        ui.colored_label(
+            Color32::DARK_RED,
+            "Anyone holding enough of these shares controls the funds of this wallet. Write each share down for its holder, they are not saved anywhere.",
+        );
+        ui.add_space(10.0);
+
+        for (group_index, group_shares) in self.shares.iter().enumerate() {
+            let group = self.groups[group_index];
+            ui.heading(format!(
+                "Group {}: any {} of its {} shares",
+                group_index + 1,
+                group.member_threshold,
+                group.member_count
+            ));
+            for (member_index, mnemonic) in group_shares.iter().enumerate() {
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(format!("Share {}", member_index + 1)).strong());
+                    if self
+                        .verified_shares
+                        .contains(&(group_index as u8, member_index as u8))
+                    {
+                        ui.colored_label(Color32::DARK_GREEN, "Verified");
+                    }
+                });
+                Self::render_share(
+                    ui,
+                    format!("slip39_share_grid_{}_{}", group_index, member_index),
+                    mnemonic,
+                );
+            }
+            ui.add_space(15.0);
+        }
+    }
+
+    fn verify_share(&mut self) {
+        let result = Share::from_mnemonic(&self.verify_input).and_then(|share| {
+            let mut mnemonic = share.to_mnemonic();
+            let matches = self
+                .shares
+                .get(share.group_index as usize)
+                .and_then(|group_shares| group_shares.get(share.member_index as usize))
+                .is_some_and(|generated| *generated == mnemonic);
+            mnemonic.zeroize();
+            if matches {
+                Ok((share.group_index, share.member_index))
+            } else {
+                Err("This is not one of the shares above, compare the words you wrote down with them".to_string())
+            }
+        });
+
+        self.verify_message = Some(match result {
+            Ok((group_index, member_index)) => {
+                self.verified_shares.insert((group_index, member_index));
+                self.verify_input.zeroize();
+                (
+                    format!(
+                        "Share {} of group {} is correct",
+                        member_index + 1,
+                        group_index + 1
+                    ),
+                    MessageType::Success,
+                )
+            }
+            Err(e) => (e, MessageType::Error),
+        });
+    }
+
+    fn render_verification(&mut self, ui: &mut Ui) {
+        ui.heading("Verify the written shares");
+        ui.add_space(5.0);
+        ui.label("Type a share back from paper to make sure it was written down correctly.");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.verify_input)
+                .hint_text("Enter the words of one share, separated by spaces")
+                .desired_rows(3)
+                .desired_width(600.0),
+        );
+        if ui.button("Verify Share").clicked() {
+            self.verify_share();
+        }
+        if let Some((message, message_type)) = &self.verify_message {
+            let color = match message_type {
+                MessageType::Error => Color32::DARK_RED,
+                _ => Color32::DARK_GREEN,
+            };
+            ui.colored_label(color, message);
+        }
+
+        let share_count: usize = self.shares.iter().map(Vec::len).sum();
+        ui.label(format!(
+            "{} of {} shares verified",
+            self.verified_shares.len(),
+            share_count
+        ));
+    }
+}
+
+impl ScreenWithWalletUnlock for Slip39ExportScreen {
+    fn selected_wallet_ref(&self) -> &Option<Arc<RwLock<Wallet>>> {
+        &self.selected_wallet
+    }
+
+    fn wallet_password_ref(&self) -> &String {
+        &self.wallet_password
+    }
+
+    fn wallet_password_mut(&mut self) -> &mut String {
+        &mut self.wallet_password
+    }
+
+    fn show_password(&self) -> bool {
+        self.show_password
+    }
+
+    fn show_password_mut(&mut self) -> &mut bool {
+        &mut self.show_password
+    }
+
+    fn set_error_message(&mut self, error_message: Option<String>) {
+        self.error_message = error_message;
+    }
+
+    fn error_message(&self) -> Option<&String> {
+        self.error_message.as_ref()
+    }
+}
+
+impl ScreenLike for Slip39ExportScreen {
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Wallets", AppAction::GoToMainScreen),
+                ("Export SLIP-39 Shares", AppAction::None),
+            ],
+            vec![],
+        );
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            crate::ui::RootScreenType::RootScreenWalletsBalances,
+        );
+
+        action |= island_central_panel(ctx, |ui| {
+            egui::ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    ui.heading("Split the wallet seed into SLIP-39 shares.");
+                    ui.add_space(5.0);
+                    ui.label("Shares are organized in groups, for example one per person or location. The wallet can be recovered from the shares needed in enough groups, using Import Wallet > SLIP-39 Shares.");
+                    ui.add_space(10.0);
+
+                    self.render_wallet_selection(ui);
+                    ui.add_space(10.0);
+
+                    if self.selected_wallet.is_none() {
+                        ui.label("Select the wallet to back up.");
+                        return;
+                    }
+
+                    let (needed_unlock, just_unlocked) = self.render_wallet_unlock_if_needed(ui);
+                    if needed_unlock && !just_unlocked {
+                        return;
+                    }
+
+                    if self.shares.is_empty() {
+                        ui.heading("1. Choose the groups and how many of their shares are needed.");
+                        ui.add_space(8.0);
+                        self.render_group_configuration(ui);
+
+                        ui.add_space(20.0);
+
+                        ui.heading("2. Add a passphrase that is needed with the shares. (Optional)");
+                        ui.add_space(8.0);
+                        Grid::new("slip39_passphrase_grid")
+                            .num_columns(2)
+                            .spacing((10.0, 5.0))
+                            .show(ui, |ui| {
+                                ui.label("Passphrase:");
+                                ui.add(egui::TextEdit::singleline(&mut self.passphrase).password(true));
+                                ui.end_row();
+
+                                ui.label("Confirm Passphrase:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut self.passphrase_confirmation)
+                                        .password(true),
+                                );
+                                ui.end_row();
+                            });
+                        ui.label(
+                            RichText::new("Any passphrase recovers a wallet from the shares, a wrong one just recovers a different, empty wallet.")
+                                .color(Color32::GRAY)
+                                .size(12.0),
+                        );
+
+                        ui.add_space(20.0);
+
+                        ui.heading("3. Generate the shares.");
+                        ui.add_space(5.0);
+                        if ui.button("Generate Shares").clicked() {
+                            self.error_message = self.generate_shares().err();
+                        }
+                    } else {
+                        self.render_shares(ui);
+                        self.render_verification(ui);
+
+                        ui.add_space(10.0);
+                        if ui.button("Start Over").clicked() {
+                            self.clear_shares();
+                        }
+                    }
+
+                    if let Some(error_message) = &self.error_message {
+                        ui.add_space(10.0);
+                        ui.colored_label(Color32::DARK_RED, error_message);
+                    }
+                });
+            AppAction::None
+        });
+
+        action
+    }
+}
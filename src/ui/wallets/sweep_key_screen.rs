@@ -0,0 +1,323 @@
+use crate::app::AppAction;
+use crate::backend_task::core::{CoreItem, CoreTask};
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
+use crate::context::AppContext;
+use crate::model::wallet::Wallet;
+use crate::model::wallet::sweep::SweepTransaction;
+use crate::ui::components::left_panel::add_left_panel;
+use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::{MessageType, ScreenLike};
+use dash_sdk::dpp::dashcore::{Network, PrivateKey};
+use eframe::egui::Context;
+use egui::{Color32, ComboBox, Grid, RichText, Ui};
+use std::sync::{Arc, RwLock};
+use zeroize::Zeroize;
+
+/// Moves everything held by a standalone private key, e.g. a paper wallet, into a wallet.
+pub struct SweepKeyScreen {
+    pub app_context: Arc<AppContext>,
+    selected_wallet: Option<Arc<RwLock<Wallet>>>,
+    private_key_input: String,
+    show_private_key: bool,
+    sweep: Option<SweepTransaction>,
+    /// A backend task is looking up funds or broadcasting
+    waiting: bool,
+    success_message: Option<String>,
+    error_message: Option<String>,
+}
+
+impl Drop for SweepKeyScreen {
+    fn drop(&mut self) {
+        self.private_key_input.zeroize();
+    }
+}
+
+impl SweepKeyScreen {
+    pub fn new(app_context: &Arc<AppContext>) -> Self {
+        Self {
+            app_context: app_context.clone(),
+            selected_wallet: None,
+            private_key_input: String::new(),
+            show_private_key: false,
+            sweep: None,
+            waiting: false,
+            success_message: None,
+            error_message: None,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.private_key_input.zeroize();
+        self.show_private_key = false;
+        self.sweep = None;
+        self.waiting = false;
+        self.success_message = None;
+        self.error_message = None;
+    }
+
+    fn render_wallet_selection(&mut self, ui: &mut Ui) {
+        let wallets: Vec<_> = self
+            .app_context
+            .wallets
+            .read()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect();
+
+        if self
+            .selected_wallet
+            .as_ref()
+            .is_some_and(|selected| !wallets.iter().any(|w| Arc::ptr_eq(w, selected)))
+        {
+            self.selected_wallet = None;
+        }
+        if self.selected_wallet.is_none() && wallets.len() == 1 {
+            self.selected_wallet = wallets.first().cloned();
+        }
+
+        let wallet_name = |wallet: &Arc<RwLock<Wallet>>| {
+            wallet
+                .read()
+                .unwrap()
+                .alias
+                .clone()
+                .unwrap_or_else(|| "Unnamed Wallet".to_string())
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("Into wallet:");
+            ComboBox::from_id_salt("sweep_key_wallet")
+                .selected_text(
+                    self.selected_wallet
+                        .as_ref()
+                        .map(wallet_name)
+                        .unwrap_or_else(|| "Select a wallet".to_string()),
+                )
+                .show_ui(ui, |ui| {
+                    for wallet in &wallets {
+                        let selected = self
+                            .selected_wallet
+                            .as_ref()
+                            .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
+                        if ui.selectable_label(selected, wallet_name(wallet)).clicked() {
+                            self.selected_wallet = Some(wallet.clone());
+                        }
+                    }
+                });
+        });
+    }
+
+    fn parse_private_key(&self) -> Result<PrivateKey, String> {
+        let private_key = PrivateKey::from_wif(self.private_key_input.trim())
+            .map_err(|e| format!("Invalid WIF private key: {}", e))?;
+        // Testnet, devnet and regtest keys share a prefix
+        let network = self.app_context.network;
+        if (private_key.network == Network::Dash) != (network == Network::Dash) {
+            return Err(format!(
+                "The private key is for {} but the app is on {}",
+                private_key.network, network
+            ));
+        }
+        Ok(private_key)
+    }
+
+    fn render_key_input(&mut self, ui: &mut Ui) -> AppAction {
+        ui.label("Private key (WIF):");
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.private_key_input)
+                    .password(!self.show_private_key)
+                    .hint_text("Type or paste the key")
+                    .desired_width(420.0),
+            );
+            ui.checkbox(&mut self.show_private_key, "Show");
+        });
+        if ui.button("Load from File").clicked() {
+            if let Some(path) = rfd::FileDialog::new().pick_file() {
+                match std::fs::read_to_string(path) {
+                    Ok(mut contents) => {
+                        self.private_key_input.zeroize();
+                        self.private_key_input = contents.trim().to_string();
+                        contents.zeroize();
+                    }
+                    Err(e) => self.error_message = Some(format!("Failed to read file: {}", e)),
+                }
+            }
+        }
+        ui.add_space(10.0);
+
+        if self.waiting {
+            ui.horizontal(|ui| {
+                ui.add(egui::Spinner::new());
+                ui.label("Looking up funds...");
+            });
+            return AppAction::None;
+        }
+
+        if ui.button("Find Funds").clicked() {
+            let Some(wallet) = self.selected_wallet.clone() else {
+                self.error_message = Some("Select the wallet to sweep into".to_string());
+                return AppAction::None;
+            };
+            match self.parse_private_key() {
+                Ok(private_key) => {
+                    self.error_message = None;
+                    self.waiting = true;
+                    return AppAction::BackendTask(BackendTask::CoreTask(CoreTask::PrepareSweep(
+                        wallet,
+                        private_key,
+                    )));
+                }
+                Err(e) => self.error_message = Some(e),
+            }
+        }
+        AppAction::None
+    }
+
+    fn render_review(&mut self, ui: &mut Ui, sweep: &SweepTransaction) -> AppAction {
+        let dash = |duffs: u64| format!("{:.8} DASH", duffs as f64 * 1e-8);
+        Grid::new("sweep_key_review")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("From:");
+                ui.label(sweep.source.to_string());
+                ui.end_row();
+                ui.label("To:");
+                ui.label(sweep.destination.to_string());
+                ui.end_row();
+                ui.label("Found:");
+                ui.label(format!(
+                    "{} in {} outputs",
+                    dash(sweep.input_value),
+                    sweep.transaction.input.len()
+                ));
+                ui.end_row();
+                ui.label("Fee:");
+                ui.label(dash(sweep.fee));
+                ui.end_row();
+                ui.label(RichText::new("Amount:").strong());
+                ui.label(RichText::new(dash(sweep.amount())).strong());
+                ui.end_row();
+            });
+        ui.add_space(10.0);
+
+        if self.waiting {
+            ui.horizontal(|ui| {
+                ui.add(egui::Spinner::new());
+                ui.label("Broadcasting...");
+            });
+            return AppAction::None;
+        }
+
+        let mut action = AppAction::None;
+        ui.horizontal(|ui| {
+            if ui.button("Sweep").clicked() {
+                if let Some(wallet) = &self.selected_wallet {
+                    self.error_message = None;
+                    self.waiting = true;
+                    action = AppAction::BackendTask(BackendTask::CoreTask(
+                        CoreTask::BroadcastSweep(wallet.clone(), sweep.clone()),
+                    ));
+                }
+            }
+            if ui.button("Cancel").clicked() {
+                self.reset();
+            }
+        });
+        action
+    }
+}
+
+impl ScreenLike for SweepKeyScreen {
+    fn display_message(&mut self, message: &str, message_type: MessageType) {
+        if !self.waiting {
+            return;
+        }
+        self.waiting = false;
+        match message_type {
+            MessageType::Error => self.error_message = Some(message.to_string()),
+            _ => {
+                // The key is no longer needed once its funds were moved
+                self.private_key_input.zeroize();
+                self.sweep = None;
+                self.success_message = Some(message.to_string());
+            }
+        }
+    }
+
+    fn display_task_result(&mut self, backend_task_success_result: BackendTaskSuccessResult) {
+        if let BackendTaskSuccessResult::CoreItem(CoreItem::SweepTransaction(sweep)) =
+            backend_task_success_result
+        {
+            self.waiting = false;
+            self.sweep = Some(sweep);
+        }
+    }
+
+    fn ui(&mut self, ctx: &Context) -> AppAction {
+        let mut action = add_top_panel(
+            ctx,
+            &self.app_context,
+            vec![
+                ("Wallets", AppAction::GoToMainScreen),
+                ("Sweep Private Key", AppAction::None),
+            ],
+            vec![],
+        );
+
+        action |= add_left_panel(
+            ctx,
+            &self.app_context,
+            crate::ui::RootScreenType::RootScreenWalletsBalances,
+        );
+
+        action |= island_central_panel(ctx, |ui| {
+            let mut inner_action = AppAction::None;
+            egui::ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    ui.heading("Sweep Private Key");
+                    ui.label(
+                        "Move all funds held by a private key, such as a paper wallet, to a new receive address of one of your wallets.",
+                    );
+                    ui.add_space(10.0);
+
+                    if let Some(success_message) = &self.success_message {
+                        ui.colored_label(Color32::DARK_GREEN, success_message);
+                        ui.add_space(10.0);
+                        if ui.button("Sweep Another").clicked() {
+                            self.reset();
+                        }
+                        return;
+                    }
+
+                    match self.sweep.clone() {
+                        Some(sweep) => inner_action = self.render_review(ui, &sweep),
+                        None => {
+                            self.render_wallet_selection(ui);
+                            ui.add_space(10.0);
+                            inner_action = self.render_key_input(ui);
+                            ui.add_space(5.0);
+                            ui.label(
+                                RichText::new(
+                                    "Funds are looked up in Dash Core's UTXO set, so only confirmed outputs are found.",
+                                )
+                                .color(Color32::GRAY),
+                            );
+                        }
+                    }
+
+                    if let Some(error_message) = &self.error_message {
+                        ui.add_space(10.0);
+                        ui.colored_label(Color32::DARK_RED, error_message);
+                    }
+                });
+            inner_action
+        });
+
+        action
+    }
+}
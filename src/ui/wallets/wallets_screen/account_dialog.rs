@@ -0,0 +1,91 @@
+use super::WalletsBalancesScreen;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use eframe::egui::{self, Context};
+use egui::Color32;
+
+impl WalletsBalancesScreen {
+    /// Dialog for adding a new account or renaming the selected one.
+    pub(super) fn render_account_dialog(&mut self, ctx: &Context) {
+        let Some(selected_wallet) = self.selected_wallet.clone() else {
+            self.show_add_account_dialog = false;
+            self.show_rename_account_dialog = false;
+            return;
+        };
+        let adding = self.show_add_account_dialog;
+        let title = if adding {
+            "Add Account"
+        } else {
+            "Rename Account"
+        };
+
+        let mut open = true;
+        let mut close = false;
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    if adding {
+                        // New accounts are derived from the seed
+                        let (needed_unlock, just_unlocked) =
+                            self.render_wallet_unlock_if_needed(ui);
+                        if needed_unlock && !just_unlocked {
+                            return;
+                        }
+                    }
+
+                    ui.label("Account name:");
+                    ui.add_space(5.0);
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.account_name_input)
+                            .hint_text("Leave empty for the default name")
+                            .desired_width(250.0),
+                    );
+
+                    if let Some(error_message) = &self.error_message {
+                        ui.add_space(5.0);
+                        ui.colored_label(Color32::RED, error_message);
+                    }
+
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Save").clicked() {
+                            let mut name = self.account_name_input.trim().to_string();
+                            name.truncate(64);
+                            let name = (!name.is_empty()).then_some(name);
+
+                            let mut wallet = selected_wallet.write().unwrap();
+                            let result = if adding {
+                                wallet
+                                    .add_account(self.app_context.network, name, &self.app_context)
+                                    .map(|index| self.selected_account = index)
+                            } else {
+                                wallet.rename_account(
+                                    self.selected_account,
+                                    name,
+                                    &self.app_context,
+                                )
+                            };
+                            match result {
+                                Ok(()) => close = true,
+                                Err(e) => self.error_message = Some(e),
+                            }
+                        }
+
+                        if ui.button("Cancel").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+            });
+
+        if !open || close {
+            self.show_add_account_dialog = false;
+            self.show_rename_account_dialog = false;
+            self.account_name_input.clear();
+            self.error_message = None;
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use super::WalletsBalancesScreen;
+use crate::ui::MessageType;
+use chrono::Utc;
+use eframe::egui::{self, Context};
+use egui::{Color32, RichText};
+use zeroize::Zeroize;
+
+impl WalletsBalancesScreen {
+    pub(super) fn render_change_password_dialog(&mut self, ctx: &Context) {
+        let Some(selected_wallet) = self.selected_wallet.clone() else {
+            self.show_change_password_dialog = false;
+            return;
+        };
+        let uses_password = selected_wallet.read().unwrap().uses_password;
+
+        let mut open = true;
+        let mut close = false;
+        egui::Window::new(if uses_password {
+            "Change Wallet Password"
+        } else {
+            "Add Wallet Password"
+        })
+        .collapsible(false)
+        .resizable(false)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            egui::Grid::new("change_password_grid")
+                .num_columns(2)
+                .spacing((10.0, 5.0))
+                .show(ui, |ui| {
+                    if uses_password {
+                        ui.label("Current Password:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.current_password_input)
+                                .password(true),
+                        );
+                        ui.end_row();
+                    }
+
+                    ui.label("New Password:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.new_password_input).password(true),
+                    );
+                    ui.end_row();
+
+                    ui.label("Confirm New Password:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.confirm_password_input)
+                            .password(true),
+                    );
+                    ui.end_row();
+
+                    ui.label("Password Hint:");
+                    ui.text_edit_singleline(&mut self.password_hint_input);
+                    ui.end_row();
+                });
+
+            if uses_password {
+                ui.label(
+                    RichText::new("Leave the new password empty to remove the password. Anyone with access to this computer can then use the wallet.")
+                        .color(Color32::GRAY)
+                        .size(12.0),
+                );
+            }
+
+            if let Some(error_message) = &self.error_message {
+                ui.add_space(5.0);
+                ui.colored_label(Color32::RED, error_message);
+            }
+
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                if ui.button("Save").clicked() {
+                    if self.new_password_input != self.confirm_password_input {
+                        self.error_message = Some("The new passwords do not match".to_string());
+                    } else if !uses_password && self.new_password_input.is_empty() {
+                        self.error_message = Some("Enter a new password".to_string());
+                    } else {
+                        let hint = self.password_hint_input.trim();
+                        let result = self.app_context.change_wallet_password(
+                            &selected_wallet,
+                            uses_password.then_some(self.current_password_input.as_str()),
+                            (!self.new_password_input.is_empty())
+                                .then_some(self.new_password_input.as_str()),
+                            (!hint.is_empty()).then(|| hint.to_string()),
+                        );
+                        match result {
+                            Ok(()) => {
+                                self.message = Some((
+                                    "Wallet password changed".to_string(),
+                                    MessageType::Success,
+                                    Utc::now(),
+                                ));
+                                close = true;
+                            }
+                            Err(e) => self.error_message = Some(e),
+                        }
+                    }
+                }
+
+                if ui.button("Cancel").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+        if !open || close {
+            self.show_change_password_dialog = false;
+            self.current_password_input.zeroize();
+            self.new_password_input.zeroize();
+            self.confirm_password_input.zeroize();
+            self.password_hint_input.clear();
+            self.error_message = None;
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use super::{SortOrder, TransactionSortColumn, WalletsBalancesScreen};
+use crate::model::wallet::wallet_transaction::{TransactionDirection, WalletTransaction};
+use crate::ui::theme::DashColors;
+use chrono::DateTime;
+use dash_sdk::dpp::platform_value::string_encoding::Encoding;
+use eframe::egui::{self, ComboBox, Ui};
+use egui::{Color32, Frame, Margin, RichText};
+use egui_extras::{Column, TableBuilder};
+
+impl WalletsBalancesScreen {
+    pub(super) fn load_transactions(&mut self) {
+        self.transactions = match &self.selected_wallet {
+            Some(wallet) => {
+                let seed_hash = wallet.read().unwrap().seed_hash();
+                self.app_context
+                    .db
+                    .get_wallet_transactions(&seed_hash, self.app_context.network)
+                    .unwrap_or_default()
+            }
+            None => Vec::new(),
+        };
+    }
+
+    fn toggle_transaction_sort(&mut self, column: TransactionSortColumn) {
+        if self.transaction_sort_column == column {
+            self.transaction_sort_order = match self.transaction_sort_order {
+                SortOrder::Ascending => SortOrder::Descending,
+                SortOrder::Descending => SortOrder::Ascending,
+            };
+        } else {
+            self.transaction_sort_column = column;
+            self.transaction_sort_order = SortOrder::Ascending;
+        }
+    }
+
+    fn transaction_header_label(&self, column: TransactionSortColumn, name: &str) -> String {
+        if self.transaction_sort_column == column {
+            match self.transaction_sort_order {
+                SortOrder::Ascending => format!("{} ^", name),
+                SortOrder::Descending => format!("{} v", name),
+            }
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// The cached transactions matching the direction filter and search, in display order.
+    fn filtered_transactions(&self) -> Vec<&WalletTransaction> {
+        let search = self.transaction_search.trim().to_lowercase();
+        let mut transactions: Vec<&WalletTransaction> = self
+            .transactions
+            .iter()
+            .filter(|tx| {
+                self.transaction_direction_filter
+                    .is_none_or(|direction| tx.direction == direction)
+            })
+            .filter(|tx| {
+                search.is_empty()
+                    || tx.txid.to_string().contains(&search)
+                    || tx.identity_id.is_some_and(|id| {
+                        id.to_string(Encoding::Base58)
+                            .to_lowercase()
+                            .contains(&search)
+                    })
+            })
+            .collect();
+
+        transactions.sort_by(|a, b| {
+            let order = match self.transaction_sort_column {
+                TransactionSortColumn::Date => a.timestamp.cmp(&b.timestamp),
+                TransactionSortColumn::Type => a.direction.cmp(&b.direction),
+                TransactionSortColumn::Amount => a.amount.cmp(&b.amount),
+                TransactionSortColumn::Fee => a.fee.cmp(&b.fee),
+                TransactionSortColumn::Confirmations => a.confirmations.cmp(&b.confirmations),
+                TransactionSortColumn::TransactionId => a.txid.cmp(&b.txid),
+                TransactionSortColumn::Identity => a.identity_id.cmp(&b.identity_id),
+            };
+
+            if self.transaction_sort_order == SortOrder::Ascending {
+                order
+            } else {
+                order.reverse()
+            }
+        });
+        transactions
+    }
+
+    pub(super) fn render_transaction_history(&mut self, ui: &mut Ui) {
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        Frame::new()
+            .fill(DashColors::surface(dark_mode))
+            .corner_radius(5.0)
+            .inner_margin(Margin::same(15))
+            .stroke(egui::Stroke::new(1.0, DashColors::border_light(dark_mode)))
+            .show(ui, |ui| {
+                ui.heading(
+                    RichText::new("Transactions").color(DashColors::text_primary(dark_mode)),
+                );
+                ui.add_space(10.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Type:");
+                    let selected_text = self
+                        .transaction_direction_filter
+                        .map(|direction| direction.to_string())
+                        .unwrap_or_else(|| "All".to_string());
+                    ComboBox::from_id_salt("transaction_direction_filter")
+                        .selected_text(selected_text)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(
+                                &mut self.transaction_direction_filter,
+                                None,
+                                "All",
+                            );
+                            for direction in TransactionDirection::ALL {
+                                ui.selectable_value(
+                                    &mut self.transaction_direction_filter,
+                                    Some(direction),
+                                    direction.to_string(),
+                                );
+                            }
+                        });
+
+                    ui.add_space(10.0);
+                    ui.label("Search:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.transaction_search)
+                            .hint_text("Transaction ID or identity")
+                            .desired_width(250.0),
+                    );
+                });
+                ui.add_space(10.0);
+
+                let transactions = self.filtered_transactions();
+                if transactions.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(20.0);
+                        ui.label(
+                            RichText::new("No transactions found")
+                                .color(Color32::GRAY)
+                                .size(14.0),
+                        );
+                        ui.add_space(20.0);
+                    });
+                    return;
+                }
+
+                let mut clicked_column = None;
+                egui::ScrollArea::both()
+                    .id_salt("transaction_history_table")
+                    .show(ui, |ui| {
+                        TableBuilder::new(ui)
+                            .striped(false)
+                            .resizable(true)
+                            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                            .column(Column::initial(130.0)) // Date
+                            .column(Column::initial(80.0)) // Type
+                            .column(Column::initial(120.0)) // Amount
+                            .column(Column::initial(100.0)) // Fee
+                            .column(Column::initial(100.0)) // Confirmations
+                            .column(Column::initial(200.0)) // Transaction ID
+                            .column(Column::remainder()) // Identity
+                            .header(30.0, |mut header| {
+                                for (column, name) in [
+                                    (TransactionSortColumn::Date, "Date"),
+                                    (TransactionSortColumn::Type, "Type"),
+                                    (TransactionSortColumn::Amount, "Amount (DASH)"),
+                                    (TransactionSortColumn::Fee, "Fee (DASH)"),
+                                    (TransactionSortColumn::Confirmations, "Confirmations"),
+                                    (TransactionSortColumn::TransactionId, "Transaction ID"),
+                                    (TransactionSortColumn::Identity, "Identity"),
+                                ] {
+                                    header.col(|ui| {
+                                        if ui
+                                            .button(self.transaction_header_label(column, name))
+                                            .clicked()
+                                        {
+                                            clicked_column = Some(column);
+                                        }
+                                    });
+                                }
+                            })
+                            .body(|mut body| {
+                                for tx in &transactions {
+                                    body.row(25.0, |mut row| {
+                                        row.col(|ui| {
+                                            let date =
+                                                DateTime::from_timestamp(tx.timestamp as i64, 0)
+                                                    .map(|dt| {
+                                                        dt.format("%Y-%m-%d %H:%M").to_string()
+                                                    })
+                                                    .unwrap_or_default();
+                                            ui.label(date);
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(tx.direction.to_string());
+                                        });
+                                        row.col(|ui| {
+                                            let sign = match tx.direction {
+                                                TransactionDirection::Incoming => "+",
+                                                _ => "-",
+                                            };
+                                            ui.label(format!(
+                                                "{}{:.8}",
+                                                sign,
+                                                tx.amount as f64 * 1e-8
+                                            ));
+                                        });
+                                        row.col(|ui| match tx.fee {
+                                            Some(fee) => {
+                                                ui.label(format!("{:.8}", fee as f64 * 1e-8));
+                                            }
+                                            None => {
+                                                ui.label("-");
+                                            }
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(tx.confirmations.to_string());
+                                        });
+                                        row.col(|ui| {
+                                            ui.label(tx.txid.to_string());
+                                        });
+                                        row.col(|ui| {
+                                            let identity = tx
+                                                .identity_id
+                                                .map(|id| id.to_string(Encoding::Base58))
+                                                .unwrap_or_default();
+                                            ui.label(identity);
+                                        });
+                                    });
+                                }
+                            });
+                    });
+
+                if let Some(column) = clicked_column {
+                    self.toggle_transaction_sort(column);
+                }
+            });
+    }
+}
@@ -0,0 +1,53 @@
+use super::WalletsBalancesScreen;
+use crate::app::AppAction;
+use crate::backend_task::BackendTask;
+use crate::backend_task::identity::IdentityTask;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use eframe::egui::{self, Context};
+
+impl WalletsBalancesScreen {
+    pub(super) fn render_identity_recovery_dialog(&mut self, ctx: &Context) -> AppAction {
+        let Some(wallet) = self.selected_wallet.clone() else {
+            self.show_identity_recovery_dialog = false;
+            return AppAction::None;
+        };
+
+        let mut action = AppAction::None;
+        let mut open = true;
+        egui::Window::new("Recover Identities")
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label("Identity indices of the wallet are checked one after the other on Platform, and every identity found is loaded with the keys the wallet derives for it, along with its top ups known to Dash Core.");
+                ui.add_space(5.0);
+
+                let (needed_unlock, just_unlocked) = self.render_wallet_unlock_if_needed(ui);
+                if needed_unlock && !just_unlocked {
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Stop after this many unused indices in a row:");
+                    ui.add(egui::DragValue::new(&mut self.identity_recovery_gap).range(1..=100));
+                });
+                ui.add_space(5.0);
+
+                if ui.button("Recover").clicked() {
+                    self.recovering_identities = true;
+                    self.show_identity_recovery_dialog = false;
+                    action = AppAction::BackendTask(BackendTask::IdentityTask(
+                        IdentityTask::RecoverIdentitiesFromWallet(
+                            wallet.clone().into(),
+                            self.identity_recovery_gap,
+                        ),
+                    ));
+                }
+            });
+
+        if !open {
+            self.show_identity_recovery_dialog = false;
+        }
+        action
+    }
+}
@@ -1,11 +1,28 @@
+mod account_dialog;
+mod change_password_dialog;
+mod history;
+mod identity_recovery_dialog;
+mod receive_request_dialog;
+mod rescan;
+mod send_dialog;
+mod sign_message_dialog;
+
 use crate::app::{AppAction, DesiredAppAction};
-use crate::backend_task::BackendTask;
-use crate::backend_task::core::CoreTask;
+use crate::backend_task::core::{CoreItem, CoreTask, WalletRescanProgress};
+use crate::backend_task::identity::DEFAULT_IDENTITY_RECOVERY_GAP;
+use crate::backend_task::{BackendTask, BackendTaskSuccessResult};
 use crate::context::AppContext;
+use crate::model::payment_uri::PaymentUri;
 use crate::model::wallet::Wallet;
+use crate::model::wallet::balance::WalletBalance;
+use crate::model::wallet::coin_selection::CoinSelectionStrategy;
+use crate::model::wallet::wallet_transaction::{TransactionDirection, WalletTransaction};
+use crate::ui::components::fee_estimate::FeeEstimate;
 use crate::ui::components::left_panel::add_left_panel;
-use crate::ui::components::styled::island_central_panel;
+use crate::ui::components::styled::{StyledCheckbox, island_central_panel};
 use crate::ui::components::top_panel::add_top_panel;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::dropped_payment_uri;
 use crate::ui::theme::DashColors;
 use crate::ui::{MessageType, RootScreenType, ScreenLike, ScreenType};
 use chrono::{DateTime, Utc};
@@ -29,6 +46,17 @@ enum SortColumn {
     DerivationPath,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TransactionSortColumn {
+    Date,
+    Type,
+    Amount,
+    Fee,
+    Confirmations,
+    TransactionId,
+    Identity,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum SortOrder {
     Ascending,
@@ -37,14 +65,63 @@ enum SortOrder {
 
 pub struct WalletsBalancesScreen {
     selected_wallet: Option<Arc<RwLock<Wallet>>>,
+    selected_account: u32,
+    show_hidden_accounts: bool,
     pub(crate) app_context: Arc<AppContext>,
     message: Option<(String, MessageType, DateTime<Utc>)>,
     sort_column: SortColumn,
     sort_order: SortOrder,
     selected_filters: HashSet<String>,
+    transactions: Vec<WalletTransaction>,
+    transaction_sort_column: TransactionSortColumn,
+    transaction_sort_order: SortOrder,
+    transaction_direction_filter: Option<TransactionDirection>,
+    transaction_search: String,
     refreshing: bool,
+    rescanning: bool,
+    rescan_progress: Option<WalletRescanProgress>,
+    rescan_start_height_input: String,
     show_rename_dialog: bool,
     rename_input: String,
+    show_change_password_dialog: bool,
+    current_password_input: String,
+    new_password_input: String,
+    confirm_password_input: String,
+    password_hint_input: String,
+    show_add_account_dialog: bool,
+    show_rename_account_dialog: bool,
+    account_name_input: String,
+    show_send_dialog: bool,
+    send_payment_uri_input: String,
+    /// Payment request the send dialog was filled from
+    send_payment_uri: Option<PaymentUri>,
+    send_recipient_input: String,
+    send_amount_input: String,
+    send_subtract_fee: bool,
+    /// Whether the send dialog builds an asset lock instead of a payment
+    send_asset_lock: bool,
+    send_coin_selection: CoinSelectionStrategy,
+    send_fee_estimate: FeeEstimate,
+    sending: bool,
+    exported_transaction: Option<String>,
+    show_export_qr_code: bool,
+    /// Address a payment request is being made for
+    receive_request_address: Option<Address>,
+    receive_request_amount_input: String,
+    receive_request_label_input: String,
+    receive_request_message_input: String,
+    receive_request_instant_send: bool,
+    /// Address a message is being signed with
+    sign_message_address: Option<Address>,
+    sign_message_input: String,
+    sign_message_signature: Option<String>,
+    show_identity_recovery_dialog: bool,
+    /// Unused identity indices in a row after which the recovery scan stops
+    identity_recovery_gap: u32,
+    recovering_identities: bool,
+    wallet_password: String,
+    show_password: bool,
+    error_message: Option<String>,
 }
 
 pub trait DerivationPathHelpers {
@@ -108,10 +185,43 @@ impl DerivationPathHelpers for DerivationPath {
     }
 }
 
+/// Every part of a balance, one per line, for hover texts.
+fn balance_breakdown(balance: &WalletBalance) -> String {
+    [
+        ("Confirmed", balance.confirmed),
+        ("InstantSend locked", balance.instant_locked),
+        ("Unconfirmed", balance.unconfirmed),
+        ("Immature", balance.immature),
+    ]
+    .iter()
+    .map(|(name, amount)| format!("{}: {:.8} DASH", name, *amount as f64 * 1e-8))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Shows the part of a balance that is not settled yet, if any.
+fn render_pending_balance(ui: &mut Ui, balance: &WalletBalance) {
+    if balance.pending() == 0 {
+        return;
+    }
+    let mut parts = vec![];
+    if balance.unconfirmed > 0 {
+        parts.push(format!(
+            "{:.8} unconfirmed",
+            balance.unconfirmed as f64 * 1e-8
+        ));
+    }
+    if balance.immature > 0 {
+        parts.push(format!("{:.8} immature", balance.immature as f64 * 1e-8));
+    }
+    ui.label(RichText::new(format!("({})", parts.join(", "))).color(Color32::GRAY))
+        .on_hover_text(balance_breakdown(balance));
+}
+
 // Define a struct to hold the address data
 struct AddressData {
     address: Address,
-    balance: u64,
+    balance: WalletBalance,
     utxo_count: usize,
     total_received: u64,
     address_type: String,
@@ -124,24 +234,75 @@ impl WalletsBalancesScreen {
         let selected_wallet = app_context.wallets.read().unwrap().values().next().cloned();
         let mut selected_filters = HashSet::new();
         selected_filters.insert("Funds".to_string()); // "Funds" selected by default
-        Self {
+        let mut screen = Self {
             selected_wallet,
+            selected_account: 0,
+            show_hidden_accounts: false,
             app_context: app_context.clone(),
             message: None,
             sort_column: SortColumn::Index,
             sort_order: SortOrder::Ascending,
             selected_filters,
+            transactions: Vec::new(),
+            transaction_sort_column: TransactionSortColumn::Date,
+            transaction_sort_order: SortOrder::Descending,
+            transaction_direction_filter: None,
+            transaction_search: String::new(),
             refreshing: false,
+            rescanning: false,
+            rescan_progress: None,
+            rescan_start_height_input: String::new(),
             show_rename_dialog: false,
             rename_input: String::new(),
-        }
+            show_change_password_dialog: false,
+            current_password_input: String::new(),
+            new_password_input: String::new(),
+            confirm_password_input: String::new(),
+            password_hint_input: String::new(),
+            show_add_account_dialog: false,
+            show_rename_account_dialog: false,
+            account_name_input: String::new(),
+            show_send_dialog: false,
+            send_payment_uri_input: String::new(),
+            send_payment_uri: None,
+            send_recipient_input: String::new(),
+            send_amount_input: String::new(),
+            send_subtract_fee: false,
+            send_asset_lock: false,
+            send_coin_selection: CoinSelectionStrategy::default(),
+            send_fee_estimate: FeeEstimate::default(),
+            sending: false,
+            exported_transaction: None,
+            show_export_qr_code: false,
+            receive_request_address: None,
+            receive_request_amount_input: String::new(),
+            receive_request_label_input: String::new(),
+            receive_request_message_input: String::new(),
+            receive_request_instant_send: false,
+            sign_message_address: None,
+            sign_message_input: String::new(),
+            sign_message_signature: None,
+            show_identity_recovery_dialog: false,
+            identity_recovery_gap: DEFAULT_IDENTITY_RECOVERY_GAP,
+            recovering_identities: false,
+            wallet_password: String::new(),
+            show_password: false,
+            error_message: None,
+        };
+        screen.load_transactions();
+        screen
     }
 
     fn add_receiving_address(&mut self) {
         if let Some(wallet) = &self.selected_wallet {
             let result = {
                 let mut wallet = wallet.write().unwrap();
-                wallet.receive_address(self.app_context.network, true, Some(&self.app_context))
+                wallet.receive_address(
+                    self.app_context.network,
+                    self.selected_account,
+                    true,
+                    Some(&self.app_context),
+                )
             };
 
             // Now the immutable borrow of `wallet` is dropped, and we can use `self` mutably
@@ -168,7 +329,7 @@ impl WalletsBalancesScreen {
         data.sort_by(|a, b| {
             let order = match self.sort_column {
                 SortColumn::Address => a.address.cmp(&b.address),
-                SortColumn::Balance => a.balance.cmp(&b.balance),
+                SortColumn::Balance => a.balance.total().cmp(&b.balance.total()),
                 SortColumn::UTXOs => a.utxo_count.cmp(&b.utxo_count),
                 SortColumn::TotalReceived => a.total_received.cmp(&b.total_received),
                 SortColumn::Type => a.address_type.cmp(&b.address_type),
@@ -197,6 +358,7 @@ impl WalletsBalancesScreen {
                 "Unused Asset Locks",
                 "Show available asset locks for identity creation",
             ),
+            ("Transactions", "Show the wallet's transaction history"),
         ];
 
         // Single row layout
@@ -253,6 +415,7 @@ impl WalletsBalancesScreen {
 
     fn render_wallet_selection(&mut self, ui: &mut Ui) {
         let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let mut wallet_changed = false;
         if self.app_context.has_wallet.load(Ordering::Relaxed) {
             let wallets = &self.app_context.wallets.read().unwrap();
             let wallet_aliases: Vec<String> = wallets
@@ -288,12 +451,17 @@ impl WalletsBalancesScreen {
                                 .as_ref()
                                 .is_some_and(|selected| Arc::ptr_eq(selected, wallet));
 
-                            if ui
-                                .selectable_label(is_selected, wallet_alias.clone())
-                                .clicked()
+                            let wallet_balance = wallet.read().unwrap().balance().total();
+                            let entry = format!(
+                                "{} ({:.4} DASH)",
+                                wallet_alias,
+                                wallet_balance as f64 * 1e-8
+                            );
+                            if ui.selectable_label(is_selected, entry).clicked()
                             {
                                 // Update the selected wallet
                                 self.selected_wallet = Some(wallet.clone());
+                                wallet_changed = true;
                             }
                         }
                     });
@@ -305,6 +473,36 @@ impl WalletsBalancesScreen {
                         self.show_rename_dialog = true;
                         self.rename_input = wallet.alias.clone().unwrap_or_default();
                     }
+
+                    if !wallet.is_watch_only() {
+                        let label = if wallet.uses_password {
+                            "Change Password"
+                        } else {
+                            "Add Password"
+                        };
+                        if ui.button(label).clicked() {
+                            self.show_change_password_dialog = true;
+                            self.password_hint_input =
+                                wallet.password_hint().clone().unwrap_or_default();
+                            self.error_message = None;
+                        }
+                    }
+
+                    let send_label = if wallet.is_watch_only() {
+                        ui.label(RichText::new("Watch-only").italics().color(Color32::GRAY))
+                            .on_hover_text("This wallet was imported from an extended public key and can not sign transactions");
+                        "Export Payment"
+                    } else {
+                        "Send"
+                    };
+                    if let Some(hint) = wallet.passphrase_hint() {
+                        ui.label(RichText::new("BIP39 Passphrase").italics().color(Color32::GRAY))
+                            .on_hover_text(format!("Passphrase hint: {}", hint));
+                    }
+                    if ui.button(send_label).clicked() {
+                        self.show_send_dialog = true;
+                        self.error_message = None;
+                    }
                 }
 
                 // Balance and rename button on same row
@@ -312,22 +510,118 @@ impl WalletsBalancesScreen {
                     ui.separator();
 
                     let wallet = selected_wallet.read().unwrap();
-                    let total_balance = wallet.max_balance();
-                    let dash_balance = total_balance as f64 * 1e-8; // Convert to DASH
+                    let balance = wallet.balance();
+                    let dash_balance = balance.total() as f64 * 1e-8; // Convert to DASH
                     ui.label(
                         RichText::new(format!("Balance: {:.8} DASH", dash_balance))
                             .strong()
                             .color(DashColors::success_color(dark_mode)),
-                    );
+                    )
+                    .on_hover_text(balance_breakdown(&balance));
+                    render_pending_balance(ui, &balance);
                 }
             });
         } else {
             ui.label("No wallets available.");
         }
+
+        if wallet_changed {
+            self.selected_account = 0;
+            self.load_transactions();
+        }
+    }
+
+    fn render_account_selection(&mut self, ui: &mut Ui) {
+        let Some(selected_wallet) = self.selected_wallet.clone() else {
+            return;
+        };
+        let dark_mode = ui.ctx().style().visuals.dark_mode;
+        let mut set_hidden = None;
+
+        ui.horizontal(|ui| {
+            let wallet = selected_wallet.read().unwrap();
+            if !wallet.accounts.contains_key(&self.selected_account) {
+                self.selected_account = 0;
+            }
+            let selected_account = &wallet.accounts[&self.selected_account];
+
+            ui.label("Account:");
+            ComboBox::from_id_salt("wallet_account_selection")
+                .selected_text(selected_account.display_name())
+                .width(200.0)
+                .show_ui(ui, |ui| {
+                    for account in wallet.accounts.values() {
+                        if account.hidden && !self.show_hidden_accounts {
+                            continue;
+                        }
+                        let label = if account.hidden {
+                            format!("{} (hidden)", account.display_name())
+                        } else {
+                            account.display_name()
+                        };
+                        ui.selectable_value(&mut self.selected_account, account.index, label);
+                    }
+                });
+
+            // Accounts are derived from the seed, which watch-only wallets don't have
+            if !wallet.is_watch_only() && ui.button("Add Account").clicked() {
+                self.show_add_account_dialog = true;
+                self.account_name_input.clear();
+                self.error_message = None;
+            }
+
+            if ui.button("Rename Account").clicked() {
+                self.show_rename_account_dialog = true;
+                self.account_name_input = selected_account.name.clone().unwrap_or_default();
+            }
+
+            if selected_account.index != 0 {
+                let label = if selected_account.hidden {
+                    "Unhide"
+                } else {
+                    "Hide"
+                };
+                if ui.button(label).clicked() {
+                    set_hidden = Some(!selected_account.hidden);
+                }
+            }
+
+            StyledCheckbox::new(&mut self.show_hidden_accounts, "Show hidden accounts").show(ui);
+
+            ui.separator();
+
+            let account_balance = wallet.balance_of_account(self.selected_account);
+            ui.label(
+                RichText::new(format!(
+                    "Account Balance: {:.8} DASH",
+                    account_balance.total() as f64 * 1e-8
+                ))
+                .color(DashColors::success_color(dark_mode)),
+            )
+            .on_hover_text(balance_breakdown(&account_balance));
+            render_pending_balance(ui, &account_balance);
+        });
+
+        if let Some(hidden) = set_hidden {
+            let result = selected_wallet.write().unwrap().set_account_hidden(
+                self.selected_account,
+                hidden,
+                &self.app_context,
+            );
+            match result {
+                Ok(()) if hidden && !self.show_hidden_accounts => self.selected_account = 0,
+                Ok(()) => {}
+                Err(e) => self.display_message(&e, MessageType::Error),
+            }
+        }
     }
 
     fn render_address_table(&mut self, ui: &mut Ui) -> AppAction {
         let action = AppAction::None;
+        let is_watch_only = self
+            .selected_wallet
+            .as_ref()
+            .is_some_and(|wallet| wallet.read().unwrap().is_watch_only());
 
         let mut included_address_types = HashSet::new();
 
@@ -356,10 +650,11 @@ impl WalletsBalancesScreen {
 
                     let utxo_count = utxo_info.map(|outpoints| outpoints.len()).unwrap_or(0);
 
-                    // Calculate total received by summing UTXO values
-                    let total_received = utxo_info
-                        .map(|outpoints| outpoints.values().map(|txout| txout.value).sum::<u64>())
-                        .unwrap_or(0u64);
+                    let total_received = wallet
+                        .address_total_received
+                        .get(address)
+                        .cloned()
+                        .unwrap_or_default();
 
                     let index = derivation_path
                         .into_iter()
@@ -382,14 +677,12 @@ impl WalletsBalancesScreen {
                             "System".to_string()
                         };
 
-                    if included_address_types.contains(address_type.as_str()) {
+                    if included_address_types.contains(address_type.as_str())
+                        && wallet.account_of_address(address) == self.selected_account
+                    {
                         Some(AddressData {
                             address: address.clone(),
-                            balance: wallet
-                                .address_balances
-                                .get(address)
-                                .cloned()
-                                .unwrap_or_default(),
+                            balance: wallet.address_balance(address),
                             utxo_count,
                             total_received,
                             address_type,
@@ -522,10 +815,31 @@ impl WalletsBalancesScreen {
                             body.row(25.0, |mut row| {
                                 row.col(|ui| {
                                     ui.label(data.address.to_string());
+                                    if ui
+                                        .small_button("Request")
+                                        .on_hover_text("Create a payment request for this address")
+                                        .clicked()
+                                    {
+                                        self.open_receive_request(data.address.clone());
+                                    }
+                                    if !is_watch_only
+                                        && ui
+                                            .small_button("Sign")
+                                            .on_hover_text(
+                                                "Sign a message to prove you own this address",
+                                            )
+                                            .clicked()
+                                    {
+                                        self.sign_message_address = Some(data.address.clone());
+                                        self.sign_message_input.clear();
+                                        self.sign_message_signature = None;
+                                    }
                                 });
                                 row.col(|ui| {
-                                    let dash_balance = data.balance as f64 * 1e-8;
-                                    ui.label(format!("{:.8}", dash_balance));
+                                    let dash_balance = data.balance.total() as f64 * 1e-8;
+                                    ui.label(format!("{:.8}", dash_balance))
+                                        .on_hover_text(balance_breakdown(&data.balance));
+                                    render_pending_balance(ui, &data.balance);
                                 });
                                 row.col(|ui| {
                                     ui.label(format!("{}", data.utxo_count));
@@ -550,18 +864,36 @@ impl WalletsBalancesScreen {
         action
     }
 
-    fn render_bottom_options(&mut self, ui: &mut Ui) {
-        if self.selected_filters.contains("Funds") {
-            ui.add_space(10.0);
-            ui.horizontal(|ui| {
-                if ui
+    fn render_bottom_options(&mut self, ui: &mut Ui) -> AppAction {
+        let mut action = AppAction::None;
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            if self.selected_filters.contains("Funds")
+                && ui
                     .button(RichText::new("➕ Add Receiving Address").size(14.0))
                     .clicked()
-                {
-                    self.add_receiving_address();
-                }
-            });
-        }
+            {
+                self.add_receiving_address();
+            }
+
+            action |= self.render_rescan_button(ui);
+
+            if self.recovering_identities {
+                ui.spinner();
+                ui.label("Recovering identities...");
+            } else if self
+                .selected_wallet
+                .as_ref()
+                .is_some_and(|wallet| !wallet.read().unwrap().is_watch_only())
+                && ui
+                    .button(RichText::new("Recover Identities").size(14.0))
+                    .on_hover_text("Finds the identities registered with this wallet, e.g. after restoring it from its seed, and loads them with their keys.")
+                    .clicked()
+            {
+                self.show_identity_recovery_dialog = true;
+            }
+        });
+        action
     }
 
     fn render_wallet_asset_locks(&mut self, ui: &mut Ui) -> AppAction {
@@ -744,6 +1076,18 @@ impl ScreenLike for WalletsBalancesScreen {
             match self.refreshing {
                 true => vec![
                     ("Refreshing...", DesiredAppAction::None),
+                    (
+                        "Sign Transaction",
+                        DesiredAppAction::AddScreenType(Box::new(ScreenType::SignTransaction)),
+                    ),
+                    (
+                        "Export Shares",
+                        DesiredAppAction::AddScreenType(Box::new(ScreenType::ExportSlip39Shares)),
+                    ),
+                    (
+                        "Sweep Key",
+                        DesiredAppAction::AddScreenType(Box::new(ScreenType::SweepPrivateKey)),
+                    ),
                     (
                         "Import Wallet",
                         DesiredAppAction::AddScreenType(Box::new(ScreenType::ImportWallet)),
@@ -760,6 +1104,18 @@ impl ScreenLike for WalletsBalancesScreen {
                             CoreTask::RefreshWalletInfo(wallet.clone()),
                         ))),
                     ),
+                    (
+                        "Sign Transaction",
+                        DesiredAppAction::AddScreenType(Box::new(ScreenType::SignTransaction)),
+                    ),
+                    (
+                        "Export Shares",
+                        DesiredAppAction::AddScreenType(Box::new(ScreenType::ExportSlip39Shares)),
+                    ),
+                    (
+                        "Sweep Key",
+                        DesiredAppAction::AddScreenType(Box::new(ScreenType::SweepPrivateKey)),
+                    ),
                     (
                         "Import Wallet",
                         DesiredAppAction::AddScreenType(Box::new(ScreenType::ImportWallet)),
@@ -820,7 +1176,11 @@ impl ScreenLike for WalletsBalancesScreen {
                                 .inner_margin(Margin::symmetric(15, 10))
                                 .stroke(egui::Stroke::new(1.0, DashColors::border_light(dark_mode)))
                                 .show(ui, |ui| {
-                                    self.render_wallet_selection(ui);
+                                    ui.vertical(|ui| {
+                                        self.render_wallet_selection(ui);
+                                        ui.add_space(5.0);
+                                        self.render_account_selection(ui);
+                                    });
                                 });
                         });
                     });
@@ -851,9 +1211,9 @@ impl ScreenLike for WalletsBalancesScreen {
                         });
                         ui.add_space(10.0);
 
-                        if !(self.selected_filters.contains("Unused Asset Locks")
-                            && self.selected_filters.len() == 1)
-                        {
+                        if !self.selected_filters.iter().all(|filter| {
+                            filter == "Unused Asset Locks" || filter == "Transactions"
+                        }) {
                             inner_action |= self.render_address_table(ui);
                         }
 
@@ -863,8 +1223,13 @@ impl ScreenLike for WalletsBalancesScreen {
                             inner_action |= self.render_wallet_asset_locks(ui);
                         }
 
+                        if self.selected_filters.contains("Transactions") {
+                            ui.add_space(15.0);
+                            self.render_transaction_history(ui);
+                        }
+
                         ui.add_space(10.0);
-                        self.render_bottom_options(ui);
+                        inner_action |= self.render_bottom_options(ui);
                     } else {
                         ui.vertical_centered(|ui| {
                             ui.add_space(50.0);
@@ -955,10 +1320,48 @@ impl ScreenLike for WalletsBalancesScreen {
                 });
         }
 
-        if let AppAction::BackendTask(BackendTask::CoreTask(CoreTask::RefreshWalletInfo(_))) =
-            action
-        {
-            self.refreshing = true;
+        if self.show_add_account_dialog || self.show_rename_account_dialog {
+            self.render_account_dialog(ctx);
+        }
+
+        if self.show_change_password_dialog {
+            self.render_change_password_dialog(ctx);
+        }
+
+        // A payment request dropped on the window starts a payment from the selected wallet
+        if let Some(uri) = dropped_payment_uri(ctx) {
+            if self.selected_wallet.is_some() && self.exported_transaction.is_none() {
+                self.show_send_dialog = true;
+                self.send_payment_uri_input = uri;
+                self.apply_payment_uri();
+            }
+        }
+
+        if self.show_send_dialog {
+            action |= self.render_send_dialog(ctx);
+        }
+
+        if self.receive_request_address.is_some() {
+            self.render_receive_request_dialog(ctx);
+        }
+
+        if self.sign_message_address.is_some() {
+            self.render_sign_message_dialog(ctx);
+        }
+
+        if self.show_identity_recovery_dialog {
+            action |= self.render_identity_recovery_dialog(ctx);
+        }
+
+        match &action {
+            AppAction::BackendTask(BackendTask::CoreTask(CoreTask::RefreshWalletInfo(_))) => {
+                self.refreshing = true;
+            }
+            AppAction::BackendTask(BackendTask::CoreTask(CoreTask::RescanWallet(_, _))) => {
+                self.rescanning = true;
+                self.rescan_progress = None;
+            }
+            _ => {}
         }
 
         action
@@ -970,18 +1373,99 @@ impl ScreenLike for WalletsBalancesScreen {
         {
             self.refreshing = false;
         }
+        if message.starts_with("Rescan finished") || message.contains("Error rescanning wallet") {
+            self.rescanning = false;
+            self.rescan_progress = None;
+        }
+        if self.recovering_identities
+            && (message_type == MessageType::Error
+                || message.starts_with("Recovered ")
+                || message.starts_with("No identities found"))
+        {
+            self.recovering_identities = false;
+        }
+        if message_type == MessageType::Success {
+            // Refreshes and sends add to the transaction history
+            self.load_transactions();
+        }
         self.message = Some((message.to_string(), message_type, Utc::now()))
     }
 
     fn display_task_result(
         &mut self,
-        _backend_task_success_result: crate::ui::BackendTaskSuccessResult,
+        backend_task_success_result: crate::ui::BackendTaskSuccessResult,
     ) {
-        // Nothing
-        // If we don't include this, messages from the ZMQ listener will keep popping up
+        if let BackendTaskSuccessResult::CoreItem(CoreItem::SentFunds(sent)) =
+            &backend_task_success_result
+        {
+            match sent {
+                Ok(message) => {
+                    self.close_send_dialog();
+                    self.display_message(message, MessageType::Success);
+                }
+                Err(e) => {
+                    self.sending = false;
+                    self.error_message = Some(e.clone());
+                }
+            }
+            return;
+        }
+        if let BackendTaskSuccessResult::CoreItem(CoreItem::UnsignedTransaction(unsigned)) =
+            backend_task_success_result
+        {
+            self.sending = false;
+            match unsigned.and_then(|unsigned| unsigned.to_json()) {
+                Ok(json) => self.exported_transaction = Some(json),
+                Err(e) => self.error_message = Some(e),
+            }
+            return;
+        }
+        if let BackendTaskSuccessResult::CoreItem(CoreItem::WalletRescanProgress(progress)) =
+            backend_task_success_result
+        {
+            self.rescan_progress = Some(progress);
+            return;
+        }
+        // No message here, otherwise messages from the ZMQ listener will keep popping up,
+        // but received transactions still belong in the history
+        self.load_transactions();
     }
 
-    fn refresh_on_arrival(&mut self) {}
+    fn refresh_on_arrival(&mut self) {
+        self.load_transactions();
+    }
 
-    fn refresh(&mut self) {}
+    fn refresh(&mut self) {
+        self.load_transactions();
+    }
+}
+
+impl ScreenWithWalletUnlock for WalletsBalancesScreen {
+    fn selected_wallet_ref(&self) -> &Option<Arc<RwLock<Wallet>>> {
+        &self.selected_wallet
+    }
+
+    fn wallet_password_ref(&self) -> &String {
+        &self.wallet_password
+    }
+
+    fn wallet_password_mut(&mut self) -> &mut String {
+        &mut self.wallet_password
+    }
+
+    fn show_password(&self) -> bool {
+        self.show_password
+    }
+
+    fn show_password_mut(&mut self) -> &mut bool {
+        &mut self.show_password
+    }
+
+    fn set_error_message(&mut self, error_message: Option<String>) {
+        self.error_message = error_message;
+    }
+
+    fn error_message(&self) -> Option<&String> {
+        self.error_message.as_ref()
+    }
 }
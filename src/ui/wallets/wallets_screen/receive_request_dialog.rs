@@ -0,0 +1,121 @@
+use super::WalletsBalancesScreen;
+use crate::model::payment_uri::{PaymentUri, parse_dash_amount};
+use crate::ui::components::styled::StyledCheckbox;
+use crate::ui::identities::funding_common::{copy_to_clipboard, generate_qr_code_image};
+use dash_sdk::dashcore_rpc::dashcore::Address;
+use eframe::egui::{self, Context};
+use egui::Color32;
+
+impl WalletsBalancesScreen {
+    pub(super) fn open_receive_request(&mut self, address: Address) {
+        self.receive_request_address = Some(address);
+        self.receive_request_amount_input.clear();
+        self.receive_request_label_input.clear();
+        self.receive_request_message_input.clear();
+        self.receive_request_instant_send = false;
+    }
+
+    /// The payment request entered in the receive request dialog.
+    fn receive_request(&self, address: &Address) -> Result<PaymentUri, String> {
+        let optional = |input: &String| Some(input.trim().to_string()).filter(|s| !s.is_empty());
+        let amount = match optional(&self.receive_request_amount_input) {
+            Some(amount) => Some(parse_dash_amount(&amount)?),
+            None => None,
+        };
+        Ok(PaymentUri {
+            address: address.clone(),
+            amount,
+            label: optional(&self.receive_request_label_input),
+            message: optional(&self.receive_request_message_input),
+            instant_send: self.receive_request_instant_send,
+        })
+    }
+
+    /// Dialog building a `dash:` payment request, with its QR code, for one of the wallet's
+    /// addresses.
+    pub(super) fn render_receive_request_dialog(&mut self, ctx: &Context) {
+        let Some(address) = self.receive_request_address.clone() else {
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new("Request Payment")
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label(format!("Address: {}", address));
+                ui.add_space(5.0);
+                egui::Grid::new("receive_request_grid")
+                    .num_columns(2)
+                    .spacing((10.0, 5.0))
+                    .show(ui, |ui| {
+                        ui.label("Amount (DASH):");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.receive_request_amount_input)
+                                .hint_text("Optional")
+                                .desired_width(150.0),
+                        );
+                        ui.end_row();
+
+                        ui.label("Label:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.receive_request_label_input)
+                                .hint_text("Your name, optional"),
+                        );
+                        ui.end_row();
+
+                        ui.label("Message:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.receive_request_message_input)
+                                .hint_text("What the payment is for, optional"),
+                        );
+                        ui.end_row();
+                    });
+                StyledCheckbox::new(
+                    &mut self.receive_request_instant_send,
+                    "Ask for InstantSend (IS=1)",
+                )
+                .show(ui);
+                ui.add_space(10.0);
+
+                let uri = match self.receive_request(&address) {
+                    Ok(payment_uri) => payment_uri.to_uri(),
+                    Err(e) => {
+                        ui.colored_label(Color32::RED, e);
+                        return;
+                    }
+                };
+
+                ui.vertical_centered(|ui| {
+                    match generate_qr_code_image(&uri) {
+                        Ok(qr_image) => {
+                            let texture = ui.ctx().load_texture(
+                                "receive_request_qr_code",
+                                qr_image,
+                                egui::TextureOptions::NEAREST,
+                            );
+                            ui.image(&texture);
+                        }
+                        Err(_) => {
+                            ui.colored_label(
+                                Color32::RED,
+                                "The request is too long for a QR code, shorten the message.",
+                            );
+                        }
+                    }
+                    ui.add_space(5.0);
+                    ui.label(&uri);
+                    if ui.button("Copy").clicked() {
+                        if let Err(e) = copy_to_clipboard(&uri) {
+                            self.error_message = Some(e);
+                        }
+                    }
+                });
+            });
+
+        if !open {
+            self.receive_request_address = None;
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use super::WalletsBalancesScreen;
+use crate::app::AppAction;
+use crate::backend_task::BackendTask;
+use crate::backend_task::core::{CoreTask, WalletRescanProgress};
+use egui::{self, RichText, Ui};
+
+impl WalletsBalancesScreen {
+    /// Button rescanning the selected wallet, or the progress of the running rescan.
+    pub(super) fn render_rescan_button(&mut self, ui: &mut Ui) -> AppAction {
+        if self.rescanning {
+            ui.spinner();
+            ui.label(rescan_progress_text(self.rescan_progress));
+            return AppAction::None;
+        }
+        let Some(wallet) = self.selected_wallet.clone() else {
+            return AppAction::None;
+        };
+        let clicked = ui
+            .button(RichText::new("Rescan").size(14.0))
+            .on_hover_text("Missing funds? Imports the wallet's addresses into Dash Core where needed, rescans the blockchain and reloads the wallet.")
+            .clicked();
+        ui.label("from block");
+        ui.add(
+            egui::TextEdit::singleline(&mut self.rescan_start_height_input)
+                .hint_text("0")
+                .desired_width(70.0),
+        )
+        .on_hover_text("Blocks before the wallet's first transaction don't need to be rescanned, leave empty if unsure.");
+        if !clicked {
+            return AppAction::None;
+        }
+
+        let start_height = match self.rescan_start_height_input.trim() {
+            "" => None,
+            height => match height.parse::<u32>() {
+                Ok(height) => Some(height),
+                Err(_) => {
+                    self.error_message =
+                        Some("The block height must be a whole number".to_string());
+                    return AppAction::None;
+                }
+            },
+        };
+        AppAction::BackendTask(BackendTask::CoreTask(CoreTask::RescanWallet(
+            wallet,
+            start_height,
+        )))
+    }
+}
+
+fn rescan_progress_text(progress: Option<WalletRescanProgress>) -> String {
+    match progress {
+        None => "Starting rescan...".to_string(),
+        Some(WalletRescanProgress::ImportingAddresses { imported, total }) => format!(
+            "Importing addresses into Dash Core ({}/{})...",
+            imported, total
+        ),
+        Some(WalletRescanProgress::Rescanning) => {
+            "Dash Core is rescanning the blockchain, this can take a while...".to_string()
+        }
+        Some(WalletRescanProgress::LoadingBalances) => "Loading balances...".to_string(),
+    }
+}
@@ -0,0 +1,341 @@
+use super::WalletsBalancesScreen;
+use crate::app::AppAction;
+use crate::backend_task::BackendTask;
+use crate::backend_task::core::{CoreTask, WalletAssetLockInfo, WalletPaymentInfo};
+use crate::model::payment_uri::{PaymentUri, format_dash_amount};
+use crate::model::wallet::Wallet;
+use crate::model::wallet::fee_estimation::FeeEstimator;
+use crate::ui::components::styled::StyledCheckbox;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::helpers::{render_coin_selection_chooser, render_transaction_export};
+use dash_sdk::dashcore_rpc::dashcore::Address;
+use eframe::egui::{self, Context, Ui};
+use egui::{Color32, RichText};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+
+impl WalletsBalancesScreen {
+    pub(super) fn render_send_dialog(&mut self, ctx: &Context) -> AppAction {
+        let mut action = AppAction::None;
+        let Some(selected_wallet) = self.selected_wallet.clone() else {
+            self.show_send_dialog = false;
+            return action;
+        };
+
+        let watch_only = selected_wallet.read().unwrap().is_watch_only();
+        let title = if watch_only {
+            "Export Payment"
+        } else {
+            "Send Dash"
+        };
+
+        let mut open = self.show_send_dialog;
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    if let Some(exported_transaction) = self.exported_transaction.clone() {
+                        ui.label("Sign this transaction on the offline instance holding the seed (Wallets > Sign Transaction), then broadcast the signed result from there.");
+                        ui.add_space(5.0);
+                        if let Some(error) = render_transaction_export(
+                            ui,
+                            &exported_transaction,
+                            "unsigned_transaction.json",
+                            &mut self.show_export_qr_code,
+                        ) {
+                            self.error_message = Some(error);
+                        }
+                        if let Some(error_message) = &self.error_message {
+                            ui.add_space(5.0);
+                            ui.colored_label(Color32::RED, error_message);
+                        }
+                        ui.add_space(10.0);
+                        if ui.button("Done").clicked() {
+                            self.close_send_dialog();
+                        }
+                        return;
+                    }
+
+                    // Exporting an unsigned payment doesn't need the seed
+                    if !watch_only {
+                        let (needed_unlock, just_unlocked) =
+                            self.render_wallet_unlock_if_needed(ui);
+                        if needed_unlock && !just_unlocked {
+                            return;
+                        }
+                    }
+
+                    let (account_name, max_balance) = {
+                        let wallet = selected_wallet.read().unwrap();
+                        let account_name = wallet
+                            .accounts
+                            .get(&self.selected_account)
+                            .map(|account| account.display_name())
+                            .unwrap_or_default();
+                        (account_name, wallet.account_balance(self.selected_account))
+                    };
+                    ui.label(format!(
+                        "Available in {}: {:.8} DASH",
+                        account_name,
+                        max_balance as f64 * 1e-8
+                    ));
+                    ui.add_space(5.0);
+
+                    StyledCheckbox::new(
+                        &mut self.send_asset_lock,
+                        "Asset lock for Platform credits",
+                    )
+                    .show(ui);
+                    ui.add_space(5.0);
+
+                    if self.send_asset_lock {
+                        ui.label(
+                            RichText::new("The credits go to a new address of this wallet. Once the signed transaction is broadcast, the asset lock can register or top up an identity from any instance holding the seed.")
+                                .color(Color32::GRAY)
+                                .size(12.0),
+                        );
+                        ui.add_space(5.0);
+                    } else {
+                        self.render_send_recipient(ui);
+                    }
+
+                    ui.label("Amount (DASH):");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.send_amount_input)
+                                .hint_text("0.0")
+                                .desired_width(150.0),
+                        );
+                        if ui.button("Max").clicked() {
+                            self.send_amount_input = format!("{:.8}", max_balance as f64 * 1e-8);
+                            self.send_subtract_fee = true;
+                        }
+                    });
+                    StyledCheckbox::new(&mut self.send_subtract_fee, "Subtract fee from amount")
+                        .show(ui);
+                    ui.add_space(5.0);
+                    render_coin_selection_chooser(
+                        ui,
+                        "send_coin_selection",
+                        &mut self.send_coin_selection,
+                    );
+                    let estimate_inputs = if self.send_asset_lock {
+                        self.parse_send_amount().map(|amount| (amount, None))
+                    } else {
+                        self.parse_send_inputs()
+                            .map(|(recipient, amount)| (amount, Some(recipient)))
+                    };
+                    if let Ok((amount, recipient)) = estimate_inputs {
+                        action |= self.send_fee_estimate.show(
+                            ui,
+                            &self.app_context,
+                            &selected_wallet.read().unwrap(),
+                            amount,
+                            |fee_rate| match &recipient {
+                                Some(recipient) => {
+                                    FeeEstimator::for_payment(fee_rate, &recipient.script_pubkey())
+                                }
+                                None => FeeEstimator::for_asset_lock(fee_rate),
+                            },
+                            self.send_subtract_fee,
+                            self.selected_account,
+                            self.send_coin_selection,
+                        );
+                    }
+
+                    if let Some(error_message) = &self.error_message {
+                        ui.add_space(5.0);
+                        ui.colored_label(Color32::RED, error_message);
+                    }
+
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        if self.sending {
+                            ui.add(egui::Spinner::new());
+                            ui.label("Sending...");
+                            return;
+                        }
+
+                        if !watch_only && !self.send_asset_lock && ui.button("Send").clicked() {
+                            match self.payment_info(&selected_wallet) {
+                                Ok(payment_info) => {
+                                    self.error_message = None;
+                                    self.sending = true;
+                                    action = AppAction::BackendTask(BackendTask::CoreTask(
+                                        CoreTask::SendFunds(payment_info),
+                                    ));
+                                }
+                                Err(e) => self.error_message = Some(e),
+                            }
+                        }
+
+                        if ui
+                            .button("Export Unsigned")
+                            .on_hover_text(
+                                "Build the transaction without signing it, for signing on an offline instance",
+                            )
+                            .clicked()
+                        {
+                            let task = if self.send_asset_lock {
+                                self.asset_lock_info(&selected_wallet)
+                                    .map(CoreTask::ExportUnsignedAssetLock)
+                            } else {
+                                self.payment_info(&selected_wallet)
+                                    .map(CoreTask::ExportUnsignedPayment)
+                            };
+                            match task {
+                                Ok(task) => {
+                                    self.error_message = None;
+                                    self.sending = true;
+                                    action = AppAction::BackendTask(BackendTask::CoreTask(task));
+                                }
+                                Err(e) => self.error_message = Some(e),
+                            }
+                        }
+
+                        if ui.button("Cancel").clicked() {
+                            self.close_send_dialog();
+                        }
+                    });
+                });
+            });
+
+        if !open {
+            self.close_send_dialog();
+        }
+
+        action
+    }
+
+    /// Payment request and recipient inputs of the send dialog.
+    fn render_send_recipient(&mut self, ui: &mut Ui) {
+        ui.label("Payment request (optional):");
+        if ui
+            .add(
+                egui::TextEdit::singleline(&mut self.send_payment_uri_input)
+                    .hint_text("Paste a dash: link, or drop it on the window")
+                    .desired_width(350.0),
+            )
+            .changed()
+        {
+            self.apply_payment_uri();
+        }
+        if let Some(payment_uri) = &self.send_payment_uri {
+            if let Some(label) = &payment_uri.label {
+                ui.label(format!("To: {}", label));
+            }
+            if let Some(message) = &payment_uri.message {
+                ui.label(format!("For: {}", message));
+            }
+            if payment_uri.instant_send {
+                ui.label(
+                    RichText::new("The recipient asked for InstantSend. Every Dash transaction is InstantSend locked by the network, nothing else is needed.")
+                        .color(Color32::GRAY)
+                        .size(12.0),
+                );
+            }
+        }
+        ui.add_space(5.0);
+
+        ui.label("Recipient address:");
+        ui.add(
+            egui::TextEdit::singleline(&mut self.send_recipient_input)
+                .hint_text("Enter a Dash address")
+                .desired_width(350.0),
+        );
+        ui.add_space(5.0);
+    }
+
+    fn payment_info(&self, wallet: &Arc<RwLock<Wallet>>) -> Result<WalletPaymentInfo, String> {
+        let (recipient, amount) = self.parse_send_inputs()?;
+
+        Ok(WalletPaymentInfo {
+            wallet: wallet.clone(),
+            recipient,
+            amount,
+            subtract_fee_from_amount: self.send_subtract_fee,
+            account: self.selected_account,
+            coin_selection: self.send_coin_selection,
+        })
+    }
+
+    fn asset_lock_info(&self, wallet: &Arc<RwLock<Wallet>>) -> Result<WalletAssetLockInfo, String> {
+        Ok(WalletAssetLockInfo {
+            wallet: wallet.clone(),
+            amount: self.parse_send_amount()?,
+            allow_take_fee_from_amount: self.send_subtract_fee,
+            account: self.selected_account,
+            coin_selection: self.send_coin_selection,
+        })
+    }
+
+    /// Recipient and amount in duffs entered in the send dialog.
+    fn parse_send_inputs(&self) -> Result<(Address, u64), String> {
+        let recipient = Address::from_str(self.send_recipient_input.trim())
+            .map_err(|e| format!("Invalid address: {}", e))?
+            .require_network(self.app_context.network)
+            .map_err(|_| {
+                format!(
+                    "Address is not valid for network {}",
+                    self.app_context.network
+                )
+            })?;
+
+        Ok((recipient, self.parse_send_amount()?))
+    }
+
+    /// Amount in duffs entered in the send dialog.
+    fn parse_send_amount(&self) -> Result<u64, String> {
+        let amount_dash = self
+            .send_amount_input
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| "Invalid amount".to_string())?;
+        let amount = (amount_dash * 1e8).round() as u64;
+        if amount == 0 {
+            return Err("Amount must be greater than zero".to_string());
+        }
+
+        Ok(amount)
+    }
+
+    /// Fills the send dialog from the entered payment request.
+    pub(super) fn apply_payment_uri(&mut self) {
+        if self.send_payment_uri_input.trim().is_empty() {
+            self.send_payment_uri = None;
+            return;
+        }
+        match PaymentUri::parse(&self.send_payment_uri_input, self.app_context.network) {
+            Ok(payment_uri) => {
+                self.send_recipient_input = payment_uri.address.to_string();
+                if let Some(amount) = payment_uri.amount {
+                    self.send_amount_input = format_dash_amount(amount);
+                    self.send_subtract_fee = false;
+                }
+                self.send_payment_uri = Some(payment_uri);
+                self.error_message = None;
+            }
+            Err(e) => {
+                self.send_payment_uri = None;
+                self.error_message = Some(e);
+            }
+        }
+    }
+
+    pub(super) fn close_send_dialog(&mut self) {
+        self.show_send_dialog = false;
+        self.sending = false;
+        self.send_payment_uri_input.clear();
+        self.send_payment_uri = None;
+        self.send_recipient_input.clear();
+        self.send_amount_input.clear();
+        self.send_subtract_fee = false;
+        self.send_asset_lock = false;
+        self.exported_transaction = None;
+        self.show_export_qr_code = false;
+        self.error_message = None;
+    }
+}
@@ -0,0 +1,86 @@
+use super::WalletsBalancesScreen;
+use crate::model::signed_message::sign_message;
+use crate::ui::components::wallet_unlock::ScreenWithWalletUnlock;
+use crate::ui::identities::funding_common::copy_to_clipboard;
+use eframe::egui::{self, Context};
+use egui::{Color32, RichText};
+
+impl WalletsBalancesScreen {
+    /// Dialog signing a message with the key of one of the wallet's addresses, the way Dash
+    /// Core's `signmessage` does.
+    pub(super) fn render_sign_message_dialog(&mut self, ctx: &Context) {
+        let (Some(address), Some(wallet)) = (
+            self.sign_message_address.clone(),
+            self.selected_wallet.clone(),
+        ) else {
+            self.sign_message_address = None;
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new("Sign Message")
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label(format!("Address: {}", address));
+                ui.add_space(5.0);
+
+                let (needed_unlock, just_unlocked) = self.render_wallet_unlock_if_needed(ui);
+                if needed_unlock && !just_unlocked {
+                    return;
+                }
+
+                ui.label("Message:");
+                let response = ui.add(
+                    egui::TextEdit::multiline(&mut self.sign_message_input)
+                        .desired_rows(3)
+                        .desired_width(400.0),
+                );
+                if response.changed() {
+                    self.sign_message_signature = None;
+                }
+                ui.add_space(5.0);
+
+                if ui.button("Sign").clicked() {
+                    let private_key = wallet
+                        .read()
+                        .unwrap()
+                        .private_key_for_address(&address, self.app_context.network)
+                        .and_then(|private_key| {
+                            private_key.ok_or(format!("Address {} is not in this wallet", address))
+                        });
+                    match private_key {
+                        Ok(private_key) => {
+                            self.sign_message_signature =
+                                Some(sign_message(&self.sign_message_input, &private_key));
+                        }
+                        Err(e) => self.error_message = Some(e),
+                    }
+                }
+
+                if let Some(signature) = self.sign_message_signature.clone() {
+                    ui.add_space(10.0);
+                    ui.label("Signature:");
+                    ui.label(RichText::new(&signature).monospace());
+                    if ui.button("Copy").clicked() {
+                        if let Err(e) = copy_to_clipboard(&signature) {
+                            self.error_message = Some(e);
+                        }
+                    }
+                    ui.label(
+                        RichText::new(
+                            "Anyone can check it with the address and the exact message, e.g. in Tools > Verify message or with Dash Core's verifymessage.",
+                        )
+                        .color(Color32::GRAY),
+                    );
+                }
+            });
+
+        if !open {
+            self.sign_message_address = None;
+            self.sign_message_input.clear();
+            self.sign_message_signature = None;
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::{NETWORK, test_wallet};
+use dash_evo_tool::backend_task::BackendTaskSuccessResult;
+use dash_evo_tool::chain_data::ChainDataBackend;
+use dash_evo_tool::chain_data::mock::MockChain;
+use dash_evo_tool::components::core_zmq_listener::ZMQMessage;
+use dash_evo_tool::context::AppContext;
+use dash_evo_tool::database::Database;
+use dash_evo_tool::model::qualified_identity::encrypted_key_storage::PrivateKeyData;
+use dash_evo_tool::model::qualified_identity::{PrivateKeyTarget, QualifiedIdentity};
+use dash_evo_tool::model::wallet::Wallet;
+use dash_evo_tool::model::wallet::coin_selection::CoinSelectionStrategy;
+use dash_evo_tool::model::wallet::fee_estimation::FeeRate;
+use dash_evo_tool::utils::tasks::TaskManager;
+use dash_sdk::dpp::dashcore::{ScriptBuf, Transaction, TxIn, TxOut};
+use dash_sdk::dpp::identity::accessors::IdentityGettersV0;
+use dash_sdk::dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dash_sdk::dpp::identity::{Identity, IdentityPublicKey};
+use dash_sdk::dpp::prelude::AssetLockProof;
+use dash_sdk::platform::Identifier;
+use std::sync::{Arc, RwLock};
+use tempfile::TempDir;
+
+/// An app context on a fresh database, whose chain data comes from a mock chain, with the
+/// test wallet added.
+struct TestApp {
+    app_context: Arc<AppContext>,
+    db: Arc<Database>,
+    chain: Arc<MockChain>,
+    wallet: Arc<RwLock<Wallet>>,
+    _data_dir: TempDir,
+}
+
+impl TestApp {
+    fn new() -> Self {
+        dash_evo_tool::app_dir::copy_env_file_if_not_exists();
+        let data_dir = tempfile::tempdir().expect("temporary directory");
+        let db_file_path = data_dir.path().join("data.db");
+        let db = Database::new(&db_file_path).expect("database");
+        db.initialize(&db_file_path).expect("initialized database");
+
+        let db = Arc::new(db);
+        let app_context = AppContext::new(NETWORK, db.clone(), None, Arc::new(TaskManager::new()))
+            .expect("app context");
+        let chain = Arc::new(MockChain::new(NETWORK));
+        app_context.use_mock_chain(chain.clone());
+        let wallet = app_context.add_wallet(test_wallet()).expect("wallet");
+
+        TestApp {
+            app_context,
+            db,
+            chain,
+            wallet,
+            _data_dir: data_dir,
+        }
+    }
+
+    /// Pays `value` to a new receive address of the wallet, mines it and refreshes the wallet.
+    fn fund(&self, value: u64) {
+        let address = self
+            .wallet
+            .write()
+            .unwrap()
+            .receive_address(NETWORK, 0, false, Some(&self.app_context))
+            .expect("receive address");
+        self.chain.fund_address(&address, value);
+        self.chain.mine_block().expect("block");
+        self.app_context
+            .refresh_wallet_info(self.wallet.clone())
+            .expect("refresh");
+    }
+
+    /// Hands the queued ZMQ messages to the app context, like the app does.
+    fn deliver_zmq_messages(&self) {
+        for message in self
+            .chain
+            .replay_zmq_messages()
+            .expect("decodable messages")
+        {
+            if let ZMQMessage::ISLockedTransaction(tx, instant_lock) = message {
+                self.app_context
+                    .received_transaction_finality(&tx, Some(instant_lock), None)
+                    .expect("finality");
+            }
+        }
+    }
+}
+
+#[test]
+fn refresh_loads_balance_from_the_chain() {
+    let app = TestApp::new();
+    let address = app
+        .wallet
+        .write()
+        .unwrap()
+        .receive_address(NETWORK, 0, false, Some(&app.app_context))
+        .expect("receive address");
+    let outpoint = app.chain.fund_address(&address, 25_000_000);
+    app.chain.mine_block().expect("block");
+
+    let result = app
+        .app_context
+        .refresh_wallet_info(app.wallet.clone())
+        .expect("refresh");
+    // The address isn't watched, but a refresh only points at Rescan instead of rescanning
+    let BackendTaskSuccessResult::Message(message) = result else {
+        panic!("expected a message");
+    };
+    assert!(message.starts_with("Successfully refreshed wallet"));
+    assert!(message.contains("Rescan"));
+    assert_eq!(app.chain.rescans(), 0);
+    assert!(app.chain.watched_addresses().is_empty());
+
+    let wallet = app.wallet.read().unwrap();
+    assert_eq!(wallet.balance().confirmed, 25_000_000);
+    assert!(wallet.utxos[&address].contains_key(&outpoint));
+}
+
+#[test]
+fn spent_address_stays_used_when_the_chain_only_reports_unspent_outputs() {
+    let app = TestApp::new();
+    app.chain.report_unspent_only();
+    let address = app
+        .wallet
+        .write()
+        .unwrap()
+        .receive_address(NETWORK, 0, false, Some(&app.app_context))
+        .expect("receive address");
+    let outpoint = app.chain.fund_address(&address, 25_000_000);
+    app.chain.mine_block().expect("block");
+    app.app_context
+        .refresh_wallet_info(app.wallet.clone())
+        .expect("refresh");
+    assert!(app.wallet.read().unwrap().is_address_used(&address));
+
+    // Spend the output to someone else
+    let spend = Transaction {
+        version: 2,
+        lock_time: 0,
+        input: vec![TxIn {
+            previous_output: outpoint,
+            ..Default::default()
+        }],
+        output: vec![TxOut {
+            value: 24_990_000,
+            script_pubkey: ScriptBuf::new(),
+        }],
+        special_transaction_payload: None,
+    };
+    app.chain.broadcast_transaction(&spend).expect("spend");
+    app.chain.mine_block().expect("block");
+    app.app_context
+        .refresh_wallet_info(app.wallet.clone())
+        .expect("refresh");
+
+    let wallet = app.wallet.read().unwrap();
+    assert!(!wallet.utxos.contains_key(&address));
+    assert_eq!(
+        wallet.address_total_received.get(&address),
+        Some(&25_000_000)
+    );
+    assert!(wallet.is_address_used(&address));
+}
+
+#[test]
+fn registration_asset_lock_is_funded_and_proven() {
+    let app = TestApp::new();
+    app.fund(50_000_000);
+
+    let (transaction, _, change_address, used_utxos) = {
+        let mut wallet = app.wallet.write().unwrap();
+        let transaction = wallet
+            .registration_asset_lock_transaction(
+                NETWORK,
+                10_000_000,
+                true,
+                0,
+                0,
+                CoinSelectionStrategy::default(),
+                FeeRate::default(),
+                Some(&app.app_context),
+            )
+            .expect("asset lock transaction");
+        let (asset_lock_transaction, _, _, used_utxos) = &transaction;
+        wallet
+            .reserve_utxos(
+                used_utxos.keys().copied(),
+                asset_lock_transaction.txid(),
+                Some(&app.app_context),
+            )
+            .expect("reservation");
+        transaction
+    };
+
+    let txid = app
+        .app_context
+        .broadcast_wallet_asset_lock(&app.wallet, &transaction, &used_utxos, None)
+        .expect("broadcast");
+    assert_eq!(txid, transaction.txid());
+    assert!(app.app_context.asset_lock_proof(&txid).is_none());
+    for outpoint in used_utxos.keys() {
+        assert!(!app.chain.is_unspent(outpoint));
+    }
+
+    app.chain.instant_lock(&txid).expect("instant lock");
+    app.deliver_zmq_messages();
+
+    assert!(matches!(
+        app.app_context.asset_lock_proof(&txid),
+        Some(AssetLockProof::Instant(_))
+    ));
+    let wallet = app.wallet.read().unwrap();
+    assert!(
+        wallet
+            .unused_asset_locks
+            .iter()
+            .any(|(tx, ..)| tx.txid() == txid)
+    );
+    let change_address = change_address.expect("change");
+    assert!(
+        wallet.utxos[&change_address]
+            .keys()
+            .any(|outpoint| outpoint.txid == txid)
+    );
+}
+
+#[test]
+fn top_up_asset_lock_is_funded_and_proven() {
+    let app = TestApp::new();
+    app.fund(30_000_000);
+    app.fund(20_000_000);
+
+    let (transaction, _, _, used_utxos) = {
+        let mut wallet = app.wallet.write().unwrap();
+        let transaction = wallet
+            .top_up_asset_lock_transaction(
+                NETWORK,
+                40_000_000,
+                true,
+                0,
+                0,
+                0,
+                CoinSelectionStrategy::default(),
+                FeeRate::default(),
+                Some(&app.app_context),
+            )
+            .expect("asset lock transaction");
+        let (asset_lock_transaction, _, _, used_utxos) = &transaction;
+        wallet
+            .reserve_utxos(
+                used_utxos.keys().copied(),
+                asset_lock_transaction.txid(),
+                Some(&app.app_context),
+            )
+            .expect("reservation");
+        transaction
+    };
+    assert_eq!(used_utxos.len(), 2);
+
+    let txid = app
+        .app_context
+        .broadcast_wallet_asset_lock(
+            &app.wallet,
+            &transaction,
+            &used_utxos,
+            Some(Identifier::new([1; 32])),
+        )
+        .expect("broadcast");
+    assert!(app.wallet.read().unwrap().utxos.is_empty());
+
+    app.chain.instant_lock(&txid).expect("instant lock");
+    app.deliver_zmq_messages();
+
+    assert!(matches!(
+        app.app_context.asset_lock_proof(&txid),
+        Some(AssetLockProof::Instant(_))
+    ));
+}
+
+#[test]
+fn password_change_is_not_stored_when_an_identity_key_fails_to_re_encrypt() {
+    let app = TestApp::new();
+    app.app_context
+        .change_wallet_password(&app.wallet, None, Some("first"), None)
+        .expect("add password");
+
+    // An identity of the wallet with a key that can't be decrypted with the wallet password
+    let platform_version = app.app_context.platform_version();
+    let identity = Identity::create_basic_identity(Identifier::new([2; 32]), platform_version)
+        .expect("identity");
+    let mut qualified_identity = QualifiedIdentity::from(identity.clone());
+    let public_key = IdentityPublicKey::random_key(0, None, platform_version);
+    qualified_identity.private_keys.private_keys.insert(
+        (PrivateKeyTarget::PrivateKeyOnMainIdentity, public_key.id()),
+        (public_key.into(), PrivateKeyData::Encrypted(vec![7; 64])),
+    );
+    let seed_hash = app.wallet.read().unwrap().seed_hash();
+    app.app_context
+        .insert_local_qualified_identity(&qualified_identity, &Some((seed_hash, 0)))
+        .expect("store identity");
+    app.wallet
+        .write()
+        .unwrap()
+        .identities
+        .insert(0, identity.clone());
+
+    let stored_wallet = |app: &TestApp| {
+        let wallets = app.db.get_wallets(&NETWORK).expect("wallets");
+        let wallet = wallets
+            .iter()
+            .find(|wallet| wallet.seed_hash() == seed_hash)
+            .expect("stored wallet");
+        (
+            wallet.encrypted_seed_slice().map(<[u8]>::to_vec),
+            wallet.salt().map(<[u8]>::to_vec),
+            wallet.nonce().map(<[u8]>::to_vec),
+        )
+    };
+    let wallet_before = stored_wallet(&app);
+    let salt_before = app.wallet.read().unwrap().salt().map(<[u8]>::to_vec);
+
+    assert!(
+        app.app_context
+            .change_wallet_password(&app.wallet, Some("first"), Some("second"), None)
+            .is_err()
+    );
+
+    assert_eq!(stored_wallet(&app), wallet_before);
+    assert_eq!(
+        app.wallet.read().unwrap().salt().map(<[u8]>::to_vec),
+        salt_before
+    );
+    let stored_identity = app
+        .app_context
+        .load_local_qualified_identities()
+        .expect("identities")
+        .into_iter()
+        .find(|stored| stored.identity.id() == identity.id())
+        .expect("stored identity");
+    assert_eq!(
+        stored_identity.private_keys,
+        qualified_identity.private_keys
+    );
+}
@@ -0,0 +1,149 @@
+use crate::{NETWORK, funded_wallet, known_address};
+use dash_evo_tool::chain_data::ChainDataBackend;
+use dash_evo_tool::chain_data::mock::MockChain;
+use dash_evo_tool::components::core_zmq_listener::ZMQMessage;
+use dash_evo_tool::model::wallet::coin_selection::CoinSelectionStrategy;
+use dash_evo_tool::model::wallet::fee_estimation::FeeRate;
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::Address;
+use dash_sdk::dpp::dashcore::transaction::special_transaction::TransactionPayload::AssetLockPayloadType;
+
+#[test]
+fn registration_asset_lock_is_broadcast_and_instant_locked() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[50_000_000]);
+
+    let (transaction, private_key, change_address, used_utxos) = wallet
+        .registration_asset_lock_transaction(
+            NETWORK,
+            10_000_000,
+            false,
+            0,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+        .expect("asset lock transaction");
+
+    let Some(AssetLockPayloadType(payload)) = &transaction.special_transaction_payload else {
+        panic!("expected an asset lock payload");
+    };
+    let one_time_address = Address::p2pkh(&private_key.public_key(&Secp256k1::new()), NETWORK);
+    assert_eq!(payload.credit_outputs.len(), 1);
+    assert_eq!(payload.credit_outputs[0].value, 10_000_000);
+    assert_eq!(
+        payload.credit_outputs[0].script_pubkey,
+        one_time_address.script_pubkey()
+    );
+
+    let txid = chain
+        .broadcast_transaction(&transaction)
+        .expect("broadcast");
+    for outpoint in used_utxos.keys() {
+        assert!(!chain.is_unspent(outpoint));
+    }
+
+    let instant_lock = chain.instant_lock(&txid).expect("instant lock");
+    assert_eq!(instant_lock.txid, txid);
+    assert!(chain.is_instant_locked(&txid));
+
+    let messages = chain.replay_zmq_messages().expect("decodable messages");
+    assert_eq!(messages.len(), 1);
+    let ZMQMessage::ISLockedTransaction(locked_transaction, replayed_lock) = &messages[0] else {
+        panic!("expected an instant locked transaction");
+    };
+    assert_eq!(locked_transaction.txid(), txid);
+    assert_eq!(replayed_lock.txid, txid);
+
+    // The change comes back to the wallet once it knows the change address
+    let change_address = change_address.expect("change");
+    assert_eq!(change_address, known_address(&mut wallet, true, 0));
+    wallet
+        .reload_utxos(&chain, NETWORK, None)
+        .expect("reload UTXOs");
+    let change_value = transaction
+        .output
+        .iter()
+        .find(|output| output.script_pubkey == change_address.script_pubkey())
+        .expect("change output")
+        .value;
+    assert_eq!(wallet.max_balance(), change_value);
+    assert!(change_value < 40_000_000);
+}
+
+#[test]
+fn top_up_asset_lock_is_chain_locked_once_mined() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[30_000_000, 20_000_000]);
+
+    let (transaction, _, _, used_utxos) = wallet
+        .top_up_asset_lock_transaction(
+            NETWORK,
+            40_000_000,
+            false,
+            0,
+            0,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+        .expect("asset lock transaction");
+    assert_eq!(used_utxos.len(), 2);
+
+    let txid = chain
+        .broadcast_transaction(&transaction)
+        .expect("broadcast");
+    assert_eq!(chain.confirmations(&txid), 0);
+    assert!(!chain.is_chain_locked(&txid));
+
+    let height = chain.mine_block().expect("block");
+    chain.chain_lock(height).expect("chain lock");
+    assert_eq!(chain.confirmations(&txid), 1);
+    assert!(chain.is_chain_locked(&txid));
+
+    let messages = chain.replay_zmq_messages().expect("decodable messages");
+    assert_eq!(messages.len(), 1);
+    let ZMQMessage::ChainLockedBlock(block) = &messages[0] else {
+        panic!("expected a chain locked block");
+    };
+    assert!(block.txdata.iter().any(|tx| tx.txid() == txid));
+}
+
+#[test]
+fn spent_outputs_can_not_be_spent_again() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[50_000_000]);
+    let utxos_before = wallet.utxos.clone();
+
+    let (first, _, _, _) = wallet
+        .registration_asset_lock_transaction(
+            NETWORK,
+            10_000_000,
+            false,
+            0,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+        .expect("asset lock transaction");
+    chain.broadcast_transaction(&first).expect("broadcast");
+
+    // A wallet with a stale UTXO set builds a conflicting transaction
+    wallet.utxos = utxos_before;
+    let (second, _, _, _) = wallet
+        .registration_asset_lock_transaction(
+            NETWORK,
+            20_000_000,
+            false,
+            1,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+        .expect("asset lock transaction");
+    assert!(chain.broadcast_transaction(&second).is_err());
+}
@@ -0,0 +1,85 @@
+mod app_context;
+mod funding;
+mod password;
+mod payment;
+mod refresh;
+
+use bip39::Mnemonic;
+use dash_evo_tool::chain_data::mock::MockChain;
+use dash_evo_tool::model::wallet::accounts::WalletAccount;
+use dash_evo_tool::model::wallet::{ClosedKeyItem, OpenWalletSeed, Wallet, WalletSeed};
+use dash_sdk::dashcore_rpc::dashcore::key::Secp256k1;
+use dash_sdk::dpp::dashcore::bip32::{DerivationPath, ExtendedPrivKey, ExtendedPubKey};
+use dash_sdk::dpp::dashcore::{Address, Network};
+use std::str::FromStr;
+
+pub const NETWORK: Network = Network::Regtest;
+
+const TEST_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// An open wallet without a password for the well-known test mnemonic.
+pub fn test_wallet() -> Wallet {
+    let seed = Mnemonic::parse_normalized(TEST_MNEMONIC)
+        .expect("valid mnemonic")
+        .to_seed("");
+    let secp = Secp256k1::new();
+    let master_key = ExtendedPrivKey::new_master(NETWORK, &seed).expect("master key");
+    let account_key = master_key
+        .derive_priv(&secp, &DerivationPath::from_str("m/44'/1'/0'").unwrap())
+        .expect("account key");
+    let master_bip44_ecdsa_extended_public_key = ExtendedPubKey::from_priv(&secp, &account_key);
+
+    Wallet {
+        wallet_seed: WalletSeed::Open(OpenWalletSeed {
+            seed,
+            wallet_info: ClosedKeyItem {
+                seed_hash: ClosedKeyItem::compute_seed_hash(&seed),
+                encrypted_seed: seed.to_vec(),
+                salt: vec![],
+                nonce: vec![],
+                password_hint: None,
+                passphrase_hint: None,
+            },
+        }),
+        uses_password: false,
+        master_bip44_ecdsa_extended_public_key,
+        accounts: WalletAccount::default_accounts(master_bip44_ecdsa_extended_public_key),
+        address_total_received: Default::default(),
+        known_addresses: Default::default(),
+        watched_addresses: Default::default(),
+        unused_asset_locks: Default::default(),
+        alias: Some("test".to_string()),
+        identities: Default::default(),
+        utxos: Default::default(),
+        utxo_statuses: Default::default(),
+        utxo_reservations: Default::default(),
+        is_main: true,
+    }
+}
+
+/// Adds the BIP44 address at `index` to the wallet, the way registering it with an app
+/// context would, and returns it.
+pub fn known_address(wallet: &mut Wallet, change: bool, index: u32) -> Address {
+    let (address, derivation_path) = wallet
+        .bip44_address_at(NETWORK, 0, change, index)
+        .expect("address");
+    wallet
+        .known_addresses
+        .insert(address.clone(), derivation_path);
+    address
+}
+
+/// A wallet with one confirmed UTXO of each of `values` duffs, on its own address.
+pub fn funded_wallet(chain: &MockChain, values: &[u64]) -> Wallet {
+    let mut wallet = test_wallet();
+    for (index, value) in values.iter().enumerate() {
+        let address = known_address(&mut wallet, false, index as u32);
+        chain.fund_address(&address, *value);
+    }
+    chain.mine_block().expect("block");
+    wallet
+        .reload_utxos(chain, NETWORK, None)
+        .expect("reload UTXOs");
+    wallet
+}
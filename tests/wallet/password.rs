@@ -0,0 +1,50 @@
+use crate::test_wallet;
+use dash_evo_tool::model::wallet::ClosedKeyItem;
+
+#[test]
+fn password_can_be_added_changed_and_removed() {
+    let mut wallet = test_wallet();
+    let seed_hash = wallet.seed_hash();
+    let seed = wallet.encrypted_seed_slice().expect("seed").to_vec();
+
+    wallet
+        .change_password(None, Some("first"), Some("hint".to_string()))
+        .expect("add password");
+    assert!(wallet.uses_password);
+    assert!(wallet.is_open());
+    assert_eq!(wallet.password_hint(), &Some("hint".to_string()));
+    assert_ne!(wallet.encrypted_seed_slice(), Some(seed.as_slice()));
+
+    assert!(wallet.change_password(None, Some("second"), None).is_err());
+    assert!(
+        wallet
+            .change_password(Some("wrong"), Some("second"), None)
+            .is_err()
+    );
+    let salt_before = wallet.salt().expect("salt").to_vec();
+    wallet
+        .change_password(Some("first"), Some("second"), None)
+        .expect("change password");
+    assert_ne!(wallet.salt(), Some(salt_before.as_slice()));
+    assert_eq!(wallet.password_hint(), &None);
+
+    let closed = ClosedKeyItem {
+        seed_hash,
+        encrypted_seed: wallet.encrypted_seed_slice().expect("seed").to_vec(),
+        salt: wallet.salt().expect("salt").to_vec(),
+        nonce: wallet.nonce().expect("nonce").to_vec(),
+        password_hint: None,
+        passphrase_hint: None,
+    };
+    assert_eq!(
+        closed.decrypt_seed("second").expect("decrypt").to_vec(),
+        seed
+    );
+
+    wallet
+        .change_password(Some("second"), None, None)
+        .expect("remove password");
+    assert!(!wallet.uses_password);
+    assert_eq!(wallet.encrypted_seed_slice(), Some(seed.as_slice()));
+    assert_eq!(wallet.seed_hash(), seed_hash);
+}
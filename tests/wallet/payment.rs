@@ -0,0 +1,102 @@
+use crate::{NETWORK, funded_wallet};
+use dash_evo_tool::chain_data::mock::MockChain;
+use dash_evo_tool::model::wallet::coin_selection::CoinSelectionStrategy;
+use dash_evo_tool::model::wallet::fee_estimation::{FeeEstimator, FeeRate};
+use dash_sdk::dpp::dashcore::Address;
+
+#[test]
+fn subtracted_fee_is_taken_from_the_recipient_with_change() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[10_000_000]);
+    let (recipient, _) = wallet
+        .bip44_address_at(NETWORK, 0, false, 100)
+        .expect("address");
+
+    let (transaction, change_address, used_utxos) = wallet
+        .payment_transaction(
+            NETWORK,
+            &recipient,
+            4_000_000,
+            true,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+        .expect("payment");
+
+    let input_value: u64 = used_utxos.values().map(|(tx_out, _)| tx_out.value).sum();
+    let output_value: u64 = transaction.output.iter().map(|output| output.value).sum();
+    let fee = input_value - output_value;
+    assert!(fee > 0);
+
+    let change_address = change_address.expect("change");
+    let output_to = |address: &Address| {
+        transaction
+            .output
+            .iter()
+            .find(|output| output.script_pubkey == address.script_pubkey())
+            .expect("output")
+            .value
+    };
+    assert_eq!(output_to(&recipient), 4_000_000 - fee);
+    assert_eq!(output_to(&change_address), 6_000_000);
+}
+
+#[test]
+fn subtracted_fee_never_pays_the_recipient_more_than_the_amount() {
+    let chain = MockChain::new(NETWORK);
+    // Too little is left over after the amount for a change output
+    let mut wallet = funded_wallet(&chain, &[5_500]);
+    let (recipient, _) = wallet
+        .bip44_address_at(NETWORK, 0, false, 100)
+        .expect("address");
+
+    let (transaction, change_address, _) = wallet
+        .payment_transaction(
+            NETWORK,
+            &recipient,
+            5_000,
+            true,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+        .expect("payment");
+
+    assert!(change_address.is_none());
+    let fee =
+        FeeEstimator::for_payment(FeeRate::default(), &recipient.script_pubkey()).fee(1, false);
+    assert_eq!(transaction.output.len(), 1);
+    assert_eq!(transaction.output[0].value, 5_000 - fee);
+}
+
+#[test]
+fn subtracted_fee_rejects_amounts_below_the_fee_or_dust() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[800]);
+    let (recipient, _) = wallet
+        .bip44_address_at(NETWORK, 0, false, 100)
+        .expect("address");
+    let mut pay = |amount| {
+        wallet.payment_transaction(
+            NETWORK,
+            &recipient,
+            amount,
+            true,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+    };
+
+    let error = pay(150).expect_err("amount below the fee");
+    assert!(error.contains("doesn't cover the fee"));
+    let error = pay(700).expect_err("dust output");
+    assert!(error.contains("dust limit"));
+
+    // The UTXO is still there to spend
+    assert_eq!(wallet.balance().confirmed, 800);
+}
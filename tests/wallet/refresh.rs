@@ -0,0 +1,160 @@
+use crate::{NETWORK, funded_wallet, known_address};
+use dash_evo_tool::chain_data::ChainDataBackend;
+use dash_evo_tool::chain_data::mock::MockChain;
+use dash_evo_tool::model::wallet::Wallet;
+use dash_evo_tool::model::wallet::balance::WalletBalance;
+use dash_evo_tool::model::wallet::coin_selection::CoinSelectionStrategy;
+use dash_evo_tool::model::wallet::fee_estimation::FeeRate;
+
+#[test]
+fn reload_utxos_picks_up_unconfirmed_payments() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[10_000_000]);
+
+    let address = known_address(&mut wallet, false, 1);
+    let outpoint = chain.fund_address(&address, 5_000_000);
+
+    let utxos = wallet
+        .reload_utxos(&chain, NETWORK, None)
+        .expect("reload UTXOs");
+    assert_eq!(utxos.len(), 2);
+    assert!(utxos.contains_key(&outpoint));
+    assert_eq!(wallet.max_balance(), 15_000_000);
+
+    assert_eq!(chain.received_by_address(&address, false), Ok(0));
+    assert_eq!(chain.received_by_address(&address, true), Ok(5_000_000));
+    chain.mine_block().expect("block");
+    assert_eq!(chain.received_by_address(&address, false), Ok(5_000_000));
+}
+
+#[test]
+fn balance_follows_settlement_of_unspent_outputs() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[10_000_000]);
+
+    let address = known_address(&mut wallet, false, 1);
+    let outpoint = chain.fund_address(&address, 5_000_000);
+    wallet
+        .reload_utxos(&chain, NETWORK, None)
+        .expect("reload UTXOs");
+    assert_eq!(
+        wallet.balance(),
+        WalletBalance {
+            confirmed: 10_000_000,
+            unconfirmed: 5_000_000,
+            ..Default::default()
+        }
+    );
+    assert_eq!(wallet.address_balance(&address).unconfirmed, 5_000_000);
+
+    chain.instant_lock(&outpoint.txid).expect("instant lock");
+    wallet
+        .reload_utxos(&chain, NETWORK, None)
+        .expect("reload UTXOs");
+    assert_eq!(wallet.balance().instant_locked, 5_000_000);
+
+    chain.mine_block().expect("block");
+    wallet
+        .reload_utxos(&chain, NETWORK, None)
+        .expect("reload UTXOs");
+    assert_eq!(wallet.balance().confirmed, 15_000_000);
+    assert_eq!(wallet.max_balance(), 15_000_000);
+}
+
+#[test]
+fn refresh_keeps_utxos_of_pending_transactions_reserved() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[10_000_000]);
+    let (recipient, _) = wallet
+        .bip44_address_at(NETWORK, 0, false, 100)
+        .expect("address");
+    let pay = |wallet: &mut Wallet| {
+        wallet.payment_transaction(
+            NETWORK,
+            &recipient,
+            4_000_000,
+            false,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+    };
+
+    let (transaction, _, used_utxos) = pay(&mut wallet).expect("payment");
+    wallet
+        .reserve_utxos(used_utxos.keys().copied(), transaction.txid(), None)
+        .expect("reserve");
+
+    // Not broadcast yet, so the chain still reports the UTXO as unspent
+    wallet
+        .reload_utxos(&chain, NETWORK, None)
+        .expect("reload UTXOs");
+    assert!(wallet.spendable_utxos().is_empty());
+    assert!(pay(&mut wallet).is_err());
+
+    wallet
+        .release_utxo_reservations(&transaction.txid(), None)
+        .expect("release");
+    assert_eq!(wallet.spendable_utxos().len(), 1);
+    assert!(pay(&mut wallet).is_ok());
+}
+
+#[test]
+fn refresh_after_payment_drops_spent_utxos_and_reports_history() {
+    let chain = MockChain::new(NETWORK);
+    let mut wallet = funded_wallet(&chain, &[10_000_000]);
+    let funding_txid = wallet
+        .utxos
+        .values()
+        .flat_map(|outpoints| outpoints.keys())
+        .next()
+        .expect("funded")
+        .txid;
+
+    // Not registered with the wallet, so it counts as someone else's address
+    let (recipient, _) = wallet
+        .bip44_address_at(NETWORK, 0, false, 100)
+        .expect("address");
+    let (transaction, _, _) = wallet
+        .payment_transaction(
+            NETWORK,
+            &recipient,
+            4_000_000,
+            false,
+            0,
+            CoinSelectionStrategy::default(),
+            FeeRate::default(),
+            None,
+        )
+        .expect("payment");
+    let payment_txid = chain
+        .broadcast_transaction(&transaction)
+        .expect("broadcast");
+
+    // The change address was never registered, so only the spent UTXO is dropped
+    wallet
+        .reload_utxos(&chain, NETWORK, None)
+        .expect("reload UTXOs");
+    assert_eq!(wallet.max_balance(), 0);
+
+    let addresses: Vec<_> = wallet.known_addresses.keys().cloned().collect();
+    let history = chain
+        .recent_transactions(&addresses, 10)
+        .expect("transactions");
+    assert_eq!(history.len(), 2);
+
+    let funding = history
+        .iter()
+        .find(|tx| tx.txid == funding_txid)
+        .expect("funding transaction");
+    assert_eq!(funding.received, 10_000_000);
+    assert_eq!(funding.confirmations, 1);
+
+    let payment = history
+        .iter()
+        .find(|tx| tx.txid == payment_txid)
+        .expect("payment transaction");
+    assert_eq!(payment.received, 0);
+    assert_eq!(payment.confirmations, 0);
+}